@@ -0,0 +1,51 @@
+use charts::{Chart, BoxPlotView, ScaleBand, ScaleLinear, WhiskerExtent, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 80, 60);
+
+    // Create a band scale that maps the endpoints to values in the [0, availableWidth]
+    // range (the width of the chart without the margins).
+    let x = ScaleBand::new()
+        .set_domain(vec![String::from("/login"), String::from("/search"), String::from("/checkout")])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate latencies in [0, 500] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 500_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Each entry is a single latency sample of an endpoint (the category) for a region (the key).
+    let data = vec![
+        ("/login", 120, "eu"), ("/login", 135, "eu"), ("/login", 150, "eu"), ("/login", 142, "eu"), ("/login", 380, "eu"),
+        ("/login", 90, "us"), ("/login", 110, "us"), ("/login", 105, "us"), ("/login", 130, "us"), ("/login", 125, "us"),
+        ("/search", 210, "eu"), ("/search", 250, "eu"), ("/search", 230, "eu"), ("/search", 275, "eu"), ("/search", 190, "eu"),
+        ("/search", 180, "us"), ("/search", 205, "us"), ("/search", 220, "us"), ("/search", 198, "us"), ("/search", 460, "us"),
+        ("/checkout", 310, "eu"), ("/checkout", 330, "eu"), ("/checkout", 295, "eu"), ("/checkout", 360, "eu"), ("/checkout", 340, "eu"),
+        ("/checkout", 260, "us"), ("/checkout", 280, "us"), ("/checkout", 300, "us"), ("/checkout", 270, "us"), ("/checkout", 290, "us"),
+    ];
+
+    // Create BoxPlot view that is going to summarize the latency distributions.
+    let view = BoxPlotView::new()
+        .set_category_scale(&x)
+        .set_value_scale(&y)
+        .set_whisker_extent(WhiskerExtent::InterquartileRange(1.5))
+        .set_mean_visibility(true)
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Box Plot"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Latency (ms)")
+        .add_legend_at(AxisPosition::Bottom)
+        .save("box-plot-chart.svg").unwrap();
+}
@@ -0,0 +1,61 @@
+use charts::{Chart, ScaleLinear, ScaleSqrt, ScatterView, MarkerType, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 700;
+    let (top, right, bottom, left) = (90, 40, 190, 60);
+
+    // Create a linear scale for the GDP per capita (in thousands of dollars).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 70_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the life expectancy.
+    let y = ScaleLinear::new()
+        .set_domain(vec![50_f32, 90_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Create a square root scale that maps the population (in millions) to the radius
+    // of the bubbles, so that the area of a bubble is proportional to the population.
+    let size = ScaleSqrt::new()
+        .set_domain(vec![0_f32, 1500_f32])
+        .set_range(vec![0, 30]);
+
+    // You can use your own iterable as data as long as its items implement the `PointDatum` trait.
+    // Here the data is made of (x, y, size, key) tuples.
+    let data = vec![
+        (63_f32, 78.5, 330_f32, "Americas"),
+        (10_f32, 77_f32, 1400_f32, "Asia"),
+        (2_f32, 69.7, 1380_f32, "Asia"),
+        (46_f32, 81.3, 83_f32, "Europe"),
+        (40_f32, 84.6, 126_f32, "Asia"),
+        (8.7, 75.9, 212_f32, "Americas"),
+        (2.2, 54.7, 206_f32, "Africa"),
+        (6_f32, 64.1, 59_f32, "Africa"),
+    ];
+
+    // Create Scatter view that is going to represent the data as bubbles.
+    let scatter_view = ScatterView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_size_scale(&size)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .set_colors(Color::color_scheme_tableau_10())
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Bubble Chart"))
+        .add_view(&scatter_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Life Expectancy")
+        .add_bottom_axis_label("GDP per Capita (thousands)")
+        .add_legend_at(AxisPosition::Bottom)
+        .save("bubble-chart.svg").unwrap();
+}
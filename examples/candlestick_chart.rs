@@ -0,0 +1,62 @@
+use charts::{Chart, CandlestickView, ScaleBand, ScaleLinear, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 80, 60);
+    let volume_height = 100;
+
+    // You can use your own iterable as data as long as its items implement the `OhlcDatum` trait.
+    // Here the data is made of (date, open, high, low, close, volume) tuples. Weekends are
+    // non-trading days, so they are not part of the data.
+    let data = vec![
+        ("Mar 02", 61.2, 63.4, 60.8, 62.9, 1200_f32),
+        ("Mar 03", 62.9, 64.1, 62.0, 63.5, 980_f32),
+        ("Mar 04", 63.5, 63.9, 60.7, 61.1, 1650_f32),
+        ("Mar 05", 61.1, 61.8, 58.9, 59.4, 2100_f32),
+        ("Mar 06", 59.4, 60.9, 59.0, 60.6, 1430_f32),
+        ("Mar 09", 60.6, 62.7, 60.2, 62.4, 1120_f32),
+        ("Mar 10", 62.4, 65.3, 62.1, 65.0, 1890_f32),
+        ("Mar 11", 65.0, 65.6, 63.8, 64.2, 1010_f32),
+        ("Mar 12", 64.2, 64.4, 61.5, 61.9, 1540_f32),
+        ("Mar 13", 61.9, 63.2, 61.7, 62.8, 870_f32),
+    ];
+
+    // Create a band scale that maps the trading days to values in the [0, availableWidth] range.
+    // Since only trading days are part of the domain, there are no gaps for weekends.
+    let x = ScaleBand::new()
+        .set_domain(data.iter().map(|datum| datum.0.to_string()).collect())
+        .set_range(vec![0, width - left - right])
+        .set_inner_padding(0.3);
+
+    // Create a linear scale for the prices.
+    let y = ScaleLinear::new()
+        .set_domain(vec![55_f32, 66_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Create a linear scale that places the volume bars in a strip at the bottom of the chart.
+    let volume = ScaleLinear::new()
+        .set_domain(vec![0_f32, 2500_f32])
+        .set_range(vec![height - top - bottom, height - top - bottom - volume_height]);
+
+    // Create Candlestick view that is going to represent the data as candles, with the volume underneath.
+    let view = CandlestickView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_volume_scale(&volume)
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Candlestick Chart"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Price")
+        .add_legend_at(AxisPosition::Bottom)
+        .save("candlestick-chart.svg").unwrap();
+}
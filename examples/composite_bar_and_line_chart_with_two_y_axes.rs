@@ -0,0 +1,64 @@
+use charts::{Chart, VerticalBarView, LineSeriesView, ScaleBand, ScaleLinear, MarkerType, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 70, 50, 70);
+
+    let quarters = vec![String::from("Q1"), String::from("Q2"), String::from("Q3"), String::from("Q4")];
+
+    // Create a band scale that maps the quarters to values in the [0, availableWidth] range.
+    let x = ScaleBand::new()
+        .set_domain(quarters.clone())
+        .set_range(vec![0, width - left - right]);
+
+    // Create a scale for the revenue, which is displayed along the left axis.
+    let revenue_scale = ScaleLinear::new()
+        .set_domain(vec![0_f32, 500_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Create a scale for the margin, which is displayed along the right axis.
+    let margin_scale = ScaleLinear::new()
+        .set_domain(vec![0_f32, 40_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    let revenue_data = vec![("Q1", 320_f32), ("Q2", 410_f32), ("Q3", 280_f32), ("Q4", 460_f32)];
+    let margin_data = vec![(String::from("Q1"), 12_f32), (String::from("Q2"), 18_f32), (String::from("Q3"), 31_f32), (String::from("Q4"), 24_f32)];
+
+    // Create a VerticalBar view for the revenue.
+    let bar_view = VerticalBarView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&revenue_scale)
+        .set_custom_data_label(String::from("Revenue"))
+        .load_data(&revenue_data).unwrap();
+
+    // Create a LineSeries view for the margin.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&margin_scale)
+        .set_marker_type(MarkerType::Circle)
+        .set_colors(Color::from_vec_of_hex_strings(vec!["#FF4700"]))
+        .set_custom_data_label(String::from("Margin"))
+        .load_data(&margin_data).unwrap();
+
+    // Generate and save the chart. Every view is bound to the axes that display its scales,
+    // and the labels of the Y axes take the colors of their series.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Composite Bar + Line Chart with Two Y Axes"))
+        .add_view_on_axes(&bar_view, AxisPosition::Bottom, AxisPosition::Left)
+        .add_view_on_axes(&line_view, AxisPosition::Bottom, AxisPosition::Right)
+        .add_axis_bottom(&x)
+        .add_axis_left(&revenue_scale)
+        .add_axis_right(&margin_scale)
+        .add_left_axis_label("Revenue (k$)")
+        .add_right_axis_label("Margin (%)")
+        .set_axis_label_color_from_series(AxisPosition::Left, true)
+        .set_axis_label_color_from_series(AxisPosition::Right, true)
+        .add_bottom_axis_label("Quarter")
+        .add_legend_at(AxisPosition::Top)
+        .save("composite-bar-and-line-chart-with-two-y-axes.svg").unwrap();
+}
@@ -0,0 +1,57 @@
+use charts::{Chart, HeatmapView, ScaleBand, ColorScale, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 400;
+    let (top, right, bottom, left) = (90, 40, 80, 60);
+
+    let days = vec!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    let hours = (0..24).map(|hour| format!("{:02}", hour)).collect::<Vec<String>>();
+
+    // Create a band scale that maps the hours of the day to columns.
+    let x = ScaleBand::new()
+        .set_inner_padding(0.05)
+        .set_outer_padding(0_f32)
+        .set_domain(hours.clone())
+        .set_range(vec![0, width - left - right]);
+
+    // Create a band scale that maps the days of the week to rows.
+    let y = ScaleBand::new()
+        .set_inner_padding(0.05)
+        .set_outer_padding(0_f32)
+        .set_domain(days.iter().map(|day| day.to_string()).collect())
+        .set_range(vec![0, height - top - bottom]);
+
+    // You can use your own iterable as data as long as its items implement the `HeatmapDatum` trait.
+    let mut data = Vec::new();
+    for (i, day) in days.iter().enumerate() {
+        for (j, hour) in hours.iter().enumerate() {
+            let daytime = (-((j as f32 - 14_f32) / 4_f32).powi(2)).exp();
+            let weekday = if i < 5 { 1_f32 } else { 0.4 };
+            data.push((hour.clone(), day.to_string(), (daytime * weekday * 100_f32).round()));
+        }
+    }
+
+    // Create Heatmap view that is going to represent the data as colored cells.
+    let view = HeatmapView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_color_scale(ColorScale::new(Color::color_scheme_viridis()))
+        .set_label_visibility(true)
+        .set_label_rounding_precision(0)
+        .set_custom_data_label(String::from("Activity"))
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Heatmap"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Bottom)
+        .save("heatmap-chart.svg").unwrap();
+}
@@ -0,0 +1,64 @@
+use charts::{Chart, Facet, Page, AxisPosition, ScaleLinear, ScaleSharing, LineSeriesView, MarkerType};
+
+fn main() {
+    // Define the sizes of every panel.
+    let (width, height) = (360, 260);
+    let (top, right, bottom, left) = (50, 20, 40, 50);
+
+    // Monthly sales of two products in four regions.
+    let regions = ["North", "East", "South", "West"];
+    let mut data = Vec::new();
+    for (i, region) in regions.iter().enumerate() {
+        for month in 1..=12 {
+            let month = month as f32;
+            data.push((region.to_string(), month, 20_f32 + 5_f32 * i as f32 + 3_f32 * month, String::from("Product A")));
+            data.push((region.to_string(), month, 60_f32 - 4_f32 * i as f32 + 2_f32 * (month / 2_f32).sin() * 10_f32, String::from("Product B")));
+        }
+    }
+    let keys = vec![String::from("Product A"), String::from("Product B")];
+
+    // Display a panel per region. All panels share the X scale, while the Y scale
+    // of every panel fits the data of its region.
+    let facet = Facet::new(&data, |datum| datum.0.clone())
+        .set_x_scale(ScaleSharing::Shared, |_| {
+            ScaleLinear::new()
+                .set_domain(vec![1_f32, 12_f32])
+                .set_range(vec![0, width - left - right])
+        })
+        .set_y_scale(ScaleSharing::Independent, |data| {
+            let max = data.iter().map(|datum| datum.2).fold(0_f32, f32::max);
+            ScaleLinear::new()
+                .set_domain(vec![0_f32, max])
+                .set_range(vec![height - top - bottom, 0])
+        })
+        .set_columns(2)
+        .add_legend_at(AxisPosition::Bottom);
+
+    let grid = facet.build_grid(|panel, x, y| {
+        let line_data = panel.get_data().iter()
+            .map(|(_, month, sales, product)| (*month, *sales, product.clone()))
+            .collect::<Vec<(f32, f32, String)>>();
+
+        // Set the keys on every view, so that every product has the same color in all panels.
+        let view = LineSeriesView::new()
+            .set_x_scale(x)
+            .set_y_scale(y)
+            .set_keys(keys.clone())
+            .set_marker_type(MarkerType::Circle)
+            .set_label_visibility(false)
+            .load_data(&line_data)?;
+
+        Ok(Chart::new()
+            .set_width(width)
+            .set_height(height)
+            .set_margins(top, right, bottom, left)
+            .add_owned_view(view)
+            .add_axis_bottom(x)
+            .add_axis_left(y))
+    }).unwrap();
+
+    Page::new()
+        .add_title(String::from("Monthly Sales per Region"))
+        .add_grid(grid)
+        .save("line-chart-facets.svg").unwrap();
+}
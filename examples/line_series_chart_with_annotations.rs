@@ -0,0 +1,66 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView, AnnotationView, AnnotationStyle, Color};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale for the hours of the day.
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 24_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the response time in milliseconds.
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 500_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Response times measured every two hours.
+    let line_data = vec![
+        (0_f32, 120_f32), (2_f32, 110_f32), (4_f32, 105_f32), (6_f32, 140_f32),
+        (8_f32, 210_f32), (10_f32, 390_f32), (12_f32, 420_f32), (14_f32, 230_f32),
+        (16_f32, 190_f32), (18_f32, 170_f32), (20_f32, 150_f32), (22_f32, 130_f32),
+        (24_f32, 125_f32),
+    ];
+
+    // Create Line series view that is going to represent the data.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .load_data(&line_data).unwrap();
+
+    // Annotate the SLA and the average as rules, and the incident window as a shaded span.
+    // The annotations are drawn behind the line, since they have a lower z-index.
+    let average = line_data.iter().map(|(_, y)| y).sum::<f32>() / line_data.len() as f32;
+    let annotation_view = AnnotationView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_z_index(-1)
+        .add_vertical_span(9_f32, 13_f32, AnnotationStyle::new()
+            .set_color(Color::from_hex("#d62728"))
+            .set_label(String::from("Incident")))
+        .add_horizontal_rule(300_f32, AnnotationStyle::new()
+            .set_color(Color::from_hex("#d62728"))
+            .set_dash_array("5,3")
+            .set_label(String::from("SLA")))
+        .add_horizontal_rule(average, AnnotationStyle::new()
+            .set_dash_array("2,2")
+            .set_label(String::from("Average")));
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Annotations"))
+        .add_view(&line_view)
+        .add_view(&annotation_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Response Time (ms)")
+        .add_bottom_axis_label("Hour")
+        .save("line-chart-with-annotations.svg").unwrap();
+}
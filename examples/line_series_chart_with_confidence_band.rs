@@ -0,0 +1,65 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView, ScatterView, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale that will interpolate values in [0, 10] to values in the
+    // [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 10_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate values in [0, 100] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // A model prediction with its 95% confidence interval, as (x, y, lower bound, upper bound) tuples.
+    let prediction = (0..=10)
+        .map(|x| {
+            let x = x as f32;
+            let y = 20_f32 + 6_f32 * x;
+            let error = 4_f32 + x * 1.2;
+            (x, y, y - error, y + error)
+        })
+        .collect::<Vec<(f32, f32, f32, f32)>>();
+
+    // Observed values with their measurement errors.
+    let observations = vec![(1.5, 31_f32, 26_f32, 36_f32), (4_f32, 41_f32, 35_f32, 47_f32), (6.5, 63_f32, 55_f32, 71_f32), (9_f32, 70_f32, 64_f32, 76_f32)];
+
+    // Create Line series view that is going to represent the prediction with a shaded confidence band.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .set_custom_data_label(String::from("Prediction"))
+        .load_data(&prediction).unwrap();
+
+    // Create Scatter view that is going to represent the observations with error bars.
+    let scatter_view = ScatterView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Square)
+        .set_label_visibility(false)
+        .set_colors(vec![Color::from_hex("#d62728")])
+        .set_custom_data_label(String::from("Observations"))
+        .load_data(&observations).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Confidence Band"))
+        .add_view(&line_view)
+        .add_view(&scatter_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Top)
+        .save("line-chart-with-confidence-band.svg").unwrap();
+}
@@ -0,0 +1,68 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView, Curve, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale that will interpolate values in [0, 200] to values in the
+    // [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 200_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate values in [0, 100] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // The same data is drawn with every curve, shifted upwards so the lines do not overlap.
+    let curves = vec![
+        (Curve::Linear, "Linear"),
+        (Curve::StepAfter, "Step after"),
+        (Curve::Basis, "Basis"),
+        (Curve::Cardinal(0.5), "Cardinal"),
+        (Curve::CatmullRom(0.5), "Catmull-Rom"),
+        (Curve::MonotoneX, "Monotone X"),
+        (Curve::Natural, "Natural"),
+    ];
+    let data = (0..curves.len())
+        .map(|i| {
+            let offset = 11_f32 * i as f32;
+            vec![(10_f32, 5_f32 + offset), (50_f32, 12_f32 + offset), (90_f32, 8_f32 + offset), (130_f32, 15_f32 + offset), (190_f32, 14_f32 + offset)]
+        })
+        .collect::<Vec<Vec<(f32, f32)>>>();
+    let colors = Color::color_scheme_10();
+
+    let views = curves.into_iter().zip(data.iter()).enumerate()
+        .map(|(i, ((curve, label), data))| {
+            LineSeriesView::new()
+                .set_x_scale(&x)
+                .set_y_scale(&y)
+                .set_marker_type(MarkerType::Circle)
+                .set_curve(curve)
+                .set_label_visibility(false)
+                .set_colors(vec![colors[i].clone()])
+                .set_custom_data_label(String::from(label))
+                .load_data(data).unwrap()
+        })
+        .collect::<Vec<LineSeriesView<f32, f32>>>();
+
+    // Generate and save the chart.
+    let mut chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Curves"))
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Top);
+
+    for view in views.iter() {
+        chart = chart.add_view(view);
+    }
+
+    chart.save("line-chart-with-curves.svg").unwrap();
+}
@@ -0,0 +1,40 @@
+use charts::{Chart, ScaleLinear, ScaleBand, MarkerType, PointLabelPosition, LineSeriesView};
+
+fn main() {
+    // Create the scales without setting their ranges. The ranges default to the extent
+    // of the view of the chart whose axes the scales are displayed on.
+    let x = ScaleBand::new()
+        .set_domain(vec![String::from("Mon"), String::from("Tue"), String::from("Wed"), String::from("Thu"), String::from("Fri")]);
+
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32]);
+
+    // You can use your own iterable as data as long as its items implement the `PointDatum` trait.
+    let line_data = vec![
+        (String::from("Mon"), 54), (String::from("Tue"), 40), (String::from("Wed"), 50),
+        (String::from("Thu"), 70), (String::from("Fri"), 65),
+    ];
+
+    // Views position their data when the chart is rendered, so the data can be loaded
+    // before the chart is set up.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_position(PointLabelPosition::N)
+        .load_data(&line_data).unwrap();
+
+    // The axes are resolved when the chart is rendered, so the axes can be added
+    // before the size and the margins of the chart are set.
+    Chart::new()
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .set_width(640)
+        .set_height(480)
+        .set_margins(90, 40, 50, 60)
+        .add_title(String::from("Line Chart with Default Ranges"))
+        .add_left_axis_label("Custom Y Axis Label")
+        .add_bottom_axis_label("Custom X Axis Label")
+        .add_view(&line_view)
+        .save("line-chart-with-default-ranges.svg").unwrap();
+}
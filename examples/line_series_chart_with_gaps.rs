@@ -0,0 +1,68 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView, GapMode, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale that will interpolate values in [0, 24] to values in the
+    // [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 24_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate values in [0, 40] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 40_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Sensor readings with missing values. A datum can also be marked as missing by
+    // implementing `PointDatum::is_defined` or by having a NaN value.
+    let readings = vec![
+        Some(12.1), Some(12.8), Some(13.5), None, None, Some(15.2), Some(16.0), Some(15.1),
+        Some(14.6), None, Some(13.2), Some(12.4), Some(12.0), Some(f32::NAN), Some(11.1),
+    ];
+
+    // Every gap mode is displayed with the same readings, shifted upwards so the lines do not overlap.
+    let gap_modes = vec![(GapMode::Break, "Break"), (GapMode::Bridge, "Bridge"), (GapMode::Interpolate, "Interpolate")];
+    let data = (0..gap_modes.len())
+        .map(|i| {
+            readings.iter().enumerate()
+                .map(|(hour, reading)| (hour as f32 * 1.5, reading.map(|value| value + 8_f32 * i as f32)))
+                .collect::<Vec<(f32, Option<f32>)>>()
+        })
+        .collect::<Vec<Vec<(f32, Option<f32>)>>>();
+    let colors = Color::color_scheme_10();
+
+    let views = gap_modes.into_iter().zip(data.iter()).enumerate()
+        .map(|(i, ((gap_mode, label), data))| {
+            LineSeriesView::new()
+                .set_x_scale(&x)
+                .set_y_scale(&y)
+                .set_marker_type(MarkerType::Circle)
+                .set_gap_mode(gap_mode)
+                .set_label_visibility(false)
+                .set_colors(vec![colors[i].clone()])
+                .set_custom_data_label(String::from(label))
+                .load_data(data).unwrap()
+        })
+        .collect::<Vec<LineSeriesView<f32, f32>>>();
+
+    // Generate and save the chart.
+    let mut chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Gaps"))
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Top);
+
+    for view in views.iter() {
+        chart = chart.add_view(view);
+    }
+
+    chart.save("line-chart-with-gaps.svg").unwrap();
+}
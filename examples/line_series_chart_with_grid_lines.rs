@@ -0,0 +1,52 @@
+use charts::{Chart, ScaleLinear, MarkerType, PointLabelPosition, LineSeriesView, AxisPosition, Color};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale for the X dimension with explicit tick values.
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 200_f32])
+        .set_range(vec![0, width - left - right])
+        .set_tick_values(vec![0_f32, 50_f32, 100_f32, 150_f32, 200_f32]);
+
+    // Create a linear scale for the Y dimension with about 5 ticks.
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0])
+        .set_tick_count(5);
+
+    // You can use your own iterable as data as long as its items implement the `PointDatum` trait.
+    let line_data = vec![(12, 54), (100, 40), (120, 50), (180, 70)];
+
+    // Create Line series view that is going to represent the data.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_position(PointLabelPosition::N)
+        .load_data(&line_data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Gridlines"))
+        .add_view(&line_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Custom Y Axis Label")
+        .add_bottom_axis_label("Custom X Axis Label")
+        .set_grid_lines(AxisPosition::Bottom, true)
+        .set_grid_lines(AxisPosition::Left, true)
+        .set_minor_tick_count(AxisPosition::Left, 1)
+        .set_minor_grid_lines(AxisPosition::Left, true)
+        .set_minor_grid_line_style(AxisPosition::Left, Color::from_hex("#eeeeee"), "4 2")
+        .set_tick_size(AxisPosition::Bottom, 6, 6)
+        .set_tick_padding(AxisPosition::Left, 8)
+        .set_axis_domain_visibility(AxisPosition::Left, false)
+        .save("line-chart-with-grid-lines.svg").unwrap();
+}
@@ -0,0 +1,49 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 70, 50, 60);
+
+    // Create a linear scale for the months of the year.
+    let x = ScaleLinear::new()
+        .set_domain(vec![1_f32, 12_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the temperature in degrees Celsius.
+    let y = ScaleLinear::new()
+        .set_domain(vec![-10_f32, 30_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Average monthly temperatures in degrees Celsius.
+    let line_data = vec![
+        (1_f32, -4.5_f32), (2_f32, -2.1_f32), (3_f32, 3.2_f32), (4_f32, 9.8_f32),
+        (5_f32, 15.6_f32), (6_f32, 20.3_f32), (7_f32, 23.1_f32), (8_f32, 22.4_f32),
+        (9_f32, 17.5_f32), (10_f32, 10.9_f32), (11_f32, 4.2_f32), (12_f32, -1.8_f32),
+    ];
+
+    // Create Line series view that is going to represent the data.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .load_data(&line_data).unwrap();
+
+    // Generate and save the chart. The right axis displays the scale of the left axis
+    // in degrees Fahrenheit, with ticks at round values of degrees Fahrenheit.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Secondary Axis"))
+        .add_view(&line_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_transformed_axis_right(&y, |celsius| celsius * 1.8 + 32_f32, |fahrenheit| (fahrenheit - 32_f32) / 1.8)
+        .add_left_axis_label("Temperature (°C)")
+        .add_right_axis_label("Temperature (°F)")
+        .add_bottom_axis_label("Month")
+        .save("line-chart-with-secondary-axis.svg").unwrap();
+}
@@ -0,0 +1,51 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView, SeriesStyle, LineCap, LineJoin, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale that will interpolate values in [0, 200] to values in the
+    // [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 200_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate values in [0, 100] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // You can use your own iterable as data as long as its items implement the `PointDatum` trait.
+    let line_data = vec![
+        (12_f32, 54_f32, "Actual"), (60_f32, 40_f32, "Actual"), (100_f32, 50_f32, "Actual"), (140_f32, 62_f32, "Actual"),
+        (140_f32, 62_f32, "Forecast"), (170_f32, 70_f32, "Forecast"), (195_f32, 75_f32, "Forecast"),
+        (12_f32, 30_f32, "Target"), (195_f32, 80_f32, "Target"),
+    ];
+
+    // Create Line series view with a different style for every key.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .set_series_style(SeriesStyle::new().set_line_cap(LineCap::Round).set_line_join(LineJoin::Round))
+        .set_key_series_style("Actual", SeriesStyle::new().set_stroke_width(3_f32).set_marker_size(4_f32))
+        .set_key_series_style("Forecast", SeriesStyle::new().set_dash_array("6,4").set_marker_visibility(false))
+        .set_key_series_style("Target", SeriesStyle::new().set_stroke_width(1_f32).set_dash_array("2,2").set_marker_visibility(false))
+        .load_data(&line_data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Styles"))
+        .add_view(&line_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Top)
+        .save("line-chart-with-styles.svg").unwrap();
+}
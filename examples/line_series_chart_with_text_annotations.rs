@@ -0,0 +1,71 @@
+use charts::{Chart, ScaleLinear, MarkerType, LineSeriesView, AnnotationView, AnnotationStyle, PointLabelPosition, Color};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale for the weeks of the year.
+    let x = ScaleLinear::new()
+        .set_domain(vec![1_f32, 20_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the amount of active users.
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 1000_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Weekly active users.
+    let line_data = (1..=20)
+        .map(|week| {
+            let week = week as f32;
+            let users = match week < 8_f32 {
+                true => 150_f32 + 10_f32 * week,
+                false => 230_f32 + 55_f32 * (week - 8_f32),
+            };
+            (week, users)
+        })
+        .collect::<Vec<(f32, f32)>>();
+
+    // Create Line series view that is going to represent the data.
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .load_data(&line_data).unwrap();
+
+    // Point an arrow at the launch, explain the growth in a callout and label the peak.
+    let annotation_view = AnnotationView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .add_arrow(8_f32, 230_f32, AnnotationStyle::new()
+            .set_color(Color::from_hex("#d62728"))
+            .set_offset(0_f32, -80_f32)
+            .set_label(String::from("Launch")))
+        .add_callout(14_f32, 560_f32, AnnotationStyle::new()
+            .set_offset(-60_f32, -60_f32)
+            .set_anchor(PointLabelPosition::NW)
+            .set_wrap_width(140_f32)
+            .set_label(String::from("Steady growth since the launch in week 8")))
+        .add_text(20_f32, 890_f32, AnnotationStyle::new()
+            .set_anchor(PointLabelPosition::W)
+            .set_offset(-8_f32, 0_f32)
+            .set_font_size(14_f32)
+            .set_label(String::from("Peak")));
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Chart with Text Annotations"))
+        .add_view(&line_view)
+        .add_view(&annotation_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Active Users")
+        .add_bottom_axis_label("Week")
+        .save("line-chart-with-text-annotations.svg").unwrap();
+}
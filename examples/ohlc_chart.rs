@@ -0,0 +1,57 @@
+use charts::{Chart, OhlcView, ScaleLinear, Color};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // You can use your own iterable as data as long as its items implement the `OhlcDatum` trait.
+    // Here the data is made of (day, open, high, low, close) tuples. Days 6, 7, 13 and 14 are
+    // non-trading days, which remain visible as gaps since the X scale is a linear one.
+    // Call `.set_gap_skipping(true)` on the view to space the trading days evenly instead.
+    let data = vec![
+        (1_f32, 61.2, 63.4, 60.8, 62.9),
+        (2_f32, 62.9, 64.1, 62.0, 63.5),
+        (3_f32, 63.5, 63.9, 60.7, 61.1),
+        (4_f32, 61.1, 61.8, 58.9, 59.4),
+        (5_f32, 59.4, 60.9, 59.0, 60.6),
+        (8_f32, 60.6, 62.7, 60.2, 62.4),
+        (9_f32, 62.4, 65.3, 62.1, 65.0),
+        (10_f32, 65.0, 65.6, 63.8, 64.2),
+        (11_f32, 64.2, 64.4, 61.5, 61.9),
+        (12_f32, 61.9, 63.2, 61.7, 62.8),
+        (15_f32, 62.8, 63.0, 60.1, 60.5),
+    ];
+
+    // Create a linear scale for the days.
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 16_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the prices.
+    let y = ScaleLinear::new()
+        .set_domain(vec![55_f32, 66_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Create OHLC view that is going to represent the data as OHLC bars.
+    let view = OhlcView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_up_color(Color::from_hex("#1f77b4"))
+        .set_down_color(Color::from_hex("#ff7f0e"))
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("OHLC Chart"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Price")
+        .add_bottom_axis_label("Day")
+        .save("ohlc-chart.svg").unwrap();
+}
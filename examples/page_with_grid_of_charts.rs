@@ -0,0 +1,87 @@
+use charts::{Chart, Grid, Page, AxisPosition, ScaleLinear, ScatterView, LineSeriesView, MarkerType};
+
+fn main() {
+    // Define the sizes of the small charts at the top and of the wide chart below them.
+    // The wide chart spans both columns of the grid, so it is as wide as the two small
+    // charts and the gutter between them.
+    let (small_width, small_height) = (400, 300);
+    let gutter = 20;
+    let wide_width = 2 * small_width + gutter;
+    let (top, right, bottom, left) = (50, 20, 50, 60);
+
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![0, small_width - left - right]);
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![small_height - top - bottom, 0]);
+    let wide_x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![0, wide_width - left - right]);
+
+    // The same keys are displayed in all charts, so they are listed only once in the shared legend.
+    let data = vec![
+        (10_f32, 20_f32, "Control"), (30_f32, 35_f32, "Control"), (55_f32, 40_f32, "Control"), (80_f32, 60_f32, "Control"),
+        (15_f32, 45_f32, "Treatment"), (35_f32, 55_f32, "Treatment"), (60_f32, 70_f32, "Treatment"), (85_f32, 90_f32, "Treatment"),
+    ];
+
+    let scatter_view = ScatterView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_label_visibility(false)
+        .load_data(&data).unwrap();
+
+    let line_view = LineSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .load_data(&data).unwrap();
+
+    let wide_line_view = LineSeriesView::new()
+        .set_x_scale(&wide_x)
+        .set_y_scale(&y)
+        .set_marker_type(MarkerType::Circle)
+        .set_label_visibility(false)
+        .load_data(&data).unwrap();
+
+    let scatter_chart = Chart::new()
+        .set_width(small_width)
+        .set_height(small_height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Scatter"))
+        .add_view(&scatter_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y);
+
+    let line_chart = Chart::new()
+        .set_width(small_width)
+        .set_height(small_height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line"))
+        .add_view(&line_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y);
+
+    let wide_chart = Chart::new()
+        .set_width(wide_width)
+        .set_height(small_height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Line Spanning Two Columns"))
+        .add_view(&wide_line_view)
+        .add_axis_bottom(&wide_x)
+        .add_axis_left(&y);
+
+    // Arrange the charts in a grid and save them on a single page.
+    let grid = Grid::new()
+        .set_gutters(gutter, gutter)
+        .add_chart(scatter_chart, 0, 0)
+        .add_chart(line_chart, 0, 1)
+        .add_chart_spanning(wide_chart, 1, 0, 1, 2);
+
+    Page::new()
+        .add_title(String::from("Page with a Grid of Charts"))
+        .add_grid(grid)
+        .add_legend_at(AxisPosition::Bottom)
+        .save("page-with-grid-of-charts.svg").unwrap();
+}
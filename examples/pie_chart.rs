@@ -0,0 +1,32 @@
+use charts::{Chart, PieView, PieLabelPosition, PieLabelType, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 80, 60);
+
+    // You can use your own iterable as data as long as its items implement the `BarDatum` trait.
+    let data = vec![("Chrome", 64.7), ("Safari", 18.6), ("Edge", 5.1), ("Firefox", 3.2), ("Other", 8.4)];
+
+    // Create Pie view with an inner radius, which turns the pie into a donut.
+    // The pie is placed in the center of the view unless a center is set.
+    let view = PieView::new()
+        .set_outer_radius(170_f32)
+        .set_inner_radius(90_f32)
+        .set_pad_angle(1_f32)
+        .set_corner_radius(4_f32)
+        .set_label_position(PieLabelPosition::Outside)
+        .set_label_type(PieLabelType::Percentage)
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Donut Chart"))
+        .add_view(&view)
+        .add_legend_at(AxisPosition::Bottom)
+        .save("pie-chart.svg").unwrap();
+}
@@ -0,0 +1,71 @@
+use charts::{Chart, ScaleLinear, ScatterView, MarkerType, MarkerStyle, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale that will interpolate values in [0, 100] to values in the
+    // [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate values in [0, 100] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Every view represents one marker shape. Custom markers are defined as SVG paths
+    // that fit within the square from (-1, -1) to (1, 1).
+    let heart = "M 0 1 L -0.9 0 A 0.45 0.45 0 0 1 0 -0.6 A 0.45 0.45 0 0 1 0.9 0 Z";
+    let shapes = vec![
+        (MarkerType::TriangleUp, "Triangle up", MarkerStyle::Filled),
+        (MarkerType::TriangleDown, "Triangle down", MarkerStyle::Hollow),
+        (MarkerType::Diamond, "Diamond", MarkerStyle::Filled),
+        (MarkerType::Cross, "Cross", MarkerStyle::Hollow),
+        (MarkerType::Star, "Star", MarkerStyle::Filled),
+        (MarkerType::Pentagon, "Pentagon", MarkerStyle::Hollow),
+        (MarkerType::Hexagon, "Hexagon", MarkerStyle::Filled),
+        (MarkerType::Custom(heart), "Custom", MarkerStyle::Filled),
+    ];
+    let colors = Color::color_scheme_10();
+
+    let data = shapes.iter().enumerate()
+        .map(|(i, (_, key, _))| vec![
+            (10_f32 + 10_f32 * i as f32, 20_f32 + 5_f32 * i as f32, *key),
+            (15_f32 + 10_f32 * i as f32, 60_f32 + 3_f32 * i as f32, *key),
+        ])
+        .collect::<Vec<Vec<(f32, f32, &str)>>>();
+
+    let views = shapes.into_iter().zip(data.iter()).enumerate()
+        .map(|(i, ((marker_type, _, marker_style), data))| {
+            ScatterView::new()
+                .set_x_scale(&x)
+                .set_y_scale(&y)
+                .set_marker_type(marker_type)
+                .set_marker_style(marker_style)
+                .set_label_visibility(false)
+                .set_colors(vec![colors[i].clone()])
+                .load_data(data).unwrap()
+        })
+        .collect::<Vec<ScatterView<f32, f32>>>();
+
+    // Generate and save the chart.
+    let mut chart = Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Scatter Chart with Marker Shapes"))
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Top);
+
+    for view in views.iter() {
+        chart = chart.add_view(view);
+    }
+
+    chart.save("scatter-chart-marker-shapes.svg").unwrap();
+}
@@ -0,0 +1,43 @@
+use charts::{Chart, ScaleLinear, ScatterView, MarkerType, Color, PointLabelPosition, AxisPosition};
+
+fn main() {
+    // Create the scales without setting their ranges. The margins of the chart are computed
+    // from the space that the title, the axes and the legend need, and the ranges default
+    // to the extent of the remaining view.
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 200000_f32]);
+
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32]);
+
+    // You can use your own iterable as data as long as its items implement the `PointDatum` trait.
+    let scatter_data = vec![
+        (120000, 90, "Northern Hemisphere"),
+        (12000, 54, "Northern Hemisphere"),
+        (100000, 40, "Southern Hemisphere"),
+        (180000, 10, "Equatorial Belt"),
+    ];
+
+    // Create Scatter view that is going to represent the data as points.
+    let scatter_view = ScatterView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_label_position(PointLabelPosition::E)
+        .set_marker_type(MarkerType::Circle)
+        .set_colors(Color::color_scheme_dark())
+        .load_data(&scatter_data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(800)
+        .set_height(520)
+        .set_auto_layout(true)
+        .add_title(String::from("Scatter Chart with Auto Layout"))
+        .add_view(&scatter_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Relative Humidity (%)")
+        .add_bottom_axis_label("Distance from the Measurement Station (m)")
+        .add_legend_at(AxisPosition::Right)
+        .save("scatter-chart-with-auto-layout.svg").unwrap();
+}
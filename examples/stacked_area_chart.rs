@@ -0,0 +1,50 @@
+use charts::{Chart, ScaleLinear, AreaSeriesView, StackOffset, Curve, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a linear scale that will interpolate values in [0, 10] to values in the
+    // [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 10_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the stacked values. The Silhouette offset centers the
+    // stacks around zero, so the domain spans both negative and positive values.
+    let y = ScaleLinear::new()
+        .set_domain(vec![-30_f32, 30_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // You can use your own iterable as data as long as its items implement the `PointDatum` trait.
+    let mut area_data = Vec::new();
+    for i in 0..=10 {
+        let x = i as f32;
+        area_data.push((x, 8_f32 + 4_f32 * (x / 2_f32).sin(), "Rock"));
+        area_data.push((x, 6_f32 + x, "Pop"));
+        area_data.push((x, 14_f32 - x, "Jazz"));
+        area_data.push((x, 5_f32 + 3_f32 * (x / 3_f32).cos(), "Folk"));
+    }
+
+    // Create an Area series view that stacks the areas of the keys as a streamgraph.
+    let area_view = AreaSeriesView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_stack_offset(StackOffset::Silhouette)
+        .set_curve(Curve::MonotoneX)
+        .load_stacked_data(&area_data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Stacked Area Chart"))
+        .add_view(&area_view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Top)
+        .save("stacked-area-chart.svg").unwrap();
+}
@@ -0,0 +1,42 @@
+use charts::{Chart, VerticalBarView, ScaleBand, ScaleLinear, ColorScale, Color, AxisPosition};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 80, 60);
+
+    // You can use your own iterable as data as long as its items implement the `BarDatum` trait.
+    let data = vec![("Jan", 12), ("Feb", 8), ("Mar", -5), ("Apr", 15), ("May", -9), ("Jun", 21)];
+
+    // Create a band scale that maps the months to values in the [0, availableWidth] range.
+    let x = ScaleBand::new()
+        .set_domain(data.iter().map(|datum| datum.0.to_string()).collect())
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale for the profit, which can also be negative.
+    let y = ScaleLinear::new()
+        .set_domain(vec![-10_f32, 25_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Create VerticalBar view that colors every bar by its value, from red for losses to blue
+    // for profits. Since no domain is set, the color scale spans the extent of the data.
+    let view = VerticalBarView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_custom_data_label(String::from("Profit"))
+        .set_color_scale(ColorScale::new(Color::color_scheme_red_blue()))
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Bar Chart with a Color Scale"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_legend_at(AxisPosition::Bottom)
+        .save("vertical-bar-chart-with-color-scale.svg").unwrap();
+}
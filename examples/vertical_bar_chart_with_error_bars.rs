@@ -0,0 +1,74 @@
+use charts::{Chart, VerticalBarView, ScaleBand, ScaleLinear, BarDatum, BarLabelPosition, symmetric_bounds};
+
+/// A measurement that reports a mean value together with its standard error.
+struct Measurement {
+    group: String,
+    mean: f32,
+    standard_error: f32,
+}
+
+// Implement the `BarDatum` trait to use the measurements as data,
+// returning the bounds of the error bars from `get_bounds`, one standard error
+// on either side of the mean.
+impl BarDatum for Measurement {
+    fn get_category(&self) -> String {
+        self.group.clone()
+    }
+
+    fn get_value(&self) -> f32 {
+        self.mean
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn get_bounds(&self) -> Option<(f32, f32)> {
+        Some(symmetric_bounds(self.mean, self.standard_error))
+    }
+}
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 60);
+
+    // Create a band scale that maps the experiment groups to values in the [0, availableWidth]
+    // range (the width of the chart without the margins).
+    let x = ScaleBand::new()
+        .set_domain(vec![String::from("Control"), String::from("Variant A"), String::from("Variant B")])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a linear scale that will interpolate values in [0, 100] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    let data = vec![
+        Measurement { group: String::from("Control"), mean: 52_f32, standard_error: 6_f32 },
+        Measurement { group: String::from("Variant A"), mean: 61_f32, standard_error: 4.5 },
+        Measurement { group: String::from("Variant B"), mean: 74_f32, standard_error: 9_f32 },
+    ];
+
+    // Create VerticalBar view that is going to represent the data as vertical bars with error bars.
+    let view = VerticalBarView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .set_label_position(BarLabelPosition::Center)
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Bar Chart with Error Bars"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Conversion Score")
+        .add_bottom_axis_label("Experiment Group")
+        .save("vertical-bar-chart-with-error-bars.svg").unwrap();
+}
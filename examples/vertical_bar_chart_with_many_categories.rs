@@ -0,0 +1,49 @@
+use charts::{Chart, VerticalBarView, ScaleBand, ScaleLinear, AxisPosition, TickLabelCollision};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 120, 60);
+
+    // Many categories with long names, whose labels would overlap on the bottom axis.
+    let data = vec![
+        ("United States", 90), ("United Kingdom", 72), ("New Zealand", 35), ("South Africa", 41),
+        ("Czech Republic", 28), ("Dominican Republic", 19), ("Papua New Guinea", 12), ("Costa Rica", 24),
+        ("Saudi Arabia", 55), ("Sri Lanka", 31), ("North Macedonia", 17), ("El Salvador", 22),
+    ];
+
+    // Create a band scale that maps the categories to values in the [0, availableWidth]
+    // range (the width of the chart without the margins).
+    let x = ScaleBand::new()
+        .set_domain(data.iter().map(|(category, _)| category.to_string()).collect())
+        .set_range(vec![0, width - left - right])
+        .set_inner_padding(0.1)
+        .set_outer_padding(0.1);
+
+    // Create a linear scale that will interpolate values in [0, 100] range to corresponding
+    // values in [availableHeight, 0] range (the height of the chart without the margins).
+    let y = ScaleLinear::new()
+        .set_domain(vec![0_f32, 100_f32])
+        .set_range(vec![height - top - bottom, 0]);
+
+    // Create VerticalBar view that is going to represent the data as vertical bars.
+    let view = VerticalBarView::new()
+        .set_x_scale(&x)
+        .set_y_scale(&y)
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart. The labels of the bottom axis are rotated,
+    // since they would overlap otherwise.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Bar Chart with Many Categories"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_left_axis_label("Units of Measurement")
+        .set_tick_label_collision(AxisPosition::Bottom, TickLabelCollision::Rotate)
+        .save("vertical-bar-chart-with-many-categories.svg").unwrap();
+}
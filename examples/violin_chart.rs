@@ -0,0 +1,47 @@
+use charts::{Chart, ViolinView, ScaleBand, ScaleLinear, Orientation};
+
+fn main() {
+    // Define chart related sizes.
+    let width = 800;
+    let height = 600;
+    let (top, right, bottom, left) = (90, 40, 50, 90);
+
+    // Create a linear scale that will interpolate latencies in [0, 500] range to corresponding
+    // values in [0, availableWidth] range (the width of the chart without the margins).
+    let x = ScaleLinear::new()
+        .set_domain(vec![0_f32, 500_f32])
+        .set_range(vec![0, width - left - right]);
+
+    // Create a band scale that maps the endpoints to values in the [0, availableHeight]
+    // range (the height of the chart without the margins).
+    let y = ScaleBand::new()
+        .set_domain(vec![String::from("/login"), String::from("/search"), String::from("/checkout")])
+        .set_range(vec![0, height - top - bottom]);
+
+    // Each entry is a single latency sample of an endpoint.
+    let data = vec![
+        ("/login", 120), ("/login", 135), ("/login", 150), ("/login", 142), ("/login", 160), ("/login", 128),
+        ("/search", 210), ("/search", 250), ("/search", 230), ("/search", 275), ("/search", 190), ("/search", 320),
+        ("/checkout", 310), ("/checkout", 330), ("/checkout", 295), ("/checkout", 420), ("/checkout", 340), ("/checkout", 300),
+    ];
+
+    // Create Violin view that is going to represent the latency distributions horizontally.
+    let view = ViolinView::new()
+        .set_orientation(Orientation::Horizontal)
+        .set_category_scale(&y)
+        .set_value_scale(&x)
+        .set_bandwidth(20_f32)
+        .load_data(&data).unwrap();
+
+    // Generate and save the chart.
+    Chart::new()
+        .set_width(width)
+        .set_height(height)
+        .set_margins(top, right, bottom, left)
+        .add_title(String::from("Violin Chart"))
+        .add_view(&view)
+        .add_axis_bottom(&x)
+        .add_axis_left(&y)
+        .add_bottom_axis_label("Latency (ms)")
+        .save("violin-chart.svg").unwrap();
+}
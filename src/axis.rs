@@ -3,10 +3,14 @@ use svg::node::element::Group;
 use svg::parser::Error;
 use svg::Node;
 use svg::node::Text as TextNode;
-use svg::node::element::Text;
-use crate::{Scale, Chart};
-use crate::components::axis::{AxisLine, AxisTick};
-use crate::scales::ScaleType;
+use svg::node::element::{Line, Text};
+use crate::{Scale, Chart, StylingMode};
+use crate::colors::Color;
+use crate::components::axis::{AxisLine, AxisTick, wrap_label, truncate_label};
+use crate::text::FontMetrics;
+use crate::scales::{ScaleType, get_scale_id};
+use crate::scales::linear::ScaleLinear;
+use crate::theme::{Theme, Presentation};
 
 /// Enum of possible axis positions on the chart.
 #[derive(Copy, Clone, PartialEq)]
@@ -17,49 +21,204 @@ pub enum AxisPosition {
     Left,
 }
 
+/// Enum of strategies to prevent tick labels from overlapping. A strategy is only applied
+/// when the labels would overlap. Labels of vertical axes can only overlap vertically,
+/// therefore all strategies thin out the labels on vertical axes.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TickLabelCollision {
+    /// Leave the labels as they are.
+    None,
+    /// Display only every n-th label.
+    Thin,
+    /// Rotate the labels by 45 degrees or, if that is not enough, by 90 degrees.
+    Rotate,
+    /// Alternate the labels between two rows.
+    Stagger,
+    /// Break the labels into multiple lines at whitespace.
+    Wrap,
+    /// Shorten the labels with an ellipsis.
+    Truncate,
+}
+
+/// The minimum distance between two tick labels.
+const TICK_LABEL_GAP: f32 = 4_f32;
+
+/// The distance between the tick labels and the axis label.
+const AXIS_LABEL_GAP: f32 = 10_f32;
+
+/// The part of a scale that an axis needs, independent of the type of the scale's domain.
+pub(crate) trait AxisScale {
+    /// Get the offsets of the ticks along the axis and their labels.
+    fn get_ticks(&self) -> Vec<(f32, String)>;
+
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType;
+
+    /// Set the range that the scale uses if no range was set explicitly.
+    fn set_default_range(&self, range: Vec<isize>);
+}
+
+impl<T: ToString> AxisScale for &dyn Scale<T> {
+    fn get_ticks(&self) -> Vec<(f32, String)> {
+        // Ticks of band scales are placed in the middle of the bands.
+        let bandwidth_offset = match self.get_type() {
+            ScaleType::Band => self.bandwidth().unwrap() / 2_f32,
+            _ => 0_f32,
+        };
+
+        Scale::get_ticks(*self).iter()
+            .map(|tick| (self.scale(tick) + bandwidth_offset, tick.to_string()))
+            .collect()
+    }
+
+    fn get_type(&self) -> ScaleType {
+        Scale::get_type(*self)
+    }
+
+    fn set_default_range(&self, range: Vec<isize>) {
+        Scale::set_default_range(*self, range);
+    }
+}
+
+/// A continuous scale that is displayed in other units, which are computed from the domain
+/// of the scale with a forward transform and converted back with an inverse transform.
+/// The ticks are placed at round values of the transformed units.
+pub(crate) struct TransformedScale<'a> {
+    scale: &'a dyn Scale<f32>,
+    forward: Box<dyn Fn(f32) -> f32 + 'a>,
+    inverse: Box<dyn Fn(f32) -> f32 + 'a>,
+}
+
+impl<'a> TransformedScale<'a> {
+    /// Create a new transformed scale from a scale and the transforms of its domain.
+    pub(crate) fn new(scale: &'a dyn Scale<f32>, forward: impl Fn(f32) -> f32 + 'a, inverse: impl Fn(f32) -> f32 + 'a) -> Self {
+        Self { scale, forward: Box::new(forward), inverse: Box::new(inverse) }
+    }
+}
+
+impl<'a> AxisScale for TransformedScale<'a> {
+    fn get_ticks(&self) -> Vec<(f32, String)> {
+        let (start, end) = match (self.scale.invert(self.scale.range_start()), self.scale.invert(self.scale.range_end())) {
+            (Some(start), Some(end)) => ((self.forward)(start), (self.forward)(end)),
+            _ => return Vec::new(),
+        };
+
+        // Aim for as many ticks as the scale displays in its own units.
+        let tick_count = Scale::get_ticks(self.scale).len().max(2);
+        ScaleLinear::new()
+            .set_domain(vec![start.min(end), start.max(end)])
+            .set_tick_count(tick_count)
+            .get_ticks().iter()
+            .map(|tick| (self.scale.scale(&(self.inverse)(*tick)), tick.to_string()))
+            .collect()
+    }
+
+    fn get_type(&self) -> ScaleType {
+        self.scale.get_type()
+    }
+
+    fn set_default_range(&self, range: Vec<isize>) {
+        self.scale.set_default_range(range);
+    }
+}
+
 /// An axis struct that represents an axis along a dimension of the chart.
+#[derive(Clone)]
 pub struct Axis {
-    ticks: Vec<AxisTick>,
-    axis_line: AxisLine,
+    ticks: Vec<(f32, String)>,
+    scale_type: ScaleType,
+    scale_id: usize,
     position: AxisPosition,
     label: String,
+    label_color: String,
+    label_color_from_series: bool,
     label_rotation: isize,
     label_format: String,
+    label_collision: TickLabelCollision,
     length: isize,
+    span: isize,
+    tick_size_inner: usize,
+    tick_size_outer: usize,
+    tick_padding: usize,
+    minor_tick_count: usize,
+    domain_visible: bool,
+    grid_lines_visible: bool,
+    grid_line_color: String,
+    grid_line_dash_array: String,
+    minor_grid_lines_visible: bool,
+    minor_grid_line_color: String,
+    minor_grid_line_dash_array: String,
+    font_metrics: FontMetrics,
+    theme: Theme,
 }
 
 impl Axis {
     /// Create a new instance of an axis for a chart based on the provided scale and position.
-    fn new<'a, T: ToString>(scale: &'a dyn Scale<T>, position: AxisPosition, chart: &Chart<'a>) -> Self {
-        Self {
-            ticks: Self::generate_ticks(scale, position),
+    pub(crate) fn new(scale: &dyn AxisScale, scale_id: usize, position: AxisPosition, chart: &Chart) -> Self {
+        let mut axis = Self {
+            ticks: scale.get_ticks(),
+            scale_type: scale.get_type(),
+            scale_id,
             position,
-            axis_line: Self::get_axis_line(position, chart),
             label: String::new(),
+            label_color: String::from("#777"),
+            label_color_from_series: false,
             label_rotation: 0,
             label_format: String::new(),
+            label_collision: TickLabelCollision::None,
             length: Self::get_axis_length(position, chart),
-        }
+            span: Self::get_axis_span(position, chart),
+            tick_size_inner: 6,
+            tick_size_outer: 0,
+            tick_padding: Self::get_default_tick_padding(position),
+            minor_tick_count: 0,
+            domain_visible: true,
+            grid_lines_visible: false,
+            grid_line_color: String::from("#e5e5e5"),
+            grid_line_dash_array: String::new(),
+            minor_grid_lines_visible: false,
+            minor_grid_line_color: String::from("#f2f2f2"),
+            minor_grid_line_dash_array: String::new(),
+            font_metrics: chart.get_font_metrics().clone(),
+            theme: Theme::light(),
+        };
+        axis.set_theme(chart.get_theme());
+
+        axis
     }
 
     /// Create a new axis at the top of the chart.
     pub fn new_top_axis<'a, T: ToString>(scale: &'a dyn Scale<T>, chart: &Chart<'a>) -> Self {
-        Self::new(scale, AxisPosition::Top, chart)
+        Self::new(&scale, get_scale_id(scale), AxisPosition::Top, chart)
     }
 
     /// Create a new axis to the right of the chart.
     pub fn new_right_axis<'a, T: ToString>(scale: &'a dyn Scale<T>, chart: &Chart<'a>) -> Self {
-        Self::new(scale, AxisPosition::Right, chart)
+        Self::new(&scale, get_scale_id(scale), AxisPosition::Right, chart)
     }
 
     /// Create a new axis at the bottom of the chart.
     pub fn new_bottom_axis<'a, T: ToString>(scale: &'a dyn Scale<T>, chart: &Chart<'a>) -> Self {
-        Self::new(scale, AxisPosition::Bottom, chart)
+        Self::new(&scale, get_scale_id(scale), AxisPosition::Bottom, chart)
     }
 
     /// Create a new axis to the left of the chart.
     pub fn new_left_axis<'a, T: ToString>(scale: &'a dyn Scale<T>, chart: &Chart<'a>) -> Self {
-        Self::new(scale, AxisPosition::Left, chart)
+        Self::new(&scale, get_scale_id(scale), AxisPosition::Left, chart)
+    }
+
+    /// Create a new axis at the given position that displays a continuous scale in other units
+    /// (e.g. degrees Fahrenheit for a scale in degrees Celsius). The forward function converts
+    /// values of the scale's domain to the displayed units, and the inverse function converts
+    /// the displayed units back to the scale's domain.
+    pub fn new_transformed_axis<'a>(
+        scale: &'a dyn Scale<f32>,
+        forward: impl Fn(f32) -> f32 + 'a,
+        inverse: impl Fn(f32) -> f32 + 'a,
+        position: AxisPosition,
+        chart: &Chart<'a>,
+    ) -> Self {
+        Self::new(&TransformedScale::new(scale, forward, inverse), get_scale_id(scale), position, chart)
     }
 
     /// Set axis label.
@@ -67,26 +226,173 @@ impl Axis {
         self.label = label;
     }
 
+    /// Set the color of the axis label.
+    pub fn set_label_color(&mut self, color: Color) {
+        self.label_color = color.as_hex();
+    }
+
+    /// Set whether the axis label takes the color of the series that is displayed on the axis
+    /// (see `Chart::add_view_on_axes`), which identifies the axis on charts with two Y axes.
+    pub fn set_label_color_from_series(&mut self, enabled: bool) {
+        self.label_color_from_series = enabled;
+    }
+
+    /// Return whether the axis label takes the color of the series displayed on the axis.
+    pub(crate) fn is_label_color_from_series(&self) -> bool {
+        self.label_color_from_series
+    }
+
     /// Set tick label rotation.
     pub fn set_tick_label_rotation(&mut self, rotation: isize) {
         self.label_rotation = rotation;
-        self.ticks.iter_mut().for_each(|tick| tick.set_label_rotation(rotation));
     }
 
     /// Set the label format.
     pub fn set_tick_label_format(&mut self, format: &str) {
         self.label_format = String::from(format);
-        let label_format = self.label_format.as_str();
-        self.ticks.iter_mut().for_each(|tick| tick.set_label_format(label_format));
+    }
+
+    /// Apply the theme to the axis, which replaces the label color, the tick size,
+    /// the visibility of the axis line and the gridline styles of the axis.
+    pub(crate) fn set_theme(&mut self, theme: &Theme) {
+        let (grid_line_color, grid_line_dash_array) = theme.get_grid_line_style();
+        let (minor_grid_line_color, minor_grid_line_dash_array) = theme.get_minor_grid_line_style();
+        self.label_color = String::from(theme.get_text_color());
+        self.tick_size_inner = theme.get_tick_size();
+        self.domain_visible = theme.is_axis_domain_visible();
+        self.grid_line_color = String::from(grid_line_color);
+        self.grid_line_dash_array = String::from(grid_line_dash_array);
+        self.minor_grid_line_color = String::from(minor_grid_line_color);
+        self.minor_grid_line_dash_array = String::from(minor_grid_line_dash_array);
+        self.theme = theme.clone();
+    }
+
+    /// Set how the elements of the axis are styled.
+    pub(crate) fn set_styling_mode(&mut self, styling_mode: StylingMode) {
+        self.theme = self.theme.clone().set_styling(styling_mode, &[]);
+    }
+
+    /// Set the font metrics that are used to measure the labels.
+    pub fn set_font_metrics(&mut self, font_metrics: FontMetrics) {
+        self.font_metrics = font_metrics;
+    }
+
+    /// Set the strategy to apply when tick labels would overlap.
+    pub fn set_tick_label_collision(&mut self, strategy: TickLabelCollision) {
+        self.label_collision = strategy;
+    }
+
+    /// Set the length of the tick lines (inner) and of the ticks at both ends
+    /// of the axis line (outer).
+    pub fn set_tick_size(&mut self, inner: usize, outer: usize) {
+        self.tick_size_inner = inner;
+        self.tick_size_outer = outer;
+    }
+
+    /// Set the distance between the tick lines and the tick labels.
+    pub fn set_tick_padding(&mut self, padding: usize) {
+        self.tick_padding = padding;
+    }
+
+    /// Set the amount of minor ticks to display between every two major ticks.
+    pub fn set_minor_tick_count(&mut self, count: usize) {
+        self.minor_tick_count = count;
+    }
+
+    /// Set whether the axis (domain) line is displayed.
+    pub fn set_domain_visibility(&mut self, visible: bool) {
+        self.domain_visible = visible;
+    }
+
+    /// Set whether gridlines are drawn across the view at every major tick.
+    pub fn set_grid_lines(&mut self, visible: bool) {
+        self.grid_lines_visible = visible;
+    }
+
+    /// Set the stroke color and dash array (e.g. "4 2") of the major gridlines.
+    /// An empty dash array draws solid lines.
+    pub fn set_grid_line_style(&mut self, color: Color, dash_array: &str) {
+        self.grid_line_color = color.as_hex();
+        self.grid_line_dash_array = String::from(dash_array);
+    }
+
+    /// Set whether gridlines are drawn across the view at every minor tick
+    /// (see `set_minor_tick_count`).
+    pub fn set_minor_grid_lines(&mut self, visible: bool) {
+        self.minor_grid_lines_visible = visible;
+    }
+
+    /// Set the stroke color and dash array (e.g. "4 2") of the minor gridlines.
+    /// An empty dash array draws solid lines.
+    pub fn set_minor_grid_line_style(&mut self, color: Color, dash_array: &str) {
+        self.minor_grid_line_color = color.as_hex();
+        self.minor_grid_line_dash_array = String::from(dash_array);
     }
 
     /// Return whether the axis has a label or not.
     pub fn has_label(&self) -> bool {
-        self.label.len() > 0
+        !self.label.is_empty()
+    }
+
+    /// Get the distance from the axis line to the farthest edge of the ticks and their labels.
+    fn get_ticks_extent(&self, ticks: &[AxisTick]) -> f32 {
+        ticks.iter()
+            .map(|tick| tick.get_extent(&self.font_metrics))
+            .fold(self.tick_size_outer as f32, f32::max)
+    }
+
+    /// Get the distance from the axis line to the farthest edge of the axis,
+    /// including its tick labels and label.
+    pub fn get_extent(&self) -> f32 {
+        let ticks_extent = self.get_ticks_extent(&self.resolve_label_collisions());
+
+        match self.has_label() {
+            true => ticks_extent + AXIS_LABEL_GAP + self.font_metrics.text_height(self.theme.get_axis_label_font_size()),
+            false => ticks_extent,
+        }
+    }
+
+    /// Get how far the tick labels extend beyond the start and the end of the axis.
+    pub fn get_overhang(&self) -> (f32, f32) {
+        self.resolve_label_collisions().iter()
+            .map(|tick| tick.get_span(&self.font_metrics))
+            .fold((0_f32, 0_f32), |(before, after), (start, end)| {
+                (before.max(-start), after.max(end - self.length as f32))
+            })
+    }
+
+    /// Get the identifier of the axis' scale (see `get_scale_id`).
+    pub(crate) fn get_scale_id(&self) -> usize {
+        self.scale_id
+    }
+
+    /// Fit the axis to a view of the given size. The range of the axis' scale is set to the
+    /// extent of the view, unless the range of the scale was set explicitly, and the ticks are
+    /// regenerated from the resulting range. The range of vertical continuous scales is reversed,
+    /// so that values increase upwards.
+    pub(crate) fn resolve(&mut self, scale: &dyn AxisScale, view_width: isize, view_height: isize) {
+        let range = match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => vec![0, view_width],
+            AxisPosition::Left | AxisPosition::Right if self.scale_type == ScaleType::Band => vec![0, view_height],
+            AxisPosition::Left | AxisPosition::Right => vec![view_height, 0],
+        };
+        scale.set_default_range(range);
+        self.ticks = scale.get_ticks();
+        self.set_view_size(view_width, view_height);
+    }
+
+    /// Set the width and height of the view that the axis belongs to.
+    pub(crate) fn set_view_size(&mut self, width: isize, height: isize) {
+        let (length, span) = match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => (width, height),
+            AxisPosition::Left | AxisPosition::Right => (height, width),
+        };
+        self.length = length;
+        self.span = span;
     }
 
     /// Compute the length of the axis.
-    fn get_axis_length<'a>(position: AxisPosition, chart: &Chart<'a>) -> isize {
+    fn get_axis_length(position: AxisPosition, chart: &Chart) -> isize {
         if position == AxisPosition::Top || position == AxisPosition::Bottom {
             chart.get_view_width()
         } else {
@@ -94,6 +400,166 @@ impl Axis {
         }
     }
 
+    /// Return a copy of the ticks with the tick label collision strategy applied.
+    fn resolve_label_collisions(&self) -> Vec<AxisTick> {
+        let mut ticks = self.generate_ticks();
+        if self.label_collision == TickLabelCollision::None || ticks.len() < 2 {
+            return ticks;
+        }
+
+        let horizontal = self.position == AxisPosition::Top || self.position == AxisPosition::Bottom;
+        let font_size = self.theme.get_tick_label_font_size();
+
+        // Order the ticks along the axis and get the extent of their labels along the axis.
+        let mut order = (0..ticks.len()).collect::<Vec<usize>>();
+        order.sort_by(|a, b| ticks[*a].get_tick_offset().total_cmp(&ticks[*b].get_tick_offset()));
+        let extents = ticks.iter().map(|tick| {
+            if horizontal {
+                self.font_metrics.text_width(&tick.get_formatted_label(), font_size)
+            } else {
+                font_size
+            }
+        }).collect::<Vec<f32>>();
+
+        // Check whether the labels overlap when only every `step`-th label is displayed.
+        let overlaps = |step: usize| {
+            order.iter().step_by(step).collect::<Vec<&usize>>().windows(2).any(|pair| {
+                let distance = (ticks[*pair[1]].get_tick_offset() - ticks[*pair[0]].get_tick_offset()).abs();
+                distance < (extents[*pair[0]] + extents[*pair[1]]) / 2_f32 + TICK_LABEL_GAP
+            })
+        };
+
+        if !overlaps(1) {
+            return ticks;
+        }
+
+        let min_spacing = order.windows(2)
+            .map(|pair| (ticks[pair[1]].get_tick_offset() - ticks[pair[0]].get_tick_offset()).abs())
+            .fold(f32::INFINITY, f32::min);
+        let strategy = if horizontal { self.label_collision } else { TickLabelCollision::Thin };
+
+        match strategy {
+            TickLabelCollision::Thin => {
+                let step = (2..ticks.len()).find(|step| !overlaps(*step)).unwrap_or(ticks.len());
+                for (i, index) in order.iter().enumerate() {
+                    if i % step != 0 {
+                        ticks[*index].set_label_visibility(false);
+                    }
+                }
+            },
+            TickLabelCollision::Rotate => {
+                // Rotated labels are as far apart as the distance between the ticks
+                // multiplied by the sine of the rotation.
+                let rotation = if min_spacing * std::f32::consts::FRAC_1_SQRT_2 >= font_size { 45 } else { 90 };
+                let anchor = if self.position == AxisPosition::Top { "start" } else { "end" };
+                for tick in ticks.iter_mut() {
+                    tick.set_label_rotation(-rotation);
+                    tick.set_label_text_anchor(anchor);
+                }
+            },
+            TickLabelCollision::Stagger => {
+                for index in order.iter().skip(1).step_by(2) {
+                    let label_offset = ticks[*index].get_label_offset();
+                    ticks[*index].set_label_offset(label_offset + font_size as usize + 2);
+                }
+            },
+            TickLabelCollision::Wrap => {
+                for tick in ticks.iter_mut() {
+                    let lines = wrap_label(&tick.get_formatted_label(), min_spacing - TICK_LABEL_GAP, font_size, &self.font_metrics);
+                    tick.set_label_lines(lines);
+                }
+            },
+            TickLabelCollision::Truncate => {
+                for tick in ticks.iter_mut() {
+                    let label = truncate_label(&tick.get_formatted_label(), min_spacing - TICK_LABEL_GAP, font_size, &self.font_metrics);
+                    tick.set_label_lines(vec![label]);
+                }
+            },
+            TickLabelCollision::None => {},
+        }
+
+        ticks
+    }
+
+    /// Compute the extent of the view perpendicular to the axis, which the gridlines span.
+    fn get_axis_span(position: AxisPosition, chart: &Chart) -> isize {
+        if position == AxisPosition::Top || position == AxisPosition::Bottom {
+            chart.get_view_height()
+        } else {
+            chart.get_view_width()
+        }
+    }
+
+    /// Get the default distance between the tick lines and the tick labels.
+    fn get_default_tick_padding(position: AxisPosition) -> usize {
+        if position == AxisPosition::Top || position == AxisPosition::Bottom {
+            10
+        } else {
+            6
+        }
+    }
+
+    /// Compute the offsets of the minor ticks, evenly spaced between every two major ticks.
+    fn get_minor_tick_offsets(&self) -> Vec<f32> {
+        let mut major_offsets = self.ticks.iter().map(|(offset, _)| *offset).collect::<Vec<f32>>();
+        major_offsets.sort_by(|a, b| a.total_cmp(b));
+
+        let mut offsets = Vec::new();
+        for pair in major_offsets.windows(2) {
+            let step = (pair[1] - pair[0]) / (self.minor_tick_count + 1) as f32;
+            for i in 1..=self.minor_tick_count {
+                offsets.push(pair[0] + step * i as f32);
+            }
+        }
+
+        offsets
+    }
+
+    /// Get a line perpendicular to the axis at the given offset, pointing away from the view
+    /// for positive lengths and across the view for negative ones.
+    fn get_perpendicular_line(&self, offset: f32, length: f32) -> Line {
+        let (x1, y1, x2, y2) = match self.position {
+            AxisPosition::Top => (offset, 0_f32, offset, -length),
+            AxisPosition::Bottom => (offset, 0_f32, offset, length),
+            AxisPosition::Left => (0_f32, offset, -length, offset),
+            AxisPosition::Right => (0_f32, offset, length, offset),
+        };
+
+        Line::new()
+            .set("x1", x1)
+            .set("y1", y1)
+            .set("x2", x2)
+            .set("y2", y2)
+            .set("shape-rendering", "crispEdges")
+    }
+
+    /// Generate the gridlines at the given offsets, along with the style that the stylesheet of the
+    /// chart gives them (see `Theme::get_grid_line_style`).
+    fn get_grid_lines(&self, offsets: &[f32], class: &str, style: (&str, &str), styled: (&str, &str)) -> Group {
+        let (color, dash_array) = style;
+        let styled = Some(styled).filter(|_| self.theme.is_styled_with_classes());
+        let mut group = Group::new()
+            .set("class", class);
+
+        for offset in offsets.iter() {
+            let mut line = self.get_perpendicular_line(*offset, -self.span as f32)
+                .set("class", "grid-line");
+            let mut presentation = Presentation::new()
+                .set("stroke", color, styled.map(|(color, _)| color))
+                .set("stroke-width", 1, styled.map(|_| "1px"));
+            // Solid lines are only set explicitly if the stylesheet dashes them.
+            let styled_dash_array = styled.map(|(_, dash_array)| dash_array).filter(|dash_array| !dash_array.is_empty());
+            if !dash_array.is_empty() || styled_dash_array.is_some() {
+                let dash_array = Some(dash_array).filter(|dash_array| !dash_array.is_empty()).unwrap_or("none");
+                presentation = presentation.set("stroke-dasharray", dash_array, styled_dash_array);
+            }
+            presentation.assign_to(&mut line);
+            group.append(line);
+        }
+
+        group
+    }
+
     /// Generate svg for the axis.
     pub fn to_svg(&self) -> Result<Group, Error> {
         let axis_class = match self.position {
@@ -104,71 +570,244 @@ impl Axis {
         };
 
         let mut group = Group::new()
-            .set("class", axis_class)
-            .add(self.axis_line.to_svg().unwrap());
+            .set("class", axis_class);
+
+        let minor_tick_offsets = self.get_minor_tick_offsets();
+        if self.minor_grid_lines_visible && !minor_tick_offsets.is_empty() {
+            let style = (self.minor_grid_line_color.as_str(), self.minor_grid_line_dash_array.as_str());
+            group.append(self.get_grid_lines(&minor_tick_offsets, "minor-grid", style, self.theme.get_minor_grid_line_style()));
+        }
 
-        for tick in self.ticks.iter() {
+        if self.grid_lines_visible {
+            let major_tick_offsets = self.ticks.iter().map(|(offset, _)| *offset).collect::<Vec<f32>>();
+            let style = (self.grid_line_color.as_str(), self.grid_line_dash_array.as_str());
+            group.append(self.get_grid_lines(&major_tick_offsets, "grid", style, self.theme.get_grid_line_style()));
+        }
+
+        if self.domain_visible {
+            let mut axis_line = Self::get_axis_line(self.position, self.length);
+            axis_line.set_style(&self.theme);
+            group.append(axis_line.to_svg().unwrap());
+
+            if self.tick_size_outer > 0 {
+                for offset in [0_f32, self.length as f32].iter() {
+                    let mut outer_tick = self.get_perpendicular_line(*offset, self.tick_size_outer as f32)
+                        .set("class", "domain");
+                    self.get_axis_stroke().assign_to(&mut outer_tick);
+                    group.append(outer_tick);
+                }
+            }
+        }
+
+        for offset in minor_tick_offsets.iter() {
+            let mut minor_tick = self.get_perpendicular_line(*offset, (self.tick_size_inner / 2) as f32)
+                .set("class", "minor-tick");
+            self.get_axis_stroke().assign_to(&mut minor_tick);
+            group.append(minor_tick);
+        }
+
+        let ticks = self.resolve_label_collisions();
+        for tick in ticks.iter() {
             group.append(tick.to_svg().unwrap());
         }
 
-        if self.label.len() > 0 {
+        if self.has_label() {
+            // Place the label right beyond the tick labels. The text is rotated on vertical axes,
+            // so the descent of the text faces the axis everywhere except on the bottom axis.
+            let distance = self.get_ticks_extent(&ticks) + AXIS_LABEL_GAP;
+            let font_size = self.theme.get_axis_label_font_size();
             let (x, y, rotate) = match self.position {
-                AxisPosition::Top => ((self.length / 2) as i32, -32, 0),
-                AxisPosition::Bottom => ((self.length / 2) as i32, 42, 0),
-                AxisPosition::Left => (-(self.length as i32 / 2), -42, -90),
-                AxisPosition::Right => ((self.length as i32 / 2), -42, 90),
+                AxisPosition::Top => ((self.length / 2) as f32, -(distance + self.font_metrics.descent(font_size)), 0),
+                AxisPosition::Bottom => ((self.length / 2) as f32, distance + self.font_metrics.ascent(font_size), 0),
+                AxisPosition::Left => (-((self.length / 2) as f32), -(distance + self.font_metrics.descent(font_size)), -90),
+                AxisPosition::Right => ((self.length / 2) as f32, -(distance + self.font_metrics.descent(font_size)), 90),
             };
-            let axis_label = Text::new()
+            let font_size = format!("{}px", font_size);
+            let mut axis_label = Text::new()
+                .set("class", "axis-label")
                 .set("x", x)
-                .set("y", y)
+                .set("y", y.round())
                 .set("text-anchor", "middle")
-                .set("font-size", "14px")
-                .set("font-family", "sans-serif")
-                .set("fill", "#777")
                 .set("transform", format!("rotate({})", rotate))
                 .add(TextNode::new(&self.label));
+            // The label can take the color of a series instead of the text color of the theme.
+            Presentation::new()
+                .set("font-size", &font_size, self.theme.in_stylesheet(&font_size))
+                .set("font-family", self.theme.get_font_family(), self.theme.in_stylesheet(self.theme.get_font_family()))
+                .set("fill", &self.label_color, self.theme.in_stylesheet(self.theme.get_text_color()))
+                .assign_to(&mut axis_label);
             group.append(axis_label);
         }
 
         Ok(group)
     }
 
+    /// Get the stroke of the axis line and the tick lines.
+    fn get_axis_stroke(&self) -> Presentation {
+        let color = self.theme.get_axis_color();
+        let stroke_width = format!("{}px", self.theme.get_axis_stroke_width());
+        Presentation::new()
+            .set("stroke", color, self.theme.in_stylesheet(color))
+            .set("stroke-width", self.theme.get_axis_stroke_width(), self.theme.in_stylesheet(&stroke_width))
+    }
+
     /// Generate ticks for the axis based on the scale and position.
-    fn generate_ticks<'a, T: ToString>(scale: &'a dyn Scale<T>, position: AxisPosition) -> Vec<AxisTick> {
-        let mut ticks = Vec::new();
-        let label_offset = {
-            if position == AxisPosition::Top || position == AxisPosition::Bottom {
-                16
-            } else {
-                12
+    fn generate_ticks(&self) -> Vec<AxisTick> {
+        let label_offset = self.tick_size_inner + self.tick_padding;
+
+        self.ticks.iter().map(|(tick_offset, label)| {
+            let mut axis_tick = AxisTick::new(*tick_offset, label_offset, self.label_rotation, label.clone(), self.position);
+            axis_tick.set_tick_size(self.tick_size_inner);
+            axis_tick.set_style(&self.theme);
+            if !self.label_format.is_empty() {
+                axis_tick.set_label_format(&self.label_format);
             }
-        };
+            axis_tick
+        }).collect()
+    }
 
-        for tick in scale.get_ticks() {
-            let tick_offset = match position {
-                AxisPosition::Bottom if scale.get_type() == ScaleType::Band => scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32,
-                AxisPosition::Bottom => scale.scale(&tick),
-                AxisPosition::Left if scale.get_type() == ScaleType::Band => scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32,
-                AxisPosition::Left => scale.scale(&tick),
-                AxisPosition::Top if scale.get_type() == ScaleType::Band => scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32,
-                AxisPosition::Top => scale.scale(&tick),
-                AxisPosition::Right if scale.get_type() == ScaleType::Band => scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32,
-                AxisPosition::Right => scale.scale(&tick),
-            };
-            let axis_tick = AxisTick::new(tick_offset, label_offset, 0, tick.to_string(), position);
-            ticks.push(axis_tick);
+    /// Generate the line that represents the axis.
+    fn get_axis_line(position: AxisPosition, length: isize) -> AxisLine {
+        match position {
+            AxisPosition::Top => AxisLine::new(0_f32, 0_f32, length as f32, 0_f32),
+            AxisPosition::Right => AxisLine::new(0_f32, 0_f32, 0_f32, length as f32),
+            AxisPosition::Bottom => AxisLine::new(0_f32, 0_f32, length as f32, 0_f32),
+            AxisPosition::Left => AxisLine::new(0_f32, 0_f32, 0_f32, length as f32),
         }
+    }
+}
 
-        ticks
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScaleBand;
+
+    #[test]
+    fn transformed_ticks_are_round_in_transformed_units() {
+        let celsius = ScaleLinear::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec![0, 100]);
+        let fahrenheit = TransformedScale::new(&celsius, |c| c * 1.8 + 32_f32, |f| (f - 32_f32) / 1.8);
+        let ticks = fahrenheit.get_ticks();
+
+        assert_eq!(ticks.first().unwrap().1, "40");
+        assert_eq!(ticks.last().unwrap().1, "200");
+        assert!((ticks[0].0 - 40_f32 / 9_f32).abs() < 1e-4);
     }
 
-    /// Generate the line that represents the axis.
-    fn get_axis_line<'a>(position: AxisPosition, chart: &Chart<'a>) -> AxisLine {
-        match position {
-            AxisPosition::Top => AxisLine::new(0_f32, 0_f32, chart.get_view_width() as f32, 0_f32),
-            AxisPosition::Right => AxisLine::new(0_f32, 0_f32, 0_f32, chart.get_view_height() as f32),
-            AxisPosition::Bottom => AxisLine::new(0_f32, 0_f32, chart.get_view_width() as f32, 0_f32),
-            AxisPosition::Left => AxisLine::new(0_f32, 0_f32, 0_f32, chart.get_view_height() as f32),
+    fn bottom_axis<'a>(scale: &'a ScaleLinear, chart: &Chart<'a>) -> Axis {
+        let mut axis = Axis::new_bottom_axis(scale, chart);
+        axis.set_view_size(100, 50);
+        axis
+    }
+
+    #[test]
+    fn grid_lines_are_drawn_at_major_and_minor_ticks() {
+        let scale = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 100]).set_tick_count(2);
+        let chart = Chart::new();
+        let mut axis = bottom_axis(&scale, &chart);
+        axis.set_minor_tick_count(4);
+        assert!(!axis.to_svg().unwrap().to_string().contains("grid-line"));
+
+        axis.set_grid_lines(true);
+        axis.set_minor_grid_lines(true);
+        axis.set_grid_line_style(Color::from_hex("#cccccc"), "4 2");
+        let svg = axis.to_svg().unwrap().to_string();
+
+        // Three major ticks with four minor ticks between every two of them.
+        assert_eq!(svg.matches("class=\"grid-line\"").count(), 3 + 8);
+        assert_eq!(svg.matches("class=\"minor-tick\"").count(), 8);
+        assert_eq!(svg.matches("stroke-dasharray=\"4 2\"").count(), 3);
+        // Gridlines extend across the view, away from the tick labels.
+        assert!(svg.contains("y2=\"-50\""));
+    }
+
+    #[test]
+    fn tick_size_and_padding_place_the_ticks_and_labels() {
+        let scale = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 100]).set_tick_count(2);
+        let chart = Chart::new();
+        let mut axis = bottom_axis(&scale, &chart);
+        axis.set_tick_size(8, 4);
+        axis.set_tick_padding(3);
+        let svg = axis.to_svg().unwrap().to_string();
+
+        assert_eq!(svg.matches("class=\"tick-line\"").count(), 3);
+        assert!(svg.contains("y2=\"8\""));
+        assert!(svg.contains("y=\"11\""));
+        // The outer ticks are drawn at both ends of the axis line.
+        assert_eq!(svg.matches("class=\"domain\"").count(), 3);
+        assert!(svg.contains("y2=\"4\""));
+    }
+
+    #[test]
+    fn hidden_domain_removes_the_axis_line_and_outer_ticks() {
+        let scale = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 100]);
+        let chart = Chart::new();
+        let mut axis = bottom_axis(&scale, &chart);
+        axis.set_tick_size(6, 6);
+        axis.set_domain_visibility(false);
+        let svg = axis.to_svg().unwrap().to_string();
+
+        assert!(!svg.contains("class=\"domain\""));
+        assert!(svg.contains("class=\"tick-line\""));
+    }
+
+    /// Create a bottom axis of a band scale whose labels overlap when they are all displayed.
+    fn crowded_axis<'a>(scale: &'a ScaleBand, chart: &Chart<'a>, strategy: TickLabelCollision) -> Axis {
+        let mut axis = Axis::new_bottom_axis(scale, chart);
+        axis.set_view_size(scale.range()[1], 50);
+        axis.set_tick_label_collision(strategy);
+        axis
+    }
+
+    /// Return whether the spans of two labels along the axis overlap.
+    fn spans_overlap(a: (f32, f32), b: (f32, f32)) -> bool {
+        a.0 < b.1 && b.0 < a.1
+    }
+
+    #[test]
+    fn rotated_labels_do_not_overlap() {
+        let domain = (1..=12).map(|i| format!("Category {}", i)).collect::<Vec<String>>();
+        let scale = ScaleBand::new().set_domain(domain).set_range(vec![0, 300]);
+        let chart = Chart::new();
+        let font_metrics = chart.get_font_metrics().clone();
+        let font_size = chart.get_theme().get_tick_label_font_size();
+
+        let ticks = crowded_axis(&scale, &chart, TickLabelCollision::None).resolve_label_collisions();
+        assert!(ticks.windows(2).all(|pair| spans_overlap(pair[0].get_span(&font_metrics), pair[1].get_span(&font_metrics))));
+
+        // Rotated labels are parallel, so they do not overlap if the distance between them,
+        // perpendicular to their text, fits the height of a line.
+        let ticks = crowded_axis(&scale, &chart, TickLabelCollision::Rotate).resolve_label_collisions();
+        for pair in ticks.windows(2) {
+            let rotation = (pair[0].get_label_rotation() as f32).to_radians();
+            let distance = (pair[1].get_tick_offset() - pair[0].get_tick_offset()).abs();
+            assert_ne!(pair[0].get_label_rotation(), 0);
+            assert!(distance * rotation.sin().abs() >= font_size);
+        }
+    }
+
+    #[test]
+    fn staggered_labels_do_not_overlap() {
+        let domain = (1..=6).map(|i| format!("Region {}", i)).collect::<Vec<String>>();
+        let scale = ScaleBand::new().set_domain(domain).set_range(vec![0, 240]);
+        let chart = Chart::new();
+        let font_metrics = chart.get_font_metrics().clone();
+        let font_size = chart.get_theme().get_tick_label_font_size();
+
+        let ticks = crowded_axis(&scale, &chart, TickLabelCollision::None).resolve_label_collisions();
+        assert!(ticks.windows(2).all(|pair| spans_overlap(pair[0].get_span(&font_metrics), pair[1].get_span(&font_metrics))));
+
+        // Adjacent labels are in different rows and labels in the same row are apart.
+        let ticks = crowded_axis(&scale, &chart, TickLabelCollision::Stagger).resolve_label_collisions();
+        for pair in ticks.windows(2) {
+            let rows_apart = (pair[1].get_label_offset() as f32 - pair[0].get_label_offset() as f32).abs() >= font_size;
+            assert!(rows_apart || !spans_overlap(pair[0].get_span(&font_metrics), pair[1].get_span(&font_metrics)));
+        }
+        for pair in ticks.windows(3) {
+            assert_eq!(pair[0].get_label_offset(), pair[2].get_label_offset());
+            assert!(!spans_overlap(pair[0].get_span(&font_metrics), pair[2].get_span(&font_metrics)));
         }
     }
 }
+
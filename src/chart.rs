@@ -1,16 +1,21 @@
+use std::rc::Rc;
 use std::string::ToString;
-use std::ffi::OsStr;
 use std::path::Path;
 use svg;
-use svg::node::element::Group;
+use svg::node::element::{Group, Rectangle, Style};
 use svg::Node;
 use svg::node::Text as TextNode;
 use svg::node::element::Text;
 use crate::{Axis, Scale};
+use crate::scales::get_scale_id;
 use crate::views::View;
-use crate::axis::AxisPosition;
+use crate::axis::{AxisPosition, AxisScale, TransformedScale, TickLabelCollision};
 use crate::legend::Legend;
 use crate::components::legend::LegendEntry;
+use crate::colors::Color;
+use crate::text::FontMetrics;
+use crate::export::save_document;
+use crate::theme::{Theme, Presentation};
 
 /// Define the orientation enum to aid in rendering and business logic.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -19,8 +24,37 @@ pub enum Orientation {
     Vertical,
 }
 
+/// Define how the elements of a chart are styled.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StylingMode {
+    /// Style the elements with inline presentation attributes (e.g. `fill="#777"`).
+    Inline,
+    /// Style the elements with CSS through their semantic class names (e.g. `tick-label`,
+    /// `bar-block key-<slug>` or `legend-entry series-<slug>`), without the presentation
+    /// attributes that the stylesheet of the chart covers (see `Chart::get_stylesheet`).
+    /// Values that differ from the stylesheet, such as the styles of single data points,
+    /// are kept in the `style` attributes of the elements.
+    Classes,
+}
+
+/// A view of the chart, along with the X and Y axes that the view is bound to (if any).
+#[derive(Clone)]
+struct ChartView<'a> {
+    view: Rc<dyn View<'a> + 'a>,
+    axes: Option<(AxisPosition, AxisPosition)>,
+}
+
+impl<'a> ChartView<'a> {
+    /// Return the legend entries of the view in the colors of the given theme.
+    fn get_legend_entries(&self, theme: &Theme) -> Vec<LegendEntry> {
+        self.view.set_theme(theme);
+        self.view.get_legend_entries()
+    }
+}
+
 /// The Chart struct definition.
-/// A Chart is the smallest entity that can be saved (the bigger one is a Page (TBD)).
+/// A Chart is the smallest entity that can be saved (the bigger one is a Page).
+#[derive(Clone)]
 pub struct Chart<'a> {
     margin_top: isize,
     margin_bottom: isize,
@@ -32,9 +66,15 @@ pub struct Chart<'a> {
     x_axis_bottom: Option<Axis>,
     y_axis_left: Option<Axis>,
     y_axis_right: Option<Axis>,
+    axis_scales: Vec<(AxisPosition, Rc<dyn AxisScale + 'a>)>,
     legend_position: Option<AxisPosition>,
-    views: Vec<&'a dyn View<'a>>,
+    views: Vec<ChartView<'a>>,
     title: String,
+    font_metrics: FontMetrics,
+    theme: Theme,
+    styling_mode: StylingMode,
+    stylesheet_embedded: bool,
+    auto_layout: bool,
 }
 
 impl<'a> Chart<'a> {
@@ -51,9 +91,15 @@ impl<'a> Chart<'a> {
             x_axis_bottom: None,
             y_axis_left: None,
             y_axis_right: None,
+            axis_scales: Vec::new(),
             legend_position: None,
             views: Vec::new(),
             title: String::new(),
+            font_metrics: FontMetrics::new(),
+            theme: Theme::light(),
+            styling_mode: StylingMode::Inline,
+            stylesheet_embedded: false,
+            auto_layout: false,
         }
     }
 
@@ -84,33 +130,144 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Set whether the margins are computed from the space that the title, the axes and the
+    /// legend need, instead of being set with `set_margins`. The size of the chart is kept and
+    /// the view takes up the remaining space, so that nothing is clipped and no space is wasted.
+    /// Scales without an explicitly set range are fitted to the resulting view.
+    pub fn set_auto_layout(mut self, auto_layout: bool) -> Self {
+        self.auto_layout = auto_layout;
+        self
+    }
+
+    /// Set the metrics of the font that the chart is displayed with, which are used to measure
+    /// the text of the chart when laying it out. The default metrics are those of the
+    /// sans-serif font (Helvetica, Arial and their metric-compatible fonts).
+    pub fn set_font_metrics(mut self, font_metrics: FontMetrics) -> Self {
+        for position in [AxisPosition::Top, AxisPosition::Right, AxisPosition::Bottom, AxisPosition::Left].iter() {
+            if let Some(axis) = self.get_axis_mut(*position) {
+                axis.set_font_metrics(font_metrics.clone());
+            }
+        }
+        self.font_metrics = font_metrics;
+        self
+    }
+
+    /// Get the metrics of the font that are used to measure the text of the chart.
+    pub fn get_font_metrics(&self) -> &FontMetrics {
+        &self.font_metrics
+    }
+
+    /// Set the theme of the chart (see `Theme`). The theme replaces the styles of the axes
+    /// that were added so far and provides the styles of the axes that are added later,
+    /// so axis styles that should differ from the theme need to be set after the theme.
+    pub fn set_theme(mut self, theme: Theme) -> Self {
+        for position in [AxisPosition::Top, AxisPosition::Right, AxisPosition::Bottom, AxisPosition::Left].iter() {
+            if let Some(axis) = self.get_axis_mut(*position) {
+                axis.set_theme(&theme);
+            }
+        }
+        self.theme = theme;
+        self
+    }
+
+    /// Get the theme of the chart.
+    pub fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Set how the elements of the chart are styled. In the `StylingMode::Classes` mode,
+    /// the chart is meant to be styled with CSS, e.g. with the stylesheet of the chart
+    /// (see `set_stylesheet_embedded`) or the stylesheets of the page that embeds it.
+    pub fn set_styling_mode(mut self, styling_mode: StylingMode) -> Self {
+        self.styling_mode = styling_mode;
+        self
+    }
+
+    /// Set whether the stylesheet of the chart (see `get_stylesheet`) is embedded
+    /// in the SVG of the chart as a `<style>` element.
+    pub fn set_stylesheet_embedded(mut self, embedded: bool) -> Self {
+        self.stylesheet_embedded = embedded;
+        self
+    }
+
+    /// Generate a stylesheet that styles the chart by the class names of its elements the way
+    /// its theme does, including the colors of its series (e.g. `.key-revenue, .series-revenue { fill: #1f77b4; }`).
+    /// Keys with the same slug are told apart by a number (e.g. `key-a-b` and `key-a-b-2`).
+    pub fn get_stylesheet(&self) -> String {
+        self.get_render_theme().get_stylesheet()
+    }
+
+    /// Return the theme that the chart is rendered with, which sets the styling mode of the chart
+    /// and the classes of its series. The series are the keys of the legend entries.
+    fn get_render_theme(&self) -> Theme {
+        let legend_entries = self.get_legend_entries();
+        let series = legend_entries.iter()
+            .map(|entry| (entry.get_key(), entry.get_color()))
+            .collect::<Vec<(&str, &str)>>();
+
+        self.theme.clone().set_styling(self.styling_mode, &series)
+    }
+
     /// Add the dataset to the chart's view.
     pub fn add_view(mut self, view: &'a dyn View<'a>) -> Self {
-        self.views.push(view);
+        self.views.push(ChartView { view: Rc::new(view), axes: None });
+        self
+    }
+
+    /// Add the dataset to the chart's view and bind it to the given X (top or bottom)
+    /// and Y (left or right) axes, e.g. to display a second series against a right axis.
+    /// The chart fails to render if the view's scales are not the scales of these axes.
+    pub fn add_view_on_axes(mut self, view: &'a dyn View<'a>, x_axis: AxisPosition, y_axis: AxisPosition) -> Self {
+        self.views.push(ChartView { view: Rc::new(view), axes: Some((x_axis, y_axis)) });
+        self
+    }
+
+    /// Add a dataset to the chart's view, moving the view into the chart. This is useful
+    /// when the view is created in a function that returns the chart (see `Facet`).
+    pub fn add_owned_view(mut self, view: impl View<'a> + 'a) -> Self {
+        self.views.push(ChartView { view: Rc::new(view), axes: None });
         self
     }
 
     /// Add an axis at the bottom of the chart.
     pub fn add_axis_bottom<T: ToString>(mut self, scale: &'a dyn Scale<T>) -> Self {
-        self.x_axis_bottom = Some(Axis::new_bottom_axis(scale, &self));
+        self.set_axis(AxisPosition::Bottom, Rc::new(scale), get_scale_id(scale));
         self
     }
 
     /// Add an axis at the left of the chart.
     pub fn add_axis_left<T: ToString>(mut self, scale: &'a dyn Scale<T>) -> Self {
-        self.y_axis_left = Some(Axis::new_left_axis(scale, &self));
+        self.set_axis(AxisPosition::Left, Rc::new(scale), get_scale_id(scale));
         self
     }
 
     /// Add an axis at the top of the chart.
     pub fn add_axis_top<T: ToString>(mut self, scale: &'a dyn Scale<T>) -> Self {
-        self.x_axis_top = Some(Axis::new_top_axis(scale, &self));
+        self.set_axis(AxisPosition::Top, Rc::new(scale), get_scale_id(scale));
         self
     }
 
     /// Add an axis at the right of the chart.
     pub fn add_axis_right<T: ToString>(mut self, scale: &'a dyn Scale<T>) -> Self {
-        self.y_axis_right = Some(Axis::new_right_axis(scale, &self));
+        self.set_axis(AxisPosition::Right, Rc::new(scale), get_scale_id(scale));
+        self
+    }
+
+    /// Add an axis at the top of the chart that displays a continuous scale (usually the scale of
+    /// the bottom axis) in other units, e.g. degrees Fahrenheit for a scale in degrees Celsius.
+    /// The forward function converts values of the scale's domain to the displayed units,
+    /// and the inverse function converts them back.
+    pub fn add_transformed_axis_top(mut self, scale: &'a dyn Scale<f32>, forward: impl Fn(f32) -> f32 + 'a, inverse: impl Fn(f32) -> f32 + 'a) -> Self {
+        self.set_axis(AxisPosition::Top, Rc::new(TransformedScale::new(scale, forward, inverse)), get_scale_id(scale));
+        self
+    }
+
+    /// Add an axis at the right of the chart that displays a continuous scale (usually the scale of
+    /// the left axis) in other units, e.g. percentages of a total for a scale of absolute values.
+    /// The forward function converts values of the scale's domain to the displayed units,
+    /// and the inverse function converts them back.
+    pub fn add_transformed_axis_right(mut self, scale: &'a dyn Scale<f32>, forward: impl Fn(f32) -> f32 + 'a, inverse: impl Fn(f32) -> f32 + 'a) -> Self {
+        self.set_axis(AxisPosition::Right, Rc::new(TransformedScale::new(scale, forward, inverse)), get_scale_id(scale));
         self
     }
 
@@ -272,26 +429,335 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Set the strategy to apply when the tick labels of the axis at the given position
+    /// would overlap.
+    pub fn set_tick_label_collision(mut self, position: AxisPosition, strategy: TickLabelCollision) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_tick_label_collision(strategy);
+        }
+        self
+    }
+
+    /// Set whether gridlines are drawn across the view at the major ticks of the axis
+    /// at the given position.
+    pub fn set_grid_lines(mut self, position: AxisPosition, visible: bool) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_grid_lines(visible);
+        }
+        self
+    }
+
+    /// Set the stroke color and dash array (e.g. "4 2") of the major gridlines
+    /// of the axis at the given position.
+    pub fn set_grid_line_style(mut self, position: AxisPosition, color: Color, dash_array: &str) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_grid_line_style(color, dash_array);
+        }
+        self
+    }
+
+    /// Set whether gridlines are drawn across the view at the minor ticks of the axis
+    /// at the given position.
+    pub fn set_minor_grid_lines(mut self, position: AxisPosition, visible: bool) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_minor_grid_lines(visible);
+        }
+        self
+    }
+
+    /// Set the stroke color and dash array (e.g. "4 2") of the minor gridlines
+    /// of the axis at the given position.
+    pub fn set_minor_grid_line_style(mut self, position: AxisPosition, color: Color, dash_array: &str) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_minor_grid_line_style(color, dash_array);
+        }
+        self
+    }
+
+    /// Set the amount of minor ticks between every two major ticks of the axis
+    /// at the given position.
+    pub fn set_minor_tick_count(mut self, position: AxisPosition, count: usize) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_minor_tick_count(count);
+        }
+        self
+    }
+
+    /// Set the inner and outer tick sizes of the axis at the given position.
+    pub fn set_tick_size(mut self, position: AxisPosition, inner: usize, outer: usize) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_tick_size(inner, outer);
+        }
+        self
+    }
+
+    /// Set the distance between the ticks and the tick labels of the axis at the given position.
+    pub fn set_tick_padding(mut self, position: AxisPosition, padding: usize) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_tick_padding(padding);
+        }
+        self
+    }
+
+    /// Set whether the line of the axis at the given position is displayed.
+    pub fn set_axis_domain_visibility(mut self, position: AxisPosition, visible: bool) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_domain_visibility(visible);
+        }
+        self
+    }
+
+    /// Set the color of the label of the axis at the given position.
+    pub fn set_axis_label_color(mut self, position: AxisPosition, color: Color) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_label_color(color);
+        }
+        self
+    }
+
+    /// Set whether the label of the axis at the given position takes the color of the first
+    /// series of the views that are bound to the axis (see `add_view_on_axes`).
+    pub fn set_axis_label_color_from_series(mut self, position: AxisPosition, enabled: bool) -> Self {
+        if let Some(axis) = self.get_axis_mut(position) {
+            axis.set_label_color_from_series(enabled);
+        }
+        self
+    }
+
+    /// Get the axis at the given position, if present.
+    fn get_axis(&self, position: AxisPosition) -> Option<&Axis> {
+        match position {
+            AxisPosition::Top => self.x_axis_top.as_ref(),
+            AxisPosition::Right => self.y_axis_right.as_ref(),
+            AxisPosition::Bottom => self.x_axis_bottom.as_ref(),
+            AxisPosition::Left => self.y_axis_left.as_ref(),
+        }
+    }
+
+    /// Get the axis at the given position, if present.
+    fn get_axis_mut(&mut self, position: AxisPosition) -> Option<&mut Axis> {
+        match position {
+            AxisPosition::Top => self.x_axis_top.as_mut(),
+            AxisPosition::Right => self.y_axis_right.as_mut(),
+            AxisPosition::Bottom => self.x_axis_bottom.as_mut(),
+            AxisPosition::Left => self.y_axis_left.as_mut(),
+        }
+    }
+
+    /// Add an axis at the given position that displays the given scale, replacing any
+    /// axis that was there. The scale is kept to resolve the axis when the chart is rendered.
+    fn set_axis(&mut self, position: AxisPosition, scale: Rc<dyn AxisScale + 'a>, scale_id: usize) {
+        let axis = Axis::new(&*scale, scale_id, position, self);
+        match position {
+            AxisPosition::Top => self.x_axis_top = Some(axis),
+            AxisPosition::Right => self.y_axis_right = Some(axis),
+            AxisPosition::Bottom => self.x_axis_bottom = Some(axis),
+            AxisPosition::Left => self.y_axis_left = Some(axis),
+        }
+        self.axis_scales.retain(|(axis_position, _)| *axis_position != position);
+        self.axis_scales.push((position, scale));
+    }
+
+    /// Fit the axes to the current size of the view, and set the range of the axes' scales
+    /// that have no explicitly set range to the extent of the view. This is done when the
+    /// chart is rendered, before the views compute the positions of their data, so that
+    /// the order in which the chart is built does not matter.
+    fn resolve_axes(&mut self) {
+        let view_width = self.get_view_width();
+        let view_height = self.get_view_height();
+        for (position, scale) in self.axis_scales.clone().iter() {
+            if let Some(axis) = self.get_axis_mut(*position) {
+                axis.resolve(&**scale, view_width, view_height);
+            }
+        }
+    }
+
+    /// Return a copy of the chart with the margins computed from the space that the title,
+    /// the axes and the legend need. The size of the chart is kept, and the axes are resolved
+    /// from the remaining plot area.
+    fn with_auto_layout(&self) -> Chart<'a> {
+        let mut chart = self.clone();
+        chart.auto_layout = false;
+        // Resolve the axes first, so that their tick labels can be measured.
+        chart.resolve_axes();
+
+        let legend_gap = self.theme.get_legend_gap();
+        let gap = legend_gap as isize;
+        let axis_offset = |axis: &Option<Axis>| -> isize {
+            let extent = axis.as_ref().map_or(0_f32, |axis| axis.get_extent());
+            (extent + legend_gap).ceil() as isize
+        };
+        let title_height = self.font_metrics.text_height(self.theme.get_title_font_size());
+        let title_offset = match !self.title.is_empty() {
+            true => (self.theme.get_title_padding() + title_height + legend_gap).ceil() as isize,
+            false => gap,
+        };
+        chart.margin_top = title_offset + axis_offset(&chart.x_axis_top) - gap;
+        chart.margin_bottom = axis_offset(&chart.x_axis_bottom);
+        chart.margin_left = axis_offset(&chart.y_axis_left);
+        chart.margin_right = axis_offset(&chart.y_axis_right);
+
+        // Legends above or below the view are as wide as the view, while those
+        // beside it have a single column.
+        let legend_entries = self.get_legend_entries();
+        let (legend_width, legend_height) = match self.legend_position {
+            Some(AxisPosition::Top) | Some(AxisPosition::Bottom) => Legend::new(legend_entries, chart.get_view_width().max(0) as usize, self.font_metrics.clone()).set_theme(self.theme.clone()).get_size(),
+            Some(_) => Legend::new(legend_entries, 0, self.font_metrics.clone()).set_theme(self.theme.clone()).get_size(),
+            None => (0, 0),
+        };
+        let legend_space = |position: AxisPosition, size: usize| -> isize {
+            match self.legend_position {
+                Some(legend_position) if legend_position == position && size > 0 => size as isize + gap,
+                _ => 0,
+            }
+        };
+        chart.margin_top += legend_space(AxisPosition::Top, legend_height);
+        chart.margin_bottom += legend_space(AxisPosition::Bottom, legend_height);
+        chart.margin_left += legend_space(AxisPosition::Left, legend_width);
+        chart.margin_right += legend_space(AxisPosition::Right, legend_width);
+
+        // Make room for the tick labels that extend beyond the ends of the axes,
+        // as they are placed along the axes of the remaining plot area.
+        chart.resolve_axes();
+        for axis in [&chart.x_axis_top, &chart.x_axis_bottom].iter().filter_map(|axis| axis.as_ref()) {
+            let (before, after) = axis.get_overhang();
+            chart.margin_left = chart.margin_left.max((before + legend_gap).ceil() as isize);
+            chart.margin_right = chart.margin_right.max((after + legend_gap).ceil() as isize);
+        }
+        for axis in [&chart.y_axis_left, &chart.y_axis_right].iter().filter_map(|axis| axis.as_ref()) {
+            let (before, after) = axis.get_overhang();
+            chart.margin_top = chart.margin_top.max((before + legend_gap).ceil() as isize);
+            chart.margin_bottom = chart.margin_bottom.max((after + legend_gap).ceil() as isize);
+        }
+        chart.resolve_axes();
+
+        chart
+    }
+
+    /// Return a copy of the chart that is laid out for rendering, with its axes
+    /// resolved from the final geometry of the chart.
+    pub(crate) fn get_resolved(&self) -> Chart<'a> {
+        let mut chart = match self.auto_layout {
+            true => self.with_auto_layout(),
+            false => {
+                let mut chart = self.clone();
+                chart.resolve_axes();
+                chart
+            },
+        };
+
+        for position in [AxisPosition::Top, AxisPosition::Right, AxisPosition::Bottom, AxisPosition::Left].iter() {
+            let series_color = self.views.iter()
+                .filter(|chart_view| match chart_view.axes {
+                    Some((x_axis, y_axis)) => x_axis == *position || y_axis == *position,
+                    None => false,
+                })
+                .flat_map(|chart_view| chart_view.get_legend_entries(&self.theme))
+                .map(|entry| entry.get_color().to_string())
+                .next();
+            if let Some(axis) = chart.get_axis_mut(*position) {
+                axis.set_styling_mode(self.styling_mode);
+                match series_color {
+                    Some(color) if axis.is_label_color_from_series() => axis.set_label_color(Color::from_hex(&color)),
+                    _ => {},
+                }
+            }
+        }
+
+        chart
+    }
+
+    /// Check that every view that is bound to axes is bound to an X and a Y axis
+    /// of the chart, and that it displays the scales of these axes.
+    fn validate_view_axes(&self) -> Result<(), String> {
+        let axis_name = |position: AxisPosition| match position {
+            AxisPosition::Top => "top",
+            AxisPosition::Right => "right",
+            AxisPosition::Bottom => "bottom",
+            AxisPosition::Left => "left",
+        };
+
+        for chart_view in self.views.iter() {
+            let (x_axis, y_axis) = match chart_view.axes {
+                Some(axes) => axes,
+                None => continue,
+            };
+            if x_axis == AxisPosition::Left || x_axis == AxisPosition::Right {
+                return Err(format!("A view cannot be bound to the {} axis along the X dimension", axis_name(x_axis)));
+            }
+            if y_axis == AxisPosition::Top || y_axis == AxisPosition::Bottom {
+                return Err(format!("A view cannot be bound to the {} axis along the Y dimension", axis_name(y_axis)));
+            }
+
+            let (x_scale_id, y_scale_id) = chart_view.view.get_scale_ids();
+            for (dimension, position, scale_id) in [("X", x_axis, x_scale_id), ("Y", y_axis, y_scale_id)].iter() {
+                match self.get_axis(*position) {
+                    None => return Err(format!("A view is bound to the {} axis, but the chart has no {} axis", axis_name(*position), axis_name(*position))),
+                    Some(axis) => match scale_id {
+                        Some(scale_id) if *scale_id != axis.get_scale_id() => {
+                            return Err(format!("The {} scale of a view does not match the scale of the {} axis that the view is bound to", dimension, axis_name(*position)));
+                        },
+                        _ => {},
+                    },
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return the legend entries of all views of the chart, in the colors of the theme.
+    pub(crate) fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        self.views.iter()
+            .flat_map(|chart_view| chart_view.get_legend_entries(&self.theme))
+            .collect()
+    }
+
     /// Generate the SVG for the chart and its components.
-    fn to_svg(&self) -> Result<Group, String> {
+    pub(crate) fn to_svg(&self) -> Result<Group, String> {
+        self.validate_view_axes()?;
+
         let mut group = Group::new()
             .set("class", "g-chart");
 
+        let theme = self.get_render_theme();
+        if self.stylesheet_embedded {
+            group.append(Style::new(theme.get_stylesheet()));
+        }
+
+        if let Some(background) = theme.get_background() {
+            let mut background_rect = Rectangle::new()
+                .set("class", "g-background")
+                .set("width", self.width)
+                .set("height", self.height);
+            Presentation::new()
+                .set("fill", background, theme.in_stylesheet(background))
+                .assign_to(&mut background_rect);
+            group.append(background_rect);
+        }
+
         // Add chart title
+        let title_padding = self.theme.get_title_padding();
+        let legend_gap = self.theme.get_legend_gap();
+        let title_height = self.font_metrics.text_height(self.theme.get_title_font_size());
         if self.title.len() > 0 {
+            let font_size = format!("{}px", theme.get_title_font_size());
+            let mut title = Text::new()
+                .set("class", "title")
+                .set("x", 0)
+                .set("y", 0)
+                .set("dy", ".35em")
+                .set("text-anchor", "middle")
+                .add(TextNode::new(&self.title));
+            Presentation::new()
+                .set("fill", theme.get_title_color(), theme.in_stylesheet(theme.get_title_color()))
+                .set("font-size", &font_size, theme.in_stylesheet(&font_size))
+                .set("font-family", theme.get_font_family(), theme.in_stylesheet(theme.get_font_family()))
+                .assign_to(&mut title);
             let title_group = Group::new()
                 .set("class", "g-title")
-                .set("transform", format!("translate({},{})", self.width / 2, 25))
-                .add(Text::new()
-                    .set("x", 0)
-                    .set("y", 0)
-                    .set("dy", ".35em")
-                    .set("fill", "#777")
-                    .set("text-anchor", "middle")
-                    .set("font-size", "24px")
-                    .set("font-family", "sans-serif")
-                    .add(TextNode::new(&self.title))
-                );
+                .set("transform", format!("translate({},{})", self.width / 2, (title_padding + title_height / 2_f32).round()))
+                .add(title);
             group.append(title_group);
         }
 
@@ -323,8 +789,12 @@ impl<'a> Chart<'a> {
             .set("class", "g-view")
             .set("transform", format!("translate({},{})", self.margin_left, self.margin_top));
 
-        for view in self.views.iter() {
-            view_group.append(view.to_svg()?);
+        let mut views = self.views.iter().collect::<Vec<&ChartView>>();
+        views.sort_by_key(|chart_view| chart_view.view.get_z_index());
+        for chart_view in views.iter() {
+            chart_view.view.set_theme(&theme);
+            chart_view.view.set_size(self.get_view_width() as f32, self.get_view_height() as f32);
+            view_group.append(chart_view.view.to_svg()?);
         }
         group.append(view_group);
 
@@ -333,76 +803,48 @@ impl<'a> Chart<'a> {
             let x_offset;
             let y_offset;
 
+            // The distance between an axis line and the legend beyond it.
+            let axis_offset = |axis: &Option<Axis>| -> isize {
+                let extent = axis.as_ref().map_or(0_f32, |axis| axis.get_extent());
+                (extent + legend_gap).ceil() as isize
+            };
+
             match legend_position {
                 AxisPosition::Top => {
-                    let axis_height = {
+                    let title_offset = {
                         if self.title.len() > 0 {
-                            45
+                            title_padding + title_height + legend_gap
                         } else {
-                            10
+                            legend_gap
                         }
                     };
                     width = self.width - self.margin_right - self.margin_left;
                     x_offset = self.margin_left;
-                    y_offset = axis_height;
+                    y_offset = title_offset.ceil() as isize;
                 },
                 AxisPosition::Bottom => {
-                    // Compute the height of the bottom axis that should serve
-                    // as an offset for the legend.
-                    // These sizes are hardcoded and work with current hardcoded axis fonts.
-                    // When a necessity will arise to have custom axis font sizes,
-                    // these will have to be dynamically computed.
-                    let axis_height = {
-                        if let Some(ref axis) = self.x_axis_bottom {
-                            if axis.has_label() {
-                                52
-                            } else {
-                                36
-                            }
-                        } else {
-                            16
-                        }
-                    };
                     width = self.width - self.margin_right - self.margin_left;
                     x_offset = self.margin_left;
-                    y_offset = self.height - self.margin_bottom + axis_height;
+                    y_offset = self.height - self.margin_bottom + axis_offset(&self.x_axis_bottom);
                 },
                 AxisPosition::Left => {
-                    let axis_width = {
-                        if let Some(ref axis) = self.y_axis_left {
-                            if axis.has_label() {
-                                68
-                            } else {
-                                50
-                            }
-                        } else {
-                            20
-                        }
-                    };
-                    width = self.margin_left - axis_width - 10; // 10 is described in the comment below
-                    x_offset = 10; // always have a 10px padding from the left of the chart
+                    let axis_width = axis_offset(&self.y_axis_left);
+                    let padding = legend_gap as isize; // always have a padding from the left of the chart
+                    width = self.margin_left - axis_width - padding;
+                    x_offset = padding;
                     y_offset = self.margin_top;
                 },
                 AxisPosition::Right => {
-                    let axis_width = {
-                        if let Some(ref axis) = self.y_axis_right {
-                            if axis.has_label() {
-                                68
-                            } else {
-                                50
-                            }
-                        } else {
-                            20
-                        }
-                    };
+                    let axis_width = axis_offset(&self.y_axis_right);
                     width = self.margin_right - axis_width;
                     x_offset = self.width - self.margin_right + axis_width;
                     y_offset = self.margin_top;
                 }
             };
 
-            let legend_entries = self.views.iter().map(|view| view.get_legend_entries()).flatten().collect::<Vec<LegendEntry>>();
-            let legend = Legend::new(legend_entries, width as usize);
+            let legend_entries = self.get_legend_entries();
+            let legend = Legend::new(legend_entries, width.max(0) as usize, self.font_metrics.clone())
+                .set_theme(theme);
             let mut legend_group = legend.to_svg()?;
             legend_group.assign("transform", format!("translate({},{})", x_offset, y_offset));
 
@@ -412,27 +854,274 @@ impl<'a> Chart<'a> {
         Ok(group)
     }
 
-    /// Save the chart to a file
+    /// Save the chart to a file. The format of the file (SVG, PNG or PDF) is given
+    /// by the extension of the path.
     pub fn save<P>(self, path: P) -> Result<(), String> where
         P: AsRef<Path>
     {
-        match path.as_ref().extension().and_then(OsStr::to_str) {
-            Some("svg") => {
-                match self.to_svg() {
-                    Ok(svg_content) => {
-                        let document = svg::Document::new()
-                            .set("width", self.width)
-                            .set("height", self.height)
-                            .set("viewBox", (0, 0, self.width, self.height))
-                            .add(svg_content);
-
-                        svg::save(path, &document).unwrap()
-                    },
-                    Err(e) => return Err(format!("Encountered an error while saving the chart: {:?}", e)),
-                }
-            },
-            _ => {},
+        let chart = self.get_resolved();
+        let svg_content = chart.to_svg()
+            .map_err(|e| format!("Encountered an error while saving the chart: {:?}", e))?;
+        let document = svg::Document::new()
+            .set("width", chart.width)
+            .set("height", chart.height)
+            .set("viewBox", (0, 0, chart.width, chart.height))
+            .add(svg_content);
+
+        save_document(&document, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DatumStyle, ScaleBand, ScaleLinear, ScatterView, VerticalBarView};
+
+    fn render(chart: Chart) -> String {
+        chart.get_resolved().to_svg().unwrap().to_string()
+    }
+
+    #[test]
+    fn default_ranges_are_resolved_under_either_call_order() {
+        let data = vec![(50_f32, 50_f32)];
+        let render_with = |axes_first: bool| {
+            let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+            let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+            // The data is loaded before the ranges of the scales are known.
+            let view = ScatterView::new().set_x_scale(&x).set_y_scale(&y).load_data(&data).unwrap();
+            let chart = match axes_first {
+                true => Chart::new().add_axis_bottom(&x).add_axis_left(&y).set_width(500).set_height(400).set_margins(50, 50, 50, 50),
+                false => Chart::new().set_width(500).set_height(400).set_margins(50, 50, 50, 50).add_axis_bottom(&x).add_axis_left(&y),
+            };
+            let svg = render(chart.add_view(&view));
+
+            assert_eq!((x.range_start(), x.range_end()), (0_f32, 400_f32));
+            assert_eq!((y.range_start(), y.range_end()), (300_f32, 0_f32));
+            svg
         };
-        Ok(())
+        let svg = render_with(true);
+
+        assert!(svg.contains("translate(200,150)"));
+        assert_eq!(svg, render_with(false));
+    }
+
+    #[test]
+    fn explicit_ranges_are_kept() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 200]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let data = vec![(50_f32, 50_f32)];
+        let view = ScatterView::new().set_x_scale(&x).set_y_scale(&y).load_data(&data).unwrap();
+        let svg = render(Chart::new().add_axis_bottom(&x).add_axis_left(&y).set_width(500).set_height(400).set_margins(50, 50, 50, 50).add_view(&view));
+
+        assert_eq!(x.range(), &vec![0, 200]);
+        assert_eq!(y.range(), &vec![0, 1]);
+        assert!(svg.contains("translate(100,150)"));
+    }
+
+    #[test]
+    fn auto_layout_keeps_the_chart_size_and_fits_the_scales_to_the_view() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let chart = Chart::new()
+            .set_width(500)
+            .set_height(400)
+            .set_auto_layout(true)
+            .add_axis_bottom(&x)
+            .add_axis_left(&y)
+            .add_left_axis_label("Relative Humidity (%)")
+            .get_resolved();
+        let left_axis_extent = chart.y_axis_left.as_ref().unwrap().get_extent();
+
+        assert_eq!((chart.get_chart_width(), chart.get_chart_height()), (500, 400));
+        assert!(chart.margin_left as f32 >= left_axis_extent);
+        assert_eq!(x.range_end(), chart.get_view_width() as f32);
+        assert_eq!(y.range_start(), chart.get_view_height() as f32);
+    }
+
+    #[test]
+    fn auto_layout_makes_room_for_the_legend() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let data = vec![(10_f32, 20_f32, "Northern Hemisphere"), (30_f32, 40_f32, "Southern Hemisphere")];
+        let view = ScatterView::new().set_x_scale(&x).set_y_scale(&y).load_data(&data).unwrap();
+        let chart = Chart::new().set_auto_layout(true).add_axis_bottom(&x).add_axis_left(&y).add_view(&view);
+        let without_legend = chart.clone().get_resolved();
+        let with_legend = chart.add_legend_at(AxisPosition::Right).get_resolved();
+
+        assert!(with_legend.margin_right > without_legend.margin_right + 100);
+        assert_eq!(with_legend.get_chart_width(), without_legend.get_chart_width());
+        assert_eq!(x.range_end(), with_legend.get_view_width() as f32);
+    }
+
+    #[test]
+    fn views_bound_to_mismatching_axes_are_rejected() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![100, 0]);
+        let y2 = ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![100, 0]);
+        let data = vec![(50_f32, 50_f32)];
+        let view = ScatterView::new().set_x_scale(&x).set_y_scale(&y).load_data(&data).unwrap();
+        let chart = Chart::new().add_axis_bottom(&x).add_axis_left(&y).add_axis_right(&y2);
+        let error_of = |x_axis: AxisPosition, y_axis: AxisPosition| {
+            chart.clone().add_view_on_axes(&view, x_axis, y_axis).to_svg().err()
+        };
+
+        assert_eq!(error_of(AxisPosition::Bottom, AxisPosition::Left), None);
+        assert_eq!(error_of(AxisPosition::Bottom, AxisPosition::Right).unwrap(), "The Y scale of a view does not match the scale of the right axis that the view is bound to");
+        assert_eq!(error_of(AxisPosition::Top, AxisPosition::Left).unwrap(), "A view is bound to the top axis, but the chart has no top axis");
+        assert_eq!(error_of(AxisPosition::Left, AxisPosition::Left).unwrap(), "A view cannot be bound to the left axis along the X dimension");
+    }
+
+    #[test]
+    fn axis_labels_can_take_the_color_of_the_series_of_their_axis() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![100, 0]);
+        let y2 = ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![100, 0]);
+        let left_data = vec![(50_f32, 50_f32)];
+        let right_data = vec![(50_f32, 0.5_f32)];
+        let left_view = ScatterView::new().set_x_scale(&x).set_y_scale(&y).set_colors(vec![Color::from_hex("#0000ff")]).load_data(&left_data).unwrap();
+        let right_view = ScatterView::new().set_x_scale(&x).set_y_scale(&y2).set_colors(vec![Color::from_hex("#ff0000")]).load_data(&right_data).unwrap();
+        let chart = Chart::new()
+            .add_axis_bottom(&x)
+            .add_axis_left(&y)
+            .add_axis_right(&y2)
+            .add_left_axis_label("Temperature")
+            .add_right_axis_label("Humidity")
+            .set_axis_label_color_from_series(AxisPosition::Right, true)
+            .add_view_on_axes(&left_view, AxisPosition::Bottom, AxisPosition::Left)
+            .add_view_on_axes(&right_view, AxisPosition::Bottom, AxisPosition::Right)
+            .get_resolved();
+        let right_axis = chart.y_axis_right.as_ref().unwrap().to_svg().unwrap().to_string();
+        let left_axis = chart.y_axis_left.as_ref().unwrap().to_svg().unwrap().to_string();
+
+        assert!(right_axis.contains("fill=\"#ff0000\""));
+        assert!(!left_axis.contains("fill=\"#0000ff\""));
+    }
+
+    /// Render a chart with a title, gridlines and a scatter view in the given theme.
+    fn render_with_theme(theme: Theme, colors: Option<Vec<Color>>) -> String {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let data = vec![(50_f32, 50_f32)];
+        let mut view = ScatterView::new().set_x_scale(&x).set_y_scale(&y);
+        if let Some(colors) = colors {
+            view = view.set_colors(colors);
+        }
+        let view = view.load_data(&data).unwrap();
+
+        render(Chart::new()
+            .set_theme(theme)
+            .add_title(String::from("Humidity"))
+            .add_axis_bottom(&x)
+            .add_axis_left(&y)
+            .set_grid_lines(AxisPosition::Left, true)
+            .add_view(&view))
+    }
+
+    #[test]
+    fn minimal_theme_hides_the_axis_lines() {
+        let svg = render_with_theme(Theme::minimal(), None);
+
+        assert!(!svg.contains("class=\"domain\""));
+        assert!(svg.contains("font-size=\"20px\""));
+        assert!(svg.contains("fill=\"#4e79a7\""));
+    }
+
+    #[test]
+    fn high_contrast_theme_uses_black_text_and_thick_axes() {
+        let svg = render_with_theme(Theme::high_contrast(), None);
+
+        assert!(svg.contains("class=\"g-background\" fill=\"#ffffff\""));
+        assert!(svg.contains("stroke-width=\"2\""));
+        assert!(svg.contains("fill=\"#0072b2\""));
+        assert!(!svg.contains("#777"));
+    }
+
+    #[test]
+    fn print_theme_uses_dashed_gridlines_and_a_grayscale_palette() {
+        let svg = render_with_theme(Theme::print(), None);
+
+        assert!(svg.contains("stroke-dasharray=\"2 2\""));
+        assert!(svg.contains("fill=\"#252525\""));
+        assert!(!svg.contains("fill=\"#1f77b4\""));
+    }
+
+    #[test]
+    fn colors_of_views_are_kept_under_a_theme() {
+        // The color is the second color of the default palette, which a theme does not replace.
+        let svg = render_with_theme(Theme::print(), Some(vec![Color::from_hex("#ff7f0e")]));
+
+        assert!(svg.contains("fill=\"#ff7f0e\""));
+        assert!(!svg.contains("fill=\"#737373\""));
+    }
+
+    #[test]
+    fn classes_mode_embeds_the_stylesheet_and_keeps_the_styles_of_data_points() {
+        let x = ScaleBand::new().set_domain(vec![String::from("A"), String::from("B")]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]);
+        let data = vec![("A", 2_f32, "Revenue"), ("B", 8_f32, "Revenue"), ("A", 1_f32, "Costs")];
+        let view = VerticalBarView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_style_encoding(|datum| match datum.get_value() > 5_f32 {
+                true => Some(DatumStyle::new().set_fill(Color::from_hex("#ff0000"))),
+                false => None,
+            })
+            .load_data(&data)
+            .unwrap();
+        let svg = render(Chart::new()
+            .add_axis_bottom(&x)
+            .add_axis_left(&y)
+            .add_legend_at(AxisPosition::Bottom)
+            .set_styling_mode(StylingMode::Classes)
+            .set_stylesheet_embedded(true)
+            .add_view(&view));
+        let elements_of = |class: &str| svg.split('<')
+            .filter(|element| element.contains(&format!("class=\"{}\"", class)))
+            .map(String::from)
+            .collect::<Vec<String>>();
+
+        assert!(svg.contains("<style>"));
+        assert!(svg.contains(".key-revenue, .series-revenue { fill: #1f77b4; }"));
+        assert!(svg.contains(".tick-label { fill: #777;"));
+
+        let blocks = elements_of("bar-block key-revenue");
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks.iter().any(|block| !block.contains("fill")));
+        assert!(blocks.iter().any(|block| block.contains("style=\"fill: #ff0000;\"")));
+        assert!(elements_of("tick-label").iter().all(|label| !label.contains("fill=") && !label.contains("font-size=")));
+        assert_eq!(elements_of("legend-entry series-costs").len(), 1);
+    }
+
+    #[test]
+    fn classes_mode_keeps_the_text_of_labels() {
+        let x = ScaleBand::new().set_domain(vec![String::from("R&D <2020>")]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]);
+        let data = vec![("R&D <2020>", 2_f32, "A/B"), ("R&D <2020>", 3_f32, "A B")];
+        let view = VerticalBarView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .load_data(&data)
+            .unwrap();
+        let chart = Chart::new()
+            .add_title(String::from("Profit & Loss"))
+            .add_axis_bottom(&x)
+            .add_axis_left(&y)
+            .add_legend_at(AxisPosition::Bottom)
+            .add_view(&view);
+        let inline_svg = render(chart.clone());
+        let svg = render(chart.set_styling_mode(StylingMode::Classes));
+        let text_of = |svg: &str, class: &str| svg.split(&format!("class=\"{}\"", class))
+            .skip(1)
+            .map(|element| element[element.find('>').unwrap() + 1..element.find("</text>").unwrap()].to_string())
+            .collect::<Vec<String>>();
+
+        for class in ["title", "tick-label", "legend-label"].iter() {
+            assert!(!text_of(&svg, class).is_empty());
+            assert_eq!(text_of(&svg, class), text_of(&inline_svg, class));
+        }
+        // Keys with the same slug are told apart.
+        assert!(svg.contains("bar-block key-a-b\""));
+        assert!(svg.contains("bar-block key-a-b-2\""));
+        assert!(svg.contains("legend-entry series-a-b-2\""));
     }
 }
@@ -1,10 +1,22 @@
+pub mod scale;
+
 /// A struct that represents a color.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Color {
     hex: String,
 }
 
 impl Color {
+    /// Create a color from a hex string (e.g. "#1f77b4").
+    pub fn from_hex(hex: &str) -> Self {
+        Color { hex: String::from(hex) }
+    }
+
+    /// Create a color from its red, green and blue components.
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color { hex: format!("#{:02x}{:02x}{:02x}", red, green, blue) }
+    }
+
     /// Generate a color scheme from a string.
     /// Useful when displaying a single dataset that requires one color.
     pub fn from_vec_of_hex_strings(color_strings: Vec<&str>) -> Vec<Self> {
@@ -64,8 +76,71 @@ impl Color {
         )
     }
 
+    /// A sequential color scheme ranging from light to dark blue.
+    pub fn color_scheme_blues() -> Vec<Self> {
+        Self::from_vec_of_hex_strings(vec!["#f7fbff", "#c6dbef", "#6baed6", "#2171b5", "#08306b"])
+    }
+
+    /// A perceptually uniform sequential color scheme ranging from dark purple to yellow.
+    pub fn color_scheme_viridis() -> Vec<Self> {
+        Self::from_vec_of_hex_strings(vec!["#440154", "#3b528b", "#21918c", "#5ec962", "#fde725"])
+    }
+
+    /// A diverging color scheme ranging from red through white to blue.
+    /// Useful when the data has a meaningful midpoint (e.g. a correlation of zero).
+    pub fn color_scheme_red_blue() -> Vec<Self> {
+        Self::from_vec_of_hex_strings(vec!["#b2182b", "#ef8a62", "#f7f7f7", "#67a9cf", "#2166ac"])
+    }
+
     /// Represent a color as a hex string.
     pub fn as_hex(&self) -> String {
         String::from(&self.hex)
     }
+
+    /// Return the red, green and blue components of the color.
+    /// Both the short (#rgb) and the long (#rrggbb) hex notations are supported.
+    pub fn as_rgb(&self) -> Result<(u8, u8, u8), String> {
+        let hex = self.hex.strip_prefix('#').unwrap_or(&self.hex);
+        let digits = hex.chars()
+            .map(|digit| digit.to_digit(16).map(|value| value as u8))
+            .collect::<Option<Vec<u8>>>();
+
+        match digits.as_deref() {
+            Some([r, g, b]) => Ok((r * 17, g * 17, b * 17)),
+            Some([r1, r0, g1, g0, b1, b0]) => Ok((r1 * 16 + r0, g1 * 16 + g0, b1 * 16 + b0)),
+            _ => Err(format!("The color \"{}\" is not a hex color (#rgb or #rrggbb).", self.hex)),
+        }
+    }
+
+    /// Return the color that lies at `t` (between 0 and 1) on the way from this color to the other one.
+    /// Colors that are not hex colors are treated as black.
+    pub fn interpolate(&self, other: &Color, t: f32) -> Color {
+        let (r0, g0, b0) = self.as_rgb().unwrap_or((0, 0, 0));
+        let (r1, g1, b1) = other.as_rgb().unwrap_or((0, 0, 0));
+        let t = t.clamp(0_f32, 1_f32);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+
+        Color::from_rgb(mix(r0, r1), mix(g0, g1), mix(b0, b1))
+    }
+
+    /// Return the relative luminance of the color, as defined by WCAG, between 0 (black) and 1 (white).
+    /// Colors that are not hex colors are treated as black.
+    pub fn luminance(&self) -> f32 {
+        let (red, green, blue) = self.as_rgb().unwrap_or((0, 0, 0));
+        let linearize = |component: u8| {
+            let c = component as f32 / 255_f32;
+            if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+
+        0.2126 * linearize(red) + 0.7152 * linearize(green) + 0.0722 * linearize(blue)
+    }
+
+    /// Return a text color (dark or light) that is readable on top of this color.
+    pub fn contrasting_text_color(&self) -> String {
+        if self.luminance() > 0.179 {
+            String::from("#333")
+        } else {
+            String::from("#fff")
+        }
+    }
 }
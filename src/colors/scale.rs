@@ -0,0 +1,155 @@
+use crate::colors::Color;
+
+/// Define how a color scale maps values to colors.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColorScaleType {
+    /// Interpolate smoothly between the colors of the scale.
+    Continuous,
+    /// Divide the domain into the given number of equally sized buckets
+    /// and assign a single color to every bucket.
+    Quantized(usize),
+}
+
+/// A scale that maps numeric values to colors by interpolating between a list of colors.
+/// The first color corresponds to the start of the domain and the last color to its end.
+#[derive(Debug, Clone)]
+pub struct ColorScale {
+    scale_type: ColorScaleType,
+    colors: Vec<Color>,
+    domain: Option<(f32, f32)>,
+}
+
+impl ColorScale {
+    /// Create a new continuous color scale from the given colors.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self {
+            scale_type: ColorScaleType::Continuous,
+            colors,
+            domain: None,
+        }
+    }
+
+    /// Set the type of the scale.
+    pub fn set_type(mut self, scale_type: ColorScaleType) -> Self {
+        self.scale_type = scale_type;
+        self
+    }
+
+    /// Set the domain limits of the scale. If not set, views that use
+    /// the scale will use the minimum and maximum values of their data.
+    pub fn set_domain(mut self, min: f32, max: f32) -> Self {
+        self.domain = Some((min, max));
+        self
+    }
+
+    /// Get the type of the scale.
+    pub fn get_type(&self) -> ColorScaleType {
+        self.scale_type
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn get_domain(&self) -> Option<(f32, f32)> {
+        self.domain
+    }
+
+    /// Check that all colors of the scale are hex colors that can be interpolated.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        for color in self.colors.iter() {
+            color.as_rgb()?;
+        }
+        Ok(())
+    }
+
+    /// Set the domain limits to the extent of the values, unless the domain was set explicitly.
+    pub(crate) fn fit_domain(&mut self, values: impl Iterator<Item = f32>) {
+        if self.domain.is_some() {
+            return;
+        }
+
+        let (min, max) = values.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| (min.min(value), max.max(value)));
+        if min.is_finite() && max.is_finite() {
+            self.domain = Some((min, max));
+        }
+    }
+
+    /// Return the color that corresponds to the given value.
+    /// Values outside of the domain are clamped to the domain limits.
+    pub fn get_color(&self, value: f32) -> Color {
+        let (min, max) = self.domain.unwrap_or((0_f32, 1_f32));
+        let t = match max - min {
+            span if span != 0_f32 => ((value - min) / span).clamp(0_f32, 1_f32),
+            _ => 0.5,
+        };
+
+        match self.scale_type {
+            ColorScaleType::Continuous => self.interpolate(t),
+            ColorScaleType::Quantized(buckets) if buckets > 1 => {
+                let bucket = ((t * buckets as f32).floor() as usize).min(buckets - 1);
+                self.interpolate(bucket as f32 / (buckets - 1) as f32)
+            },
+            ColorScaleType::Quantized(_) => self.interpolate(0.5),
+        }
+    }
+
+    /// Return a list of colors that represent the scale from the start to the end of its domain.
+    /// A quantized scale returns one color per bucket.
+    pub fn get_samples(&self, nr_of_samples: usize) -> Vec<Color> {
+        let nr_of_samples = match self.scale_type {
+            ColorScaleType::Quantized(buckets) => buckets.max(1),
+            ColorScaleType::Continuous => nr_of_samples.max(2),
+        };
+
+        match nr_of_samples {
+            1 => vec![self.interpolate(0.5)],
+            _ => (0..nr_of_samples)
+                .map(|i| self.interpolate(i as f32 / (nr_of_samples - 1) as f32))
+                .collect(),
+        }
+    }
+
+    /// Interpolate the colors of the scale at `t` (between 0 and 1).
+    fn interpolate(&self, t: f32) -> Color {
+        match self.colors.len() {
+            0 => Color::from_hex("#000"),
+            1 => self.colors[0].clone(),
+            len => {
+                let position = t * (len - 1) as f32;
+                let i = (position.floor() as usize).min(len - 2);
+                self.colors[i].interpolate(&self.colors[i + 1], position - i as f32)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn continuous_scale_interpolates_between_colors() {
+        let scale = ColorScale::new(Color::from_vec_of_hex_strings(vec!["#000000", "#ffffff"]))
+            .set_domain(0_f32, 10_f32);
+
+        assert_eq!(scale.get_color(0_f32).as_hex(), "#000000");
+        assert_eq!(scale.get_color(5_f32).as_hex(), "#808080");
+        assert_eq!(scale.get_color(20_f32).as_hex(), "#ffffff");
+    }
+
+    #[test]
+    fn quantized_scale_buckets_values() {
+        let scale = ColorScale::new(Color::from_vec_of_hex_strings(vec!["#000000", "#ffffff"]))
+            .set_type(ColorScaleType::Quantized(3))
+            .set_domain(0_f32, 9_f32);
+
+        assert_eq!(scale.get_color(2_f32).as_hex(), "#000000");
+        assert_eq!(scale.get_color(4_f32).as_hex(), "#808080");
+        assert_eq!(scale.get_color(9_f32).as_hex(), "#ffffff");
+    }
+
+    #[test]
+    fn colors_that_are_not_hex_colors_are_rejected() {
+        assert!(ColorScale::new(Color::from_vec_of_hex_strings(vec!["#0f0", "#00ff00"])).validate().is_ok());
+        assert!(ColorScale::new(Color::from_vec_of_hex_strings(vec!["#0f0", "#ééé"])).validate().is_err());
+        assert!(ColorScale::new(Color::from_vec_of_hex_strings(vec!["red"])).validate().is_err());
+    }
+}
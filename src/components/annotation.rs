@@ -0,0 +1,473 @@
+use svg::node::Node;
+use svg::node::Text as TextNode;
+use svg::node::element::{Element, Group, Line, Path, Rectangle, Text};
+use svg::node::element::path::Data;
+use crate::colors::Color;
+use crate::components::DatumRepresentation;
+use crate::components::axis::wrap_label;
+use crate::components::scatter::PointLabelPosition;
+use crate::chart::Orientation;
+use crate::text::FontMetrics;
+
+/// The distance between an annotation's label and the edges of the annotation.
+const ANNOTATION_LABEL_PADDING: f32 = 4_f32;
+
+/// The distance between the lines of wrapped text, in ems.
+const ANNOTATION_LINE_HEIGHT: f32 = 1.1;
+
+/// The offset of arrows and callouts from the point that they annotate, if none is set.
+const DEFAULT_POINTER_OFFSET: (f32, f32) = (0_f32, -40_f32);
+
+/// The length and the width of arrow heads.
+const ARROW_HEAD_SIZE: (f32, f32) = (8_f32, 8_f32);
+
+/// Represents the visual properties of an annotation, along with its label.
+#[derive(Debug, Clone)]
+pub struct AnnotationStyle {
+    color: String,
+    stroke_width: f32,
+    dash_array: String,
+    fill_opacity: f32,
+    label: String,
+    font_size: f32,
+    anchor: PointLabelPosition,
+    offset: Option<(f32, f32)>,
+    wrap_width: Option<f32>,
+    background: String,
+    font_family: String,
+}
+
+impl AnnotationStyle {
+    /// Create a new style with a solid gray line and no label.
+    pub fn new() -> Self {
+        Self {
+            color: String::from("#777"),
+            stroke_width: 1_f32,
+            dash_array: String::from("none"),
+            fill_opacity: 0.15,
+            label: String::new(),
+            font_size: 12_f32,
+            anchor: PointLabelPosition::N,
+            offset: None,
+            wrap_width: None,
+            background: String::from("#fff"),
+            font_family: String::from("sans-serif"),
+        }
+    }
+
+    /// Set the color of the annotation and its label.
+    pub fn set_color(mut self, color: Color) -> Self {
+        self.color = color.as_hex();
+        self
+    }
+
+    /// Set the width of the annotation's line.
+    pub fn set_stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Set the dash pattern of the annotation's line, as lengths of alternating dashes and gaps
+    /// (e.g. "5,3"), the same as the SVG `stroke-dasharray` attribute.
+    pub fn set_dash_array(mut self, dash_array: &str) -> Self {
+        self.dash_array = dash_array.to_string();
+        self
+    }
+
+    /// Set the opacity (between 0 and 1) of the fill of shaded spans.
+    pub fn set_fill_opacity(mut self, opacity: f32) -> Self {
+        self.fill_opacity = opacity;
+        self
+    }
+
+    /// Set the label that is displayed next to the annotation. This is the text
+    /// of text annotations, arrows and callouts.
+    pub fn set_label(mut self, label: String) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Set the font size of the annotation's label.
+    pub fn set_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set where the text of text annotations, arrows and callouts is placed relative to
+    /// its anchor point (e.g. `PointLabelPosition::N` places the text above the point).
+    /// Defaults to `PointLabelPosition::N`.
+    pub fn set_anchor(mut self, anchor: PointLabelPosition) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Set the offset in pixels of the text's anchor point from the annotated point.
+    /// Arrows and callouts point from the anchor point to the annotated point, and
+    /// default to an offset of 40 pixels upwards.
+    pub fn set_offset(mut self, dx: f32, dy: f32) -> Self {
+        self.offset = Some((dx, dy));
+        self
+    }
+
+    /// Set the width in pixels at which the text of text annotations, arrows and callouts
+    /// is broken into lines. By default, the text is displayed on a single line.
+    pub fn set_wrap_width(mut self, width: f32) -> Self {
+        self.wrap_width = Some(width);
+        self
+    }
+
+    /// Set the fill color of the boxes of callouts.
+    pub fn set_background(mut self, color: Color) -> Self {
+        self.background = color.as_hex();
+        self
+    }
+
+    /// Set the font family of the annotation's label, which is the font family
+    /// of the theme of the chart that renders the annotation.
+    pub(crate) fn set_font_family(mut self, font_family: String) -> Self {
+        self.font_family = font_family;
+        self
+    }
+
+    /// Create the text of the annotation's label at the given position.
+    fn label(&self, x: f32, y: f32, dy: &str, anchor: &str) -> Text {
+        Text::new()
+            .set("x", x)
+            .set("y", y)
+            .set("dy", dy)
+            .set("text-anchor", anchor)
+            .set("font-family", self.font_family.as_str())
+            .set("font-size", format!("{}px", self.font_size))
+            .set("fill", self.color.as_ref())
+            .add(TextNode::new(self.label.clone()))
+    }
+
+    /// Create a line between two points in the style's stroke.
+    fn line(&self, from: (f32, f32), to: (f32, f32)) -> Line {
+        Line::new()
+            .set("x1", from.0)
+            .set("y1", from.1)
+            .set("x2", to.0)
+            .set("y2", to.1)
+            .set("stroke", self.color.as_ref())
+            .set("stroke-width", self.stroke_width)
+            .set("stroke-dasharray", self.dash_array.as_ref())
+    }
+}
+
+impl Default for AnnotationStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a rule that crosses the plot area at a position, e.g. a target value.
+/// A vertical rule is drawn at an X position from the top to the bottom of the plot area,
+/// and a horizontal rule at a Y position from its left to its right. All values are in pixels.
+#[derive(Debug)]
+pub struct ReferenceLine {
+    orientation: Orientation,
+    position: f32,
+    extent: (f32, f32),
+    style: AnnotationStyle,
+}
+
+impl ReferenceLine {
+    pub fn new(orientation: Orientation, position: f32, extent: (f32, f32), style: AnnotationStyle) -> Self {
+        Self {
+            orientation,
+            position,
+            extent,
+            style,
+        }
+    }
+}
+
+impl DatumRepresentation for ReferenceLine {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", "reference-line");
+
+        let (start, end) = (self.extent.0.min(self.extent.1), self.extent.0.max(self.extent.1));
+        let (from, to) = match self.orientation {
+            Orientation::Vertical => ((self.position, start), (self.position, end)),
+            Orientation::Horizontal => ((start, self.position), (end, self.position)),
+        };
+
+        group.append(self.style.line(from, to).set("shape-rendering", "crispEdges"));
+
+        // Labels of vertical rules are placed at the top, to the right of the rule,
+        // and labels of horizontal rules at the right, above the rule.
+        if !self.style.label.is_empty() {
+            group.append(match self.orientation {
+                Orientation::Vertical => self.style.label(self.position + ANNOTATION_LABEL_PADDING, start + ANNOTATION_LABEL_PADDING, ".71em", "start"),
+                Orientation::Horizontal => self.style.label(end - ANNOTATION_LABEL_PADDING, self.position - ANNOTATION_LABEL_PADDING, "0", "end"),
+            });
+        }
+
+        Ok(group)
+    }
+}
+
+/// Represents a shaded span of the plot area between two positions, e.g. an incident window.
+/// A vertical span covers an X interval from the top to the bottom of the plot area,
+/// and a horizontal span covers a Y interval from its left to its right. All values are in pixels.
+#[derive(Debug)]
+pub struct ReferenceBand {
+    orientation: Orientation,
+    span: (f32, f32),
+    extent: (f32, f32),
+    style: AnnotationStyle,
+}
+
+impl ReferenceBand {
+    pub fn new(orientation: Orientation, span: (f32, f32), extent: (f32, f32), style: AnnotationStyle) -> Self {
+        Self {
+            orientation,
+            span,
+            extent,
+            style,
+        }
+    }
+}
+
+impl DatumRepresentation for ReferenceBand {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", "reference-band");
+
+        let (span_start, span_end) = (self.span.0.min(self.span.1), self.span.0.max(self.span.1));
+        let (start, end) = (self.extent.0.min(self.extent.1), self.extent.0.max(self.extent.1));
+        let (x, y, width, height) = match self.orientation {
+            Orientation::Vertical => (span_start, start, span_end - span_start, end - start),
+            Orientation::Horizontal => (start, span_start, end - start, span_end - span_start),
+        };
+
+        group.append(
+            Rectangle::new()
+                .set("x", x)
+                .set("y", y)
+                .set("width", width)
+                .set("height", height)
+                .set("shape-rendering", "crispEdges")
+                .set("fill", self.style.color.as_ref())
+                .set("fill-opacity", self.style.fill_opacity)
+                .set("stroke", "none")
+        );
+
+        // Labels are placed in the top left corner of vertical spans
+        // and in the top right corner of horizontal spans.
+        if !self.style.label.is_empty() {
+            group.append(match self.orientation {
+                Orientation::Vertical => self.style.label(x + ANNOTATION_LABEL_PADDING, y + ANNOTATION_LABEL_PADDING, ".71em", "start"),
+                Orientation::Horizontal => self.style.label(x + width - ANNOTATION_LABEL_PADDING, y + ANNOTATION_LABEL_PADDING, ".71em", "end"),
+            });
+        }
+
+        Ok(group)
+    }
+}
+
+/// Define the kinds of annotations that display text at a point.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextAnnotationType {
+    /// Text at the point.
+    Text,
+    /// Text with an arrow that points from the text to the point.
+    Arrow,
+    /// Text in a box that is connected to the point by a line.
+    Callout,
+}
+
+/// Represents text that annotates a point of the plot area, e.g. an event on a line chart.
+/// The text is placed at an offset from the point, on the side of the offset point that
+/// the style's anchor defines. All values are in pixels.
+#[derive(Debug)]
+pub struct TextAnnotation {
+    annotation_type: TextAnnotationType,
+    point: (f32, f32),
+    style: AnnotationStyle,
+    font_metrics: FontMetrics,
+}
+
+impl TextAnnotation {
+    pub fn new(annotation_type: TextAnnotationType, point: (f32, f32), style: AnnotationStyle, font_metrics: FontMetrics) -> Self {
+        Self {
+            annotation_type,
+            point,
+            style,
+            font_metrics,
+        }
+    }
+
+    /// Get the position of the anchor point of the text.
+    fn get_anchor_point(&self) -> (f32, f32) {
+        let (dx, dy) = match (self.style.offset, self.annotation_type) {
+            (Some(offset), _) => offset,
+            (None, TextAnnotationType::Text) => (0_f32, 0_f32),
+            (None, _) => DEFAULT_POINTER_OFFSET,
+        };
+        (self.point.0 + dx, self.point.1 + dy)
+    }
+
+    /// Get the position of the top left corner of a box of the given size,
+    /// placed on the anchor's side of the anchor point.
+    fn get_box_position(&self, anchor_point: (f32, f32), size: (f32, f32)) -> (f32, f32) {
+        let (x, y) = anchor_point;
+        let (width, height) = size;
+        match self.style.anchor {
+            PointLabelPosition::N => (x - width / 2_f32, y - height),
+            PointLabelPosition::NE => (x, y - height),
+            PointLabelPosition::E => (x, y - height / 2_f32),
+            PointLabelPosition::SE => (x, y),
+            PointLabelPosition::S => (x - width / 2_f32, y),
+            PointLabelPosition::SW => (x - width, y),
+            PointLabelPosition::W => (x - width, y - height / 2_f32),
+            PointLabelPosition::NW => (x - width, y - height),
+        }
+    }
+
+    /// Create the head of an arrow that points from one point to another.
+    fn arrow_head(&self, from: (f32, f32), to: (f32, f32)) -> Option<Path> {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0_f32 {
+            return None;
+        }
+
+        let (ux, uy) = (dx / length, dy / length);
+        let (head_length, head_width) = ARROW_HEAD_SIZE;
+        let base = (to.0 - ux * head_length, to.1 - uy * head_length);
+        let data = Data::new()
+            .move_to(to)
+            .line_to((base.0 - uy * head_width / 2_f32, base.1 + ux * head_width / 2_f32))
+            .line_to((base.0 + uy * head_width / 2_f32, base.1 - ux * head_width / 2_f32))
+            .close();
+
+        Some(Path::new()
+            .set("d", data)
+            .set("fill", self.style.color.as_ref())
+            .set("stroke", "none"))
+    }
+}
+
+impl DatumRepresentation for TextAnnotation {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let class = match self.annotation_type {
+            TextAnnotationType::Text => "text-annotation",
+            TextAnnotationType::Arrow => "arrow-annotation",
+            TextAnnotationType::Callout => "callout-annotation",
+        };
+        let mut group = Group::new()
+            .set("class", class);
+
+        let font_size = self.style.font_size;
+        let lines = match self.style.wrap_width {
+            Some(wrap_width) => wrap_label(&self.style.label, wrap_width, font_size, &self.font_metrics),
+            None => vec![self.style.label.clone()],
+        };
+        let text_width = lines.iter().map(|line| self.font_metrics.text_width(line, font_size)).fold(0_f32, f32::max);
+        let text_height = self.font_metrics.text_height(font_size) + (lines.len().max(1) - 1) as f32 * ANNOTATION_LINE_HEIGHT * font_size;
+
+        // The text is padded, which separates it from the anchor point and from the box of callouts.
+        let anchor_point = self.get_anchor_point();
+        let box_size = (text_width + 2_f32 * ANNOTATION_LABEL_PADDING, text_height + 2_f32 * ANNOTATION_LABEL_PADDING);
+        let (box_x, box_y) = self.get_box_position(anchor_point, box_size);
+
+        match self.annotation_type {
+            TextAnnotationType::Text => {},
+            TextAnnotationType::Arrow => {
+                group.append(self.style.line(anchor_point, self.point));
+                if let Some(arrow_head) = self.arrow_head(anchor_point, self.point) {
+                    group.append(arrow_head);
+                }
+            },
+            TextAnnotationType::Callout => {
+                group.append(self.style.line(self.point, anchor_point));
+                group.append(
+                    Rectangle::new()
+                        .set("x", box_x)
+                        .set("y", box_y)
+                        .set("width", box_size.0)
+                        .set("height", box_size.1)
+                        .set("rx", 2)
+                        .set("fill", self.style.background.as_ref())
+                        .set("stroke", self.style.color.as_ref())
+                        .set("stroke-width", self.style.stroke_width)
+                );
+            },
+        }
+
+        let text_x = box_x + ANNOTATION_LABEL_PADDING;
+        let mut text = Text::new()
+            .set("x", text_x)
+            .set("y", box_y + ANNOTATION_LABEL_PADDING + self.font_metrics.ascent(font_size))
+            .set("font-family", self.style.font_family.as_str())
+            .set("font-size", format!("{}px", font_size))
+            .set("fill", self.style.color.as_ref());
+        for (i, line) in lines.iter().enumerate() {
+            let mut tspan = Element::new("tspan");
+            tspan.assign("x", text_x);
+            tspan.assign("dy", format!("{}em", if i == 0 { 0_f32 } else { ANNOTATION_LINE_HEIGHT }));
+            tspan.append(TextNode::new(line.as_str()));
+            text.append(tspan);
+        }
+        group.append(text);
+
+        Ok(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(annotation_type: TextAnnotationType, style: AnnotationStyle) -> TextAnnotation {
+        TextAnnotation::new(annotation_type, (100_f32, 100_f32), style, FontMetrics::new())
+    }
+
+    #[test]
+    fn anchor_point_is_offset_from_the_point() {
+        let style = AnnotationStyle::new();
+
+        assert_eq!(annotation(TextAnnotationType::Text, style.clone()).get_anchor_point(), (100_f32, 100_f32));
+        assert_eq!(annotation(TextAnnotationType::Arrow, style.clone()).get_anchor_point(), (100_f32, 60_f32));
+        assert_eq!(annotation(TextAnnotationType::Callout, style.set_offset(30_f32, 20_f32)).get_anchor_point(), (130_f32, 120_f32));
+    }
+
+    #[test]
+    fn box_is_placed_on_the_anchor_side_of_the_anchor_point() {
+        let box_position = |anchor: PointLabelPosition| {
+            annotation(TextAnnotationType::Text, AnnotationStyle::new().set_anchor(anchor))
+                .get_box_position((100_f32, 100_f32), (40_f32, 20_f32))
+        };
+
+        assert_eq!(box_position(PointLabelPosition::N), (80_f32, 80_f32));
+        assert_eq!(box_position(PointLabelPosition::SE), (100_f32, 100_f32));
+        assert_eq!(box_position(PointLabelPosition::W), (60_f32, 90_f32));
+        assert_eq!(box_position(PointLabelPosition::NW), (60_f32, 80_f32));
+    }
+
+    #[test]
+    fn text_is_wrapped_at_the_wrap_width() {
+        let style = AnnotationStyle::new().set_label(String::from("Release of the second version"));
+        let single_line = annotation(TextAnnotationType::Text, style.clone()).to_svg().unwrap().to_string();
+        let wrapped = annotation(TextAnnotationType::Text, style.set_wrap_width(60_f32)).to_svg().unwrap().to_string();
+
+        assert_eq!(single_line.matches("<tspan").count(), 1);
+        assert!(wrapped.matches("<tspan").count() > 1);
+    }
+
+    #[test]
+    fn only_callouts_have_a_background_box() {
+        let style = AnnotationStyle::new().set_label(String::from("Outage")).set_background(Color::from_hex("#ffeeaa"));
+        let callout = annotation(TextAnnotationType::Callout, style.clone()).to_svg().unwrap().to_string();
+        let text = annotation(TextAnnotationType::Text, style).to_svg().unwrap().to_string();
+
+        assert!(callout.contains("<rect") && callout.contains("fill=\"#ffeeaa\""));
+        assert!(!text.contains("<rect"));
+    }
+}
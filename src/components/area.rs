@@ -4,55 +4,268 @@ use svg::node::element::path::Data;
 use svg::node::Node;
 use crate::components::DatumRepresentation;
 use crate::components::scatter::ScatterPoint;
+use crate::components::curve::Curve;
+use crate::components::style::SeriesStyle;
+use crate::theme::{KeyStyle, Presentation};
 
-/// Represents a point in a scatter plot.
+/// Define how the areas of multiple keys are combined.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AreaMode {
+    /// Draw the area of every key from the baseline, on top of each other.
+    Overlay,
+    /// Stack the areas of the keys on top of each other (see
+    /// `AreaSeriesView::load_stacked_data`).
+    Stacked,
+    /// Draw the area of every key between the lower and upper bounds of its data
+    /// (see `PointDatum::get_y_bounds`).
+    Range,
+}
+
+/// Define the baseline of stacked areas, following D3's stack offsets.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StackOffset {
+    /// Stack the areas on a zero baseline.
+    None,
+    /// Normalize the stacks so that they always add up to 1.
+    Expand,
+    /// Center the stacks around zero.
+    Silhouette,
+    /// Shift the baseline to minimize the weighted change in slope of the areas (a streamgraph).
+    Wiggle,
+}
+
+impl StackOffset {
+    /// Stack the values of multiple series, where `values[i][j]` is the value of the i-th series
+    /// at the j-th X value. Return the lower and upper boundaries of every series at every X value.
+    pub(crate) fn stack(&self, values: &[Vec<f32>]) -> Vec<Vec<(f32, f32)>> {
+        let nr_of_points = values.iter().map(|series| series.len()).max().unwrap_or(0);
+        let value = |i: usize, j: usize| values[i].get(j).cloned().unwrap_or(0_f32);
+        let totals = (0..nr_of_points)
+            .map(|j| (0..values.len()).map(|i| value(i, j)).sum::<f32>())
+            .collect::<Vec<f32>>();
+
+        let baselines = match self {
+            StackOffset::Silhouette => totals.iter().map(|total| -total / 2_f32).collect::<Vec<f32>>(),
+            StackOffset::Wiggle => {
+                let mut baselines = vec![0_f32; nr_of_points];
+                let mut y = 0_f32;
+                for (j, baseline) in baselines.iter_mut().enumerate().skip(1) {
+                    let mut weight = 0_f32;
+                    let mut weighted_slope = 0_f32;
+                    let mut slope_below = 0_f32;
+                    for i in 0..values.len() {
+                        let change = value(i, j) - value(i, j - 1);
+                        weight += value(i, j);
+                        weighted_slope += (slope_below + change / 2_f32) * value(i, j);
+                        slope_below += change;
+                    }
+                    if weight != 0_f32 {
+                        y -= weighted_slope / weight;
+                    }
+                    *baseline = y;
+                }
+                baselines
+            },
+            _ => vec![0_f32; nr_of_points],
+        };
+
+        let mut accumulated = baselines;
+        (0..values.len()).map(|i| {
+            (0..nr_of_points).map(|j| {
+                let value = match self {
+                    StackOffset::Expand if totals[j] != 0_f32 => value(i, j) / totals[j],
+                    _ => value(i, j),
+                };
+                let lower = accumulated[j];
+                accumulated[j] += value;
+                (lower, accumulated[j])
+            }).collect()
+        }).collect()
+    }
+}
+
+/// Represents an area between a series of points and a horizontal baseline.
+/// The area consists of separate segments if the series has gaps in it.
 #[derive(Debug)]
 pub struct AreaSeries<T: Display + Clone, U: Display + Clone> {
-    points: Vec<ScatterPoint<T, U>>,
+    segments: Vec<Vec<ScatterPoint<T, U>>>,
+    baseline: f32,
     color: String,
+    curve: Curve,
+    style: SeriesStyle,
+    key_style: KeyStyle,
 }
 
 impl<T: Display + Clone, U: Display + Clone> AreaSeries<T, U> {
     pub fn new(
-        points: Vec<ScatterPoint<T, U>>,
+        segments: Vec<Vec<ScatterPoint<T, U>>>,
+        baseline: f32,
         color: String
     ) -> Self {
         Self {
-            points,
+            segments,
+            baseline,
             color,
+            curve: Curve::Linear,
+            style: SeriesStyle::new(),
+            key_style: KeyStyle::default(),
         }
     }
+
+    /// Set the style of the key of the data that the area represents, which adds
+    /// the class of the series to the classes of the area (e.g. `series-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Set the style of the area, including the opacity of its fill.
+    pub fn set_style(mut self, style: SeriesStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the curve that connects the points of the area's boundary.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
 }
 
 impl<T: Display + Clone, U: Display + Clone> DatumRepresentation for AreaSeries<T, U> {
 
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new()
-            .set("class", "line");
+            .set("class", self.key_style.get_series_classes("line"));
 
         let mut data = Data::new();
+        for segment in self.segments.iter() {
+            let coordinates = segment.iter().map(|point| (point.get_x(), point.get_y())).collect::<Vec<(f32, f32)>>();
+            data = self.curve.append_to(data, &coordinates, false);
 
-        for (i, point) in self.points.iter().enumerate() {
-            if i == 0 {
-                data = data.move_to((point.get_x(), point.get_y()));
-            } else {
-                data = data.line_to((point.get_x(), point.get_y()));
+            // Close the area along the baseline.
+            if let (Some(first), Some(last)) = (coordinates.first(), coordinates.last()) {
+                data = data
+                    .line_to((last.0, self.baseline))
+                    .line_to((first.0, self.baseline))
+                    .close();
             }
         }
 
-        data = data.close();
-
-        let area = Path::new()
-            .set("fill", self.color.as_ref())
-            .set("stroke", self.color.as_ref())
+        let mut area = Path::new()
+            .set("class", "area-path")
             .set("d", data);
+        Presentation::new()
+            .set("fill", &self.color, self.key_style.get_color())
+            .set("stroke", &self.color, self.key_style.get_color())
+            .assign_to(&mut area);
+
+        if let Some(opacity) = self.style.get_fill_opacity() {
+            area.assign("fill-opacity", opacity);
+        }
+        self.style.assign_stroke(&mut area);
 
         group.append(area);
 
-        for point in self.points.iter() {
+        for point in self.segments.iter().flatten() {
             group.append(point.to_svg()?);
         }
 
         Ok(group)
     }
 }
+
+/// Represents a shaded area between an upper and a lower boundary, such as
+/// the confidence band of a line. The boundaries are in already scaled (pixel) coordinates.
+#[derive(Debug)]
+pub struct RangeArea {
+    upper: Vec<(f32, f32)>,
+    lower: Vec<(f32, f32)>,
+    color: String,
+    opacity: f32,
+    curve: Curve,
+    style: SeriesStyle,
+    key_style: KeyStyle,
+}
+
+impl RangeArea {
+    pub fn new(upper: Vec<(f32, f32)>, lower: Vec<(f32, f32)>, color: String, opacity: f32) -> Self {
+        Self {
+            upper,
+            lower,
+            color,
+            opacity,
+            curve: Curve::Linear,
+            style: SeriesStyle::new(),
+            key_style: KeyStyle::default(),
+        }
+    }
+
+    /// Set the style of the key of the data that the area represents, which adds
+    /// the class of the series to the classes of the area (e.g. `series-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Set the style of the area. The area is outlined if the style sets a stroke width.
+    pub fn set_style(mut self, style: SeriesStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the curve that connects the points of both boundaries.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+}
+
+impl DatumRepresentation for RangeArea {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", self.key_style.get_series_classes("range-area"));
+
+        // Trace the upper boundary forwards and the lower boundary backwards.
+        let lower = self.lower.iter().rev().cloned().collect::<Vec<(f32, f32)>>();
+        let mut data = self.curve.append_to(Data::new(), &self.upper, false);
+        data = self.curve.append_to(data, &lower, true);
+
+        data = data.close();
+
+        let mut area = Path::new()
+            .set("class", "area-path")
+            .set("fill-opacity", self.style.get_fill_opacity().unwrap_or(self.opacity))
+            .set("d", data);
+        let stroke = match self.style.get_stroke_width() {
+            Some(_) => self.color.as_str(),
+            None => "none",
+        };
+        Presentation::new()
+            .set("fill", &self.color, self.key_style.get_color())
+            .set("stroke", stroke, self.key_style.get_color())
+            .assign_to(&mut area);
+        self.style.assign_stroke(&mut area);
+
+        group.append(area);
+
+        Ok(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_offsets() {
+        let values = vec![vec![1_f32, 2_f32], vec![3_f32, 2_f32]];
+        assert_eq!(StackOffset::None.stack(&values), vec![vec![(0_f32, 1_f32), (0_f32, 2_f32)], vec![(1_f32, 4_f32), (2_f32, 4_f32)]]);
+        assert_eq!(StackOffset::Expand.stack(&values), vec![vec![(0_f32, 0.25), (0_f32, 0.5)], vec![(0.25, 1_f32), (0.5, 1_f32)]]);
+        assert_eq!(StackOffset::Silhouette.stack(&values), vec![vec![(-2_f32, -1_f32), (-2_f32, 0_f32)], vec![(-1_f32, 2_f32), (0_f32, 2_f32)]]);
+        // The first series grows by 1 and the second shrinks by 1, so the baseline moves by
+        // -(2 * 0.5 + 2 * (1 - 0.5)) / 4 = -0.5.
+        assert_eq!(StackOffset::Wiggle.stack(&values)[0], vec![(0_f32, 1_f32), (-0.5, 1.5)]);
+    }
+}
@@ -1,47 +1,114 @@
-use svg::node::element::{Group, Line};
+use svg::node::element::{Element, Group, Line};
 use svg::node::Text as TextNode;
 use svg::node::element::Text;
 use svg::Node;
 use format_num::NumberFormat;
 use crate::axis::AxisPosition;
+use crate::text::FontMetrics;
+use crate::theme::Theme;
 
 /// A simple struct that represents an axis line.
+#[derive(Clone)]
 pub(crate) struct AxisLine {
     x1: f32,
     y1: f32,
     x2: f32,
     y2: f32,
+    color: String,
+    stroke_width: f32,
+    styled_with_classes: bool,
 }
 
 impl AxisLine {
     /// Create a new instance of axis line.
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self { x1, y1, x2, y2, color: String::from("#bbbbbb"), stroke_width: 1_f32, styled_with_classes: false }
+    }
+
+    /// Set the color and the width of the line's stroke from the theme.
+    pub fn set_style(&mut self, theme: &Theme) {
+        self.color = String::from(theme.get_axis_color());
+        self.stroke_width = theme.get_axis_stroke_width();
+        self.styled_with_classes = theme.is_styled_with_classes();
     }
 
     /// Render the axis line to svg.
     pub fn to_svg(&self) -> Result<Line, String> {
-        let line = Line::new()
+        let mut line = Line::new()
+            .set("class", "domain")
             .set("x1", self.x1)
             .set("y1", self.y1)
             .set("x2", self.x2)
             .set("y2", self.y2)
-            .set("shape-rendering", "crispEdges")
-            .set("stroke-width", 1)
-            .set("stroke", "#bbbbbb");
+            .set("shape-rendering", "crispEdges");
+        // The theme gives the stylesheet the same stroke, so it is left out in the classes mode.
+        if !self.styled_with_classes {
+            line.assign("stroke-width", self.stroke_width);
+            line.assign("stroke", self.color.as_str());
+        }
 
         Ok(line)
     }
 }
 
+/// The default font size of the tick labels.
+pub(crate) const TICK_LABEL_FONT_SIZE: f32 = 12_f32;
+
+/// Break a label into lines at whitespace, so that every line fits the available width
+/// in the given font size. Words that are wider than the available width are kept on a line of their own.
+pub(crate) fn wrap_label(label: &str, available_width: f32, font_size: f32, font_metrics: &FontMetrics) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for word in label.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if font_metrics.text_width(&format!("{} {}", line, word), font_size) <= available_width => {
+                line.push(' ');
+                line.push_str(word);
+            },
+            _ => lines.push(String::from(word)),
+        }
+    }
+
+    lines
+}
+
+/// Shorten a label with an ellipsis, so that it fits the available width.
+pub(crate) fn truncate_label(label: &str, available_width: f32, font_size: f32, font_metrics: &FontMetrics) -> String {
+    if font_metrics.text_width(label, font_size) <= available_width {
+        return String::from(label);
+    }
+
+    let mut truncated = String::new();
+    for c in label.chars() {
+        if font_metrics.text_width(&format!("{}{}…", truncated, c), font_size) > available_width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated.push('…');
+
+    truncated
+}
+
 /// A struct to represent an axis tick
+#[derive(Clone)]
 pub struct AxisTick {
     axis_position: AxisPosition,
     label_offset: usize,
     label_rotation: isize,
+    label_text_anchor: Option<String>,
+    label_visible: bool,
+    label_lines: Vec<String>,
+    tick_size: usize,
     tick_offset: f32,
     label: String,
-    label_format: Option<String>
+    label_format: Option<String>,
+    font_size: f32,
+    font_family: String,
+    label_color: String,
+    stroke_color: String,
+    stroke_width: f32,
+    styled_with_classes: bool,
 }
 
 impl AxisTick {
@@ -51,30 +118,170 @@ impl AxisTick {
             label_offset,
             tick_offset,
             label_rotation,
+            label_text_anchor: None,
+            label_visible: true,
+            label_lines: Vec::new(),
+            tick_size: 6,
             label,
             axis_position,
             label_format: None,
+            font_size: TICK_LABEL_FONT_SIZE,
+            font_family: String::from("sans-serif"),
+            label_color: String::from("#777"),
+            stroke_color: String::from("#bbbbbb"),
+            stroke_width: 1_f32,
+            styled_with_classes: false,
         }
     }
 
+    /// Set the font, the colors and the stroke width of the tick from the theme.
+    pub fn set_style(&mut self, theme: &Theme) {
+        self.font_size = theme.get_tick_label_font_size();
+        self.font_family = String::from(theme.get_font_family());
+        self.label_color = String::from(theme.get_text_color());
+        self.stroke_color = String::from(theme.get_axis_color());
+        self.stroke_width = theme.get_axis_stroke_width();
+        self.styled_with_classes = theme.is_styled_with_classes();
+    }
+
     /// Set label rotation.
     pub fn set_label_rotation(&mut self, rotation: isize) {
         self.label_rotation = rotation;
     }
 
-    /// Set label rotation.
+    /// Set the text anchor of the label, overriding the one that follows from the axis position.
+    pub fn set_label_text_anchor(&mut self, anchor: &str) {
+        self.label_text_anchor = Some(String::from(anchor));
+    }
+
+    /// Set whether the label is displayed.
+    pub fn set_label_visibility(&mut self, visible: bool) {
+        self.label_visible = visible;
+    }
+
+    /// Set the lines to display instead of the (formatted) label.
+    pub fn set_label_lines(&mut self, lines: Vec<String>) {
+        self.label_lines = lines;
+    }
+
+    /// Get the distance of the label from the axis line.
+    pub fn get_label_offset(&self) -> usize {
+        self.label_offset
+    }
+
+    /// Get the rotation of the label in degrees.
+    #[cfg(test)]
+    pub(crate) fn get_label_rotation(&self) -> isize {
+        self.label_rotation
+    }
+
+    /// Get the width of the longest line of the label and the number of lines.
+    fn get_label_size(&self, font_metrics: &FontMetrics) -> (f32, usize) {
+        let lines = match self.label_lines.len() {
+            0 => vec![self.get_formatted_label()],
+            _ => self.label_lines.clone(),
+        };
+        let width = lines.iter()
+            .map(|line| font_metrics.text_width(line, self.font_size))
+            .fold(0_f32, f32::max);
+
+        (width, lines.len())
+    }
+
+    /// Get the start and end of the label along the direction of its text,
+    /// relative to the point that it is anchored at.
+    fn get_label_text_range(&self, width: f32) -> (f32, f32) {
+        let anchor = match self.label_text_anchor.as_deref() {
+            Some(anchor) => anchor,
+            None => match self.axis_position {
+                AxisPosition::Top | AxisPosition::Bottom => "middle",
+                AxisPosition::Left => "end",
+                AxisPosition::Right => "start",
+            },
+        };
+
+        match anchor {
+            "middle" => (-width / 2_f32, width / 2_f32),
+            "end" => (-width, 0_f32),
+            _ => (0_f32, width),
+        }
+    }
+
+    /// Get the distance from the axis line to the farthest edge of the tick and its label.
+    pub fn get_extent(&self, font_metrics: &FontMetrics) -> f32 {
+        if !self.label_visible {
+            return self.tick_size as f32;
+        }
+
+        let (width, nr_of_lines) = self.get_label_size(font_metrics);
+        // Lines are 1.1em apart and the label is vertically centered on its first line.
+        let height = self.font_size * (0.5 + 1.1 * (nr_of_lines - 1) as f32);
+        let (start, end) = self.get_label_text_range(width);
+        let width = start.abs().max(end.abs());
+
+        let rotation = (self.label_rotation as f32).to_radians();
+        let label_extent = match self.axis_position {
+            AxisPosition::Top | AxisPosition::Bottom => width * rotation.sin().abs() + height * rotation.cos().abs(),
+            AxisPosition::Left | AxisPosition::Right => width * rotation.cos().abs() + height * rotation.sin().abs(),
+        };
+
+        (self.label_offset as f32 + label_extent).max(self.tick_size as f32)
+    }
+
+    /// Get the start and end of the label along the axis, relative to the start of the axis.
+    pub fn get_span(&self, font_metrics: &FontMetrics) -> (f32, f32) {
+        if !self.label_visible {
+            return (self.tick_offset, self.tick_offset);
+        }
+
+        let (width, _) = self.get_label_size(font_metrics);
+        let (start, end) = self.get_label_text_range(width);
+        let rotation = (self.label_rotation as f32).to_radians();
+        let half_height = self.font_size / 2_f32;
+        let (along, across) = match self.axis_position {
+            AxisPosition::Top | AxisPosition::Bottom => (rotation.cos(), half_height * rotation.sin().abs()),
+            AxisPosition::Left | AxisPosition::Right => (rotation.sin(), half_height * rotation.cos().abs()),
+        };
+
+        (
+            self.tick_offset + (start * along).min(end * along) - across,
+            self.tick_offset + (start * along).max(end * along) + across,
+        )
+    }
+
+    /// Set the length of the tick line.
+    pub fn set_tick_size(&mut self, tick_size: usize) {
+        self.tick_size = tick_size;
+    }
+
+    /// Set the distance of the label from the axis line.
+    pub fn set_label_offset(&mut self, label_offset: usize) {
+        self.label_offset = label_offset;
+    }
+
+    /// Get the offset of the tick along the axis.
+    pub fn get_tick_offset(&self) -> f32 {
+        self.tick_offset
+    }
+
+    /// Set label format.
     pub fn set_label_format(&mut self, format: &str) {
         self.label_format = Some(format.to_owned());
     }
 
-    /// Render the axis tick to svg.
-    pub fn to_svg(&self) -> Result<Group, String> {
-        let formatted_label = if self.label_format.is_some() {
+    /// Get the label, formatted with the label format (if set).
+    pub fn get_formatted_label(&self) -> String {
+        if self.label_format.is_some() {
             let formatter = NumberFormat::new();
             formatter.format(self.label_format.as_ref().unwrap(), self.label.parse::<f64>().unwrap()).replace('G', "B")
         } else {
             self.label.to_owned()
-        };
+        }
+    }
+
+    /// Render the axis tick to svg.
+    pub fn to_svg(&self) -> Result<Group, String> {
+        let formatted_label = self.get_formatted_label();
         let offsets: (f32, f32);
         let tick_line_p2: (isize, isize);
         let tick_label_offset: (isize, isize);
@@ -83,25 +290,25 @@ impl AxisTick {
         match self.axis_position {
             AxisPosition::Left => {
                 offsets = (0_f32, self.tick_offset);
-                tick_line_p2 = (-6, 0);
+                tick_line_p2 = (-(self.tick_size as isize), 0);
                 tick_label_offset = (-(self.label_offset as isize), 0);
                 tick_label_text_anchor = "end";
             },
             AxisPosition::Bottom => {
                 offsets = (self.tick_offset, 0_f32);
-                tick_line_p2 = (0, 6);
+                tick_line_p2 = (0, self.tick_size as isize);
                 tick_label_offset = (0, self.label_offset as isize);
                 tick_label_text_anchor = "middle";
             },
             AxisPosition::Right => {
                 offsets = (0_f32, self.tick_offset);
-                tick_line_p2 = (6, 0);
+                tick_line_p2 = (self.tick_size as isize, 0);
                 tick_label_offset = (self.label_offset as isize, 0);
                 tick_label_text_anchor = "start";
             },
             AxisPosition::Top => {
                 offsets = (self.tick_offset, 0_f32);
-                tick_line_p2 = (0, -6);
+                tick_line_p2 = (0, -(self.tick_size as isize));
                 tick_label_offset = (0, -(self.label_offset as isize));
                 tick_label_text_anchor = "middle";
             },
@@ -111,29 +318,69 @@ impl AxisTick {
             .set("class", "tick")
             .set("transform", format!("translate({},{})", offsets.0, offsets.1));
 
-        let tick_line = Line::new()
+        let mut tick_line = Line::new()
+            .set("class", "tick-line")
             .set("x1", 0)
             .set("y1", 0)
             .set("x2", tick_line_p2.0)
             .set("y2", tick_line_p2.1)
-            .set("shape-rendering", "crispEdges")
-            .set("stroke", "#bbbbbb")
-            .set("stroke-width", "1px");
+            .set("shape-rendering", "crispEdges");
 
-        let tick_label = Text::new()
+        let mut tick_label = Text::new()
+            .set("class", "tick-label")
             .set("transform", format!("rotate({},{},{})", self.label_rotation, tick_label_offset.0, tick_label_offset.1))
             .set("x", tick_label_offset.0)
             .set("y", tick_label_offset.1)
             .set("dy", ".35em")
-            .set("text-anchor", tick_label_text_anchor)
-            .set("font-size", "12px")
-            .set("font-family", "sans-serif")
-            .set("fill", "#777")
-            .add(TextNode::new(formatted_label));
+            .set("text-anchor", self.label_text_anchor.as_deref().unwrap_or(tick_label_text_anchor));
+
+        // The theme gives the stylesheet the same styles, so they are left out in the classes mode.
+        if !self.styled_with_classes {
+            tick_line.assign("stroke", self.stroke_color.as_str());
+            tick_line.assign("stroke-width", format!("{}px", self.stroke_width));
+            tick_label.assign("font-size", format!("{}px", self.font_size));
+            tick_label.assign("font-family", self.font_family.as_str());
+            tick_label.assign("fill", self.label_color.as_str());
+        }
+
+        match self.label_lines.len() {
+            0 => tick_label.append(TextNode::new(formatted_label)),
+            1 => tick_label.append(TextNode::new(self.label_lines[0].as_str())),
+            nr_of_lines => {
+                // Lines of labels above the axis grow upwards.
+                let first_line_dy = match self.axis_position {
+                    AxisPosition::Top => -1.1 * (nr_of_lines - 1) as f32,
+                    _ => 0_f32,
+                };
+                for (i, line) in self.label_lines.iter().enumerate() {
+                    let mut tspan = Element::new("tspan");
+                    tspan.assign("x", tick_label_offset.0);
+                    tspan.assign("dy", format!("{}em", if i == 0 { first_line_dy } else { 1.1 }));
+                    tspan.append(TextNode::new(line.as_str()));
+                    tick_label.append(tspan);
+                }
+            },
+        }
 
         group.append(tick_line);
-        group.append(tick_label);
+        if self.label_visible {
+            group.append(tick_label);
+        }
 
         Ok(group)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_fit_available_width() {
+        let font_metrics = FontMetrics::new();
+        assert_eq!(wrap_label("North America", 45_f32, TICK_LABEL_FONT_SIZE, &font_metrics), vec!["North", "America"]);
+        assert_eq!(wrap_label("a b c", 45_f32, TICK_LABEL_FONT_SIZE, &font_metrics), vec!["a b c"]);
+        assert_eq!(truncate_label("Antarctica", 45_f32, TICK_LABEL_FONT_SIZE, &font_metrics), "Antar…");
+        assert_eq!(truncate_label("Asia", 45_f32, TICK_LABEL_FONT_SIZE, &font_metrics), "Asia");
+    }
+}
@@ -5,6 +5,8 @@ use svg::node::Text as TextNode;
 use svg::node::element::Text;
 use crate::components::DatumRepresentation;
 use crate::chart::Orientation;
+use crate::components::style::DatumStyle;
+use crate::theme::{KeyStyle, Presentation};
 
 /// Set the position of a bar's label.
 #[derive(Copy, Clone, Debug)]
@@ -17,14 +19,32 @@ pub enum BarLabelPosition {
 }
 
 /// Represents a block within a bar.
-/// The first tuple element represents the starting position, the second
-/// one is the size of that block and the third one is the color.
+/// The first two tuple elements represent the starting and ending positions, the third
+/// one is the size of that block, the fourth one is the color, the next two are
+/// the optional stroke color and opacity and the last one is the style of the key of the block.
 #[derive(Debug)]
-pub struct BarBlock(f32, f32, f32, String);
+pub struct BarBlock(f32, f32, f32, String, Option<String>, Option<f32>, KeyStyle);
 
 impl BarBlock {
     pub fn new(start: f32, end: f32, size: f32, color: String) -> Self {
-        Self(start, end, size, color)
+        Self(start, end, size, color, None, None, KeyStyle::default())
+    }
+
+    /// Set the style of the key of the data that the block represents, which adds
+    /// the class of the key to the classes of the block (e.g. `key-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.6 = key_style;
+        self
+    }
+
+    /// Override the appearance of the block with the properties set in the style.
+    pub fn set_style(mut self, style: &DatumStyle) -> Self {
+        if let Some(fill) = style.get_fill() {
+            self.3 = fill;
+        }
+        self.4 = style.get_stroke();
+        self.5 = style.get_opacity();
+        self
     }
 }
 
@@ -38,6 +58,10 @@ pub struct Bar {
     category: String,
     bar_width: f32,
     offset: f32,
+    label_color: String,
+    font_family: String,
+    font_size: f32,
+    styled_with_classes: bool,
 }
 
 impl Bar {
@@ -60,8 +84,27 @@ impl Bar {
             category,
             bar_width,
             offset,
+            label_color: String::from("#333"),
+            font_family: String::from("sans-serif"),
+            font_size: 14_f32,
+            styled_with_classes: false,
         }
     }
+
+    /// Set the color, the font family and the font size of the labels of the bar's blocks.
+    pub fn set_label_style(mut self, color: String, font_family: String, font_size: f32) -> Self {
+        self.label_color = color;
+        self.font_family = font_family;
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set whether the labels are styled with classes, in which case the stylesheet of the chart
+    /// gives them the color and the font of the theme.
+    pub(crate) fn set_styled_with_classes(mut self, styled_with_classes: bool) -> Self {
+        self.styled_with_classes = styled_with_classes;
+        self
+    }
 }
 
 impl DatumRepresentation for Bar {
@@ -84,13 +127,23 @@ impl DatumRepresentation for Bar {
         };
 
         for block in self.blocks.iter() {
-            let block_rect = Rectangle::new()
+            let mut block_rect = Rectangle::new()
                 .set(x_attr, block.0)
                 .set(y_attr, 0)
                 .set(width_attr, block.1 - block.0)
                 .set(height_attr, self.bar_width)
-                .set("shape-rendering", "crispEdges")
-                .set("fill", block.3.as_ref());
+                .set("class", block.6.get_key_classes("bar-block"))
+                .set("shape-rendering", "crispEdges");
+            Presentation::new()
+                .set("fill", &block.3, block.6.get_color())
+                .assign_to(&mut block_rect);
+
+            if let Some(stroke) = &block.4 {
+                block_rect.assign("stroke", stroke.as_ref());
+            }
+            if let Some(opacity) = block.5 {
+                block_rect.assign("opacity", opacity);
+            }
 
             group.append(block_rect);
 
@@ -115,15 +168,18 @@ impl DatumRepresentation for Bar {
                     Some(nr_of_digits) => format!("{:.1$}", block.2.to_string().parse::<f32>().unwrap(), nr_of_digits)
                 };
 
-                let label = Text::new()
+                let mut label = Text::new()
+                    .set("class", "bar-label")
                     .set(x_attr, label_x_attr_value)
                     .set(y_attr, self.bar_width / 2_f32)
                     .set("text-anchor", text_anchor)
                     .set("dy", ".35em")
-                    .set("font-family", "sans-serif")
-                    .set("fill", "#333")
-                    .set("font-size", "14px")
                     .add(TextNode::new(label_text));
+                if !self.styled_with_classes {
+                    label.assign("font-family", self.font_family.as_str());
+                    label.assign("fill", self.label_color.as_str());
+                    label.assign("font-size", format!("{}px", self.font_size));
+                }
 
                 group.append(label);
             }
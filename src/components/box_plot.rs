@@ -0,0 +1,149 @@
+use svg::node::Node;
+use svg::node::element::{Group, Line, Rectangle};
+use crate::components::DatumRepresentation;
+use crate::components::scatter::{ScatterPoint, MarkerType, PointLabelPosition};
+use crate::chart::Orientation;
+use crate::theme::{KeyStyle, Presentation};
+
+/// Define how far the whiskers of a box plot extend.
+#[derive(Debug, Copy, Clone)]
+pub enum WhiskerExtent {
+    /// Extend the whiskers to the most extreme samples that are within the given
+    /// multiple of the interquartile range from the box. Samples outside of it are
+    /// displayed as outliers.
+    InterquartileRange(f32),
+    /// Extend the whiskers to the minimum and maximum samples.
+    MinMax,
+}
+
+/// The statistics of a distribution of values, in scaled (pixel) coordinates.
+#[derive(Debug, Clone)]
+pub struct BoxStatistics {
+    /// The first quartile, the median and the third quartile.
+    pub quartiles: (f32, f32, f32),
+    /// The ends of the lower and the upper whiskers.
+    pub whiskers: (f32, f32),
+    pub outliers: Vec<f32>,
+    pub mean: Option<f32>,
+}
+
+/// Represents a box (and its whiskers) that summarizes a distribution of values.
+/// All the values are expressed in already scaled (pixel) coordinates.
+#[derive(Debug)]
+pub struct BoxPlot {
+    orientation: Orientation,
+    offset: f32,
+    width: f32,
+    statistics: BoxStatistics,
+    color: String,
+    outline_color: String,
+    key_style: KeyStyle,
+}
+
+impl BoxPlot {
+    pub fn new(orientation: Orientation, offset: f32, width: f32, statistics: BoxStatistics, color: String) -> Self {
+        Self {
+            orientation,
+            offset,
+            width,
+            statistics,
+            color,
+            outline_color: String::from("#333"),
+            key_style: KeyStyle::default(),
+        }
+    }
+
+    /// Set the color of the outlines of the box, the whiskers, the median and the mean.
+    pub fn set_outline_color(mut self, color: String) -> Self {
+        self.outline_color = color;
+        self
+    }
+
+    /// Set the style of the key of the data that the box represents, which adds
+    /// the class of the key to the classes of the box (e.g. `key-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Translate a (category, value) position to a (x, y) position based on orientation.
+    fn to_xy(&self, category_position: f32, value_position: f32) -> (f32, f32) {
+        match self.orientation {
+            Orientation::Vertical => (category_position, value_position),
+            Orientation::Horizontal => (value_position, category_position),
+        }
+    }
+
+    /// Create a line between two (category, value) positions.
+    fn line(&self, from: (f32, f32), to: (f32, f32), stroke_width: usize) -> Line {
+        let (x1, y1) = self.to_xy(from.0, from.1);
+        let (x2, y2) = self.to_xy(to.0, to.1);
+
+        Line::new()
+            .set("x1", x1)
+            .set("y1", y1)
+            .set("x2", x2)
+            .set("y2", y2)
+            .set("shape-rendering", "crispEdges")
+            .set("stroke", self.outline_color.as_str())
+            .set("stroke-width", stroke_width)
+    }
+
+    /// Create a point marker at the given value position.
+    fn marker(&self, value_position: f32, marker_type: MarkerType, size: f32, color: String) -> ScatterPoint<String, String> {
+        let (x, y) = self.to_xy(self.offset + self.width / 2_f32, value_position);
+        ScatterPoint::new(x, y, marker_type, size, String::new(), String::new(), PointLabelPosition::N, false, true, color)
+    }
+}
+
+impl DatumRepresentation for BoxPlot {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", self.key_style.get_key_classes("box-plot"));
+
+        let center = self.offset + self.width / 2_f32;
+        let cap_start = self.offset + self.width / 4_f32;
+        let cap_end = self.offset + self.width * 3_f32 / 4_f32;
+        let (q1, median, q3) = self.statistics.quartiles;
+        let (box_start, box_end) = (q1.min(q3), q1.max(q3));
+        let (whisker_start, whisker_end) = (self.statistics.whiskers.0.min(self.statistics.whiskers.1), self.statistics.whiskers.0.max(self.statistics.whiskers.1));
+
+        // Whiskers and their caps.
+        group.append(self.line((center, whisker_start), (center, box_start), 1));
+        group.append(self.line((center, box_end), (center, whisker_end), 1));
+        group.append(self.line((cap_start, whisker_start), (cap_end, whisker_start), 1));
+        group.append(self.line((cap_start, whisker_end), (cap_end, whisker_end), 1));
+
+        // The box spanning the interquartile range.
+        let (x_attr, y_attr, width_attr, height_attr) = match self.orientation {
+            Orientation::Horizontal => ("x", "y", "width", "height"),
+            Orientation::Vertical => ("y", "x", "height", "width"),
+        };
+        let mut box_rect = Rectangle::new()
+            .set(x_attr, box_start)
+            .set(y_attr, self.offset)
+            .set(width_attr, box_end - box_start)
+            .set(height_attr, self.width)
+            .set("shape-rendering", "crispEdges")
+            .set("stroke", self.outline_color.as_str())
+            .set("stroke-width", 1);
+        Presentation::new()
+            .set("fill", &self.color, self.key_style.get_color())
+            .assign_to(&mut box_rect);
+        group.append(box_rect);
+
+        // The median line.
+        group.append(self.line((self.offset, median), (self.offset + self.width, median), 2));
+
+        for outlier in self.statistics.outliers.iter() {
+            group.append(self.marker(*outlier, MarkerType::Circle, 3_f32, self.color.clone()).to_svg()?);
+        }
+
+        if let Some(mean) = self.statistics.mean {
+            group.append(self.marker(mean, MarkerType::X, 4_f32, self.outline_color.clone()).to_svg()?);
+        }
+
+        Ok(group)
+    }
+}
@@ -0,0 +1,138 @@
+use svg::node::Node;
+use svg::node::element::{Group, Line, Rectangle};
+use crate::components::DatumRepresentation;
+
+/// Define how the prices of a period are drawn.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CandleStyle {
+    /// A body spanning the open and close prices, with a wick spanning the high and low prices.
+    Candlestick,
+    /// A vertical line spanning the high and low prices, with a tick on
+    /// the left for the open price and a tick on the right for the close price.
+    Ohlc,
+}
+
+/// The open, high, low and close prices of a period, in scaled (pixel) coordinates.
+#[derive(Debug, Copy, Clone)]
+pub struct CandlePrices {
+    pub open: f32,
+    pub high: f32,
+    pub low: f32,
+    pub close: f32,
+}
+
+/// Represents the open, high, low and close prices of a single period.
+/// All the values are expressed in already scaled (pixel) coordinates.
+#[derive(Debug)]
+pub struct Candle {
+    style: CandleStyle,
+    x: f32,
+    width: f32,
+    prices: CandlePrices,
+    color: String,
+}
+
+impl Candle {
+    pub fn new(style: CandleStyle, x: f32, width: f32, prices: CandlePrices, color: String) -> Self {
+        Self {
+            style,
+            x,
+            width,
+            prices,
+            color,
+        }
+    }
+
+    /// Create a line with the color of the candle.
+    fn line(&self, x1: f32, y1: f32, x2: f32, y2: f32) -> Line {
+        Line::new()
+            .set("x1", x1)
+            .set("y1", y1)
+            .set("x2", x2)
+            .set("y2", y2)
+            .set("shape-rendering", "crispEdges")
+            .set("stroke", self.color.as_ref())
+            .set("stroke-width", 1)
+    }
+}
+
+impl DatumRepresentation for Candle {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", "candle");
+
+        match self.style {
+            CandleStyle::Candlestick => {
+                let body_start = self.prices.open.min(self.prices.close);
+                // Keep a visible body even when the open and close prices are equal.
+                let body_height = (self.prices.open - self.prices.close).abs().max(1_f32);
+
+                group.append(self.line(self.x, self.prices.high, self.x, self.prices.low));
+                group.append(
+                    Rectangle::new()
+                        .set("x", self.x - self.width / 2_f32)
+                        .set("y", body_start)
+                        .set("width", self.width)
+                        .set("height", body_height)
+                        .set("shape-rendering", "crispEdges")
+                        .set("fill", self.color.as_ref())
+                        .set("stroke", self.color.as_ref())
+                        .set("stroke-width", 1)
+                );
+            },
+            CandleStyle::Ohlc => {
+                group.append(self.line(self.x, self.prices.high, self.x, self.prices.low));
+                group.append(self.line(self.x - self.width / 2_f32, self.prices.open, self.x, self.prices.open));
+                group.append(self.line(self.x, self.prices.close, self.x + self.width / 2_f32, self.prices.close));
+            },
+        }
+
+        Ok(group)
+    }
+}
+
+/// Represents the traded volume of a single period.
+/// All the values are expressed in already scaled (pixel) coordinates.
+#[derive(Debug)]
+pub struct VolumeBar {
+    x: f32,
+    width: f32,
+    start: f32,
+    end: f32,
+    color: String,
+}
+
+impl VolumeBar {
+    pub fn new(x: f32, width: f32, start: f32, end: f32, color: String) -> Self {
+        Self {
+            x,
+            width,
+            start,
+            end,
+            color,
+        }
+    }
+}
+
+impl DatumRepresentation for VolumeBar {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", "volume-bar");
+
+        group.append(
+            Rectangle::new()
+                .set("x", self.x - self.width / 2_f32)
+                .set("y", self.start.min(self.end))
+                .set("width", self.width)
+                .set("height", (self.end - self.start).abs())
+                .set("shape-rendering", "crispEdges")
+                .set("fill", self.color.as_ref())
+                .set("fill-opacity", 0.4)
+                .set("stroke", "none")
+        );
+
+        Ok(group)
+    }
+}
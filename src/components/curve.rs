@@ -0,0 +1,390 @@
+use svg::node::element::path::Data;
+
+/// Define the ways in which the points of a line or an area are connected.
+/// The curves mirror the curve factories of D3's d3-shape module.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Curve {
+    /// Connect the points with straight line segments.
+    Linear,
+    /// Connect the points with steps, changing the Y value halfway between two points.
+    Step,
+    /// Connect the points with steps, changing the Y value at the start of every step.
+    StepBefore,
+    /// Connect the points with steps, changing the Y value at the end of every step.
+    StepAfter,
+    /// A cubic basis spline. The curve passes through the first and last points only.
+    Basis,
+    /// A cardinal spline passing through all the points, with the given tension
+    /// (between 0 and 1). A tension of 1 yields straight line segments.
+    Cardinal(f32),
+    /// A Catmull-Rom spline passing through all the points, with the given alpha
+    /// (between 0 and 1). An alpha of 0.5 yields a centripetal spline.
+    CatmullRom(f32),
+    /// A cubic spline that preserves monotonicity in Y, assuming the points are monotonic in X.
+    MonotoneX,
+    /// A natural cubic spline, with its second derivative set to zero at the endpoints.
+    Natural,
+}
+
+impl Curve {
+    /// Append the curve through the given points to the path data. If `continued` is
+    /// true, the curve is connected to the end of the path with a line, otherwise it starts
+    /// a new subpath.
+    pub(crate) fn append_to(&self, data: Data, points: &[(f32, f32)], continued: bool) -> Data {
+        let (x, y) = match points.first() {
+            Some(point) => *point,
+            None => return data,
+        };
+        let mut data = match continued {
+            true => data.line_to((x, y)),
+            false => data.move_to((x, y)),
+        };
+
+        if points.len() == 1 {
+            return data;
+        }
+
+        match self {
+            Curve::Step => data = Self::step(data, points, 0.5),
+            Curve::StepBefore => data = Self::step(data, points, 0_f32),
+            Curve::StepAfter => data = Self::step(data, points, 1_f32),
+            // Splines through two points are straight lines.
+            _ if points.len() == 2 => data = data.line_to(points[1]),
+            Curve::Linear => {
+                for point in points.iter().skip(1) {
+                    data = data.line_to(*point);
+                }
+            },
+            Curve::Basis => data = Self::basis(data, points),
+            Curve::Cardinal(tension) => data = Self::cardinal(data, points, *tension),
+            Curve::CatmullRom(alpha) => data = Self::catmull_rom(data, points, *alpha),
+            Curve::MonotoneX => data = Self::monotone_x(data, points),
+            Curve::Natural => data = Self::natural(data, points),
+        }
+
+        data
+    }
+
+    /// Connect the points with steps, where `t` is the fraction of the distance between
+    /// two points at which the Y value changes.
+    fn step(mut data: Data, points: &[(f32, f32)], t: f32) -> Data {
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            data = match t > 0_f32 {
+                true => {
+                    let x = x0 * (1_f32 - t) + x1 * t;
+                    data.line_to((x, y0)).line_to((x, y1))
+                },
+                false => data.line_to((x0, y1)).line_to((x1, y1)),
+            };
+        }
+        // When the Y value changes halfway, the last step still has to reach the last point.
+        if t > 0_f32 && t < 1_f32 {
+            data = data.line_to(points[points.len() - 1]);
+        }
+        data
+    }
+
+    fn basis(mut data: Data, points: &[(f32, f32)]) -> Data {
+        let bezier = |data: Data, p0: (f32, f32), p1: (f32, f32), p: (f32, f32)| {
+            data.cubic_curve_to((
+                (2_f32 * p0.0 + p1.0) / 3_f32, (2_f32 * p0.1 + p1.1) / 3_f32,
+                (p0.0 + 2_f32 * p1.0) / 3_f32, (p0.1 + 2_f32 * p1.1) / 3_f32,
+                (p0.0 + 4_f32 * p1.0 + p.0) / 6_f32, (p0.1 + 4_f32 * p1.1 + p.1) / 6_f32,
+            ))
+        };
+
+        let (p0, p1) = (points[0], points[1]);
+        data = data.line_to(((5_f32 * p0.0 + p1.0) / 6_f32, (5_f32 * p0.1 + p1.1) / 6_f32));
+        for window in points.windows(3) {
+            data = bezier(data, window[0], window[1], window[2]);
+        }
+
+        let (p0, p1) = (points[points.len() - 2], points[points.len() - 1]);
+        data = bezier(data, p0, p1, p1);
+        data.line_to(p1)
+    }
+
+    fn cardinal(mut data: Data, points: &[(f32, f32)], tension: f32) -> Data {
+        let k = (1_f32 - tension) / 6_f32;
+        let last = points.len() - 1;
+        // Like D3, the points before the first and after the last point are the neighbours
+        // of the endpoints reflected back onto the curve (the second and the second to last point).
+        let point = |i: isize| match i {
+            -1 => points[1],
+            i if i as usize > last => points[last - 1],
+            i => points[i as usize],
+        };
+
+        for i in 0..last as isize {
+            let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+            data = data.cubic_curve_to((
+                p1.0 + k * (p2.0 - p0.0), p1.1 + k * (p2.1 - p0.1),
+                p2.0 + k * (p1.0 - p3.0), p2.1 + k * (p1.1 - p3.1),
+                p2.0, p2.1,
+            ));
+        }
+
+        data
+    }
+
+    fn catmull_rom(mut data: Data, points: &[(f32, f32)], alpha: f32) -> Data {
+        // With an alpha of zero the spline is a uniform Catmull-Rom spline, which is the
+        // same as a cardinal spline with zero tension.
+        if alpha == 0_f32 {
+            return Self::cardinal(data, points, 0_f32);
+        }
+
+        let epsilon = 1e-6;
+        let distance = |a: (f32, f32), b: (f32, f32)| {
+            ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).powf(alpha / 2_f32)
+        };
+        let last = points.len() - 1;
+
+        // Like D3, the endpoints are duplicated to act as the points before the first and after
+        // the last point, so the first and last control points coincide with the endpoints.
+        for i in 0..last {
+            let (p1, p2) = (points[i], points[i + 1]);
+            let p0 = points[i.max(1) - 1];
+            let p3 = points[(i + 2).min(last)];
+            let l12 = distance(p1, p2);
+            let mut c1 = p1;
+            let mut c2 = p2;
+
+            let l01 = distance(p0, p1);
+            if l01 > epsilon {
+                let a = 2_f32 * l01 * l01 + 3_f32 * l01 * l12 + l12 * l12;
+                let n = 3_f32 * l01 * (l01 + l12);
+                c1 = (
+                    (p1.0 * a - p0.0 * l12 * l12 + p2.0 * l01 * l01) / n,
+                    (p1.1 * a - p0.1 * l12 * l12 + p2.1 * l01 * l01) / n,
+                );
+            }
+            let l23 = distance(p2, p3);
+            if l23 > epsilon {
+                let b = 2_f32 * l23 * l23 + 3_f32 * l23 * l12 + l12 * l12;
+                let m = 3_f32 * l23 * (l23 + l12);
+                c2 = (
+                    (p2.0 * b + p1.0 * l23 * l23 - p3.0 * l12 * l12) / m,
+                    (p2.1 * b + p1.1 * l23 * l23 - p3.1 * l12 * l12) / m,
+                );
+            }
+
+            data = data.cubic_curve_to((c1.0, c1.1, c2.0, c2.1, p2.0, p2.1));
+        }
+
+        data
+    }
+
+    fn monotone_x(mut data: Data, points: &[(f32, f32)]) -> Data {
+        // Coincident points do not have a slope, so they are ignored.
+        let mut unique: Vec<(f32, f32)> = Vec::with_capacity(points.len());
+        for point in points.iter() {
+            if unique.last() != Some(point) {
+                unique.push(*point);
+            }
+        }
+        let points = unique;
+        if points.len() < 3 {
+            for point in points.iter().skip(1) {
+                data = data.line_to(*point);
+            }
+            return data;
+        }
+
+        let sign = |value: f32| if value < 0_f32 { -1_f32 } else { 1_f32 };
+        // The slope at the middle point of three consecutive points (Steffen's method).
+        let slope3 = |p0: (f32, f32), p1: (f32, f32), p2: (f32, f32)| {
+            let (h0, h1) = (p1.0 - p0.0, p2.0 - p1.0);
+            let s0 = (p1.1 - p0.1) / h0;
+            let s1 = (p2.1 - p1.1) / h1;
+            let p = (s0 * h1 + s1 * h0) / (h0 + h1);
+            let slope = (sign(s0) + sign(s1)) * s0.abs().min(s1.abs()).min(0.5 * p.abs());
+            match s0.is_finite() && s1.is_finite() && p.is_finite() {
+                true => slope,
+                false => 0_f32,
+            }
+        };
+        // The slope at an endpoint, given the slope at its neighbouring point.
+        let slope2 = |p0: (f32, f32), p1: (f32, f32), t: f32| {
+            let h = p1.0 - p0.0;
+            match h != 0_f32 {
+                true => (3_f32 * (p1.1 - p0.1) / h - t) / 2_f32,
+                false => t,
+            }
+        };
+
+        let last = points.len() - 1;
+        let mut tangents = vec![0_f32; points.len()];
+        for i in 1..last {
+            tangents[i] = slope3(points[i - 1], points[i], points[i + 1]);
+        }
+        tangents[0] = slope2(points[0], points[1], tangents[1]);
+        tangents[last] = slope2(points[last - 1], points[last], tangents[last - 1]);
+
+        for i in 0..last {
+            let ((x0, y0), (x1, y1)) = (points[i], points[i + 1]);
+            let dx = (x1 - x0) / 3_f32;
+            data = data.cubic_curve_to((x0 + dx, y0 + dx * tangents[i], x1 - dx, y1 - dx * tangents[i + 1], x1, y1));
+        }
+
+        data
+    }
+
+    fn natural(mut data: Data, points: &[(f32, f32)]) -> Data {
+        let xs = points.iter().map(|point| point.0).collect::<Vec<f32>>();
+        let ys = points.iter().map(|point| point.1).collect::<Vec<f32>>();
+        let (x_first, x_second) = Self::natural_control_points(&xs);
+        let (y_first, y_second) = Self::natural_control_points(&ys);
+
+        for i in 0..points.len() - 1 {
+            data = data.cubic_curve_to((x_first[i], y_first[i], x_second[i], y_second[i], xs[i + 1], ys[i + 1]));
+        }
+
+        data
+    }
+
+    /// Compute the first and second control points of every segment of a natural cubic
+    /// spline in one dimension, by solving a tridiagonal system (Thomas algorithm).
+    fn natural_control_points(values: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        let n = values.len() - 1;
+        let mut a = vec![1_f32; n];
+        let mut b = vec![4_f32; n];
+        let mut r = vec![0_f32; n];
+
+        a[0] = 0_f32;
+        b[0] = 2_f32;
+        r[0] = values[0] + 2_f32 * values[1];
+        for i in 1..n - 1 {
+            r[i] = 4_f32 * values[i] + 2_f32 * values[i + 1];
+        }
+        a[n - 1] = 2_f32;
+        b[n - 1] = 7_f32;
+        r[n - 1] = 8_f32 * values[n - 1] + values[n];
+
+        for i in 1..n {
+            let m = a[i] / b[i - 1];
+            b[i] -= m;
+            r[i] -= m * r[i - 1];
+        }
+
+        a[n - 1] = r[n - 1] / b[n - 1];
+        for i in (0..n - 1).rev() {
+            a[i] = (r[i] - a[i + 1]) / b[i];
+        }
+        b[n - 1] = (values[n] + a[n - 1]) / 2_f32;
+        for i in 0..n - 1 {
+            b[i] = 2_f32 * values[i + 1] - a[i + 1];
+        }
+
+        (a, b)
+    }
+}
+
+/// Define how lines and areas treat missing values (see `PointDatum::is_defined`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GapMode {
+    /// Break the line into separate segments at missing values.
+    Break,
+    /// Connect the values on either side of missing values directly.
+    Bridge,
+    /// Replace missing values with values linearly interpolated from their neighbours.
+    Interpolate,
+}
+
+impl GapMode {
+    /// Split a series into the segments to draw. Every position is the X coordinate of
+    /// a datum and its Y coordinate, if the datum is defined. The segments consist of
+    /// the index of the datum and its coordinates, which are interpolated for missing data
+    /// when interpolating. Data without a valid X coordinate are always left out.
+    pub(crate) fn segments(&self, positions: &[(f32, Option<f32>)]) -> Vec<Vec<(usize, f32, f32)>> {
+        let positions = positions.iter().enumerate()
+            .filter(|(_, (x, _))| x.is_finite())
+            .map(|(i, (x, y))| (i, *x, y.filter(|y| y.is_finite())))
+            .collect::<Vec<(usize, f32, Option<f32>)>>();
+
+        let mut segments = Vec::new();
+        let mut segment = Vec::new();
+        let mut previous: Option<(f32, f32)> = None;
+
+        for (index, (i, x, y)) in positions.iter().enumerate() {
+            match (y, self) {
+                (Some(y), _) => {
+                    segment.push((*i, *x, *y));
+                    previous = Some((*x, *y));
+                },
+                (None, GapMode::Break) => {
+                    if !segment.is_empty() {
+                        segments.push(segment);
+                        segment = Vec::new();
+                    }
+                },
+                (None, GapMode::Bridge) => {},
+                (None, GapMode::Interpolate) => {
+                    let next = positions[index + 1..].iter().find_map(|(_, x, y)| y.map(|y| (*x, y)));
+                    if let (Some((x0, y0)), Some((x1, y1))) = (previous, next) {
+                        let t = if x1 != x0 { (x - x0) / (x1 - x0) } else { 0_f32 };
+                        segment.push((*i, *x, y0 + (y1 - y0) * t));
+                    }
+                },
+            }
+        }
+        if !segment.is_empty() {
+            segments.push(segment);
+        }
+
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps_break_bridge_or_interpolate_missing_values() {
+        let positions = vec![(0_f32, Some(0_f32)), (1_f32, None), (2_f32, Some(4_f32)), (3_f32, Some(f32::NAN))];
+        assert_eq!(GapMode::Break.segments(&positions), vec![vec![(0, 0_f32, 0_f32)], vec![(2, 2_f32, 4_f32)]]);
+        assert_eq!(GapMode::Bridge.segments(&positions), vec![vec![(0, 0_f32, 0_f32), (2, 2_f32, 4_f32)]]);
+        assert_eq!(GapMode::Interpolate.segments(&positions), vec![vec![(0, 0_f32, 0_f32), (1, 1_f32, 2_f32), (2, 2_f32, 4_f32)]]);
+    }
+
+    fn path(curve: Curve, points: &[(f32, f32)]) -> String {
+        let data = curve.append_to(Data::new(), points, false);
+        svg::node::Value::from(data).to_string()
+    }
+
+    #[test]
+    fn steps_through_two_points() {
+        let points = [(0_f32, 0_f32), (2_f32, 4_f32)];
+        assert_eq!(path(Curve::Step, &points), "M0,0 L1,0 L1,4 L2,4");
+        assert_eq!(path(Curve::StepBefore, &points), "M0,0 L0,4 L2,4");
+        assert_eq!(path(Curve::StepAfter, &points), "M0,0 L2,0 L2,4");
+        assert_eq!(path(Curve::Natural, &points), "M0,0 L2,4");
+    }
+
+    #[test]
+    fn steps_through_three_points() {
+        let points = [(0_f32, 0_f32), (2_f32, 4_f32), (4_f32, 2_f32)];
+        assert_eq!(path(Curve::Step, &points), "M0,0 L1,0 L1,4 L3,4 L3,2 L4,2");
+        assert_eq!(path(Curve::StepBefore, &points), "M0,0 L0,4 L2,4 L2,2 L4,2");
+        assert_eq!(path(Curve::StepAfter, &points), "M0,0 L2,0 L2,4 L4,4 L4,2");
+    }
+
+    #[test]
+    fn cardinal_and_catmull_rom_endpoints_match_d3() {
+        // The control points D3's curveCardinal and curveCatmullRom produce for these points.
+        let points = [(0_f32, 0_f32), (6_f32, 6_f32), (12_f32, 0_f32)];
+        assert_eq!(path(Curve::Cardinal(0_f32), &points), "M0,0 C0,0,4,6,6,6 C8,6,12,0,12,0");
+        let catmull_rom = path(Curve::CatmullRom(0.5), &points);
+        assert!(catmull_rom.starts_with("M0,0 C0,0,"));
+        assert!(catmull_rom.ends_with(",12,0,12,0"));
+    }
+
+    #[test]
+    fn natural_spline_of_collinear_points_is_straight() {
+        let (first, second) = Curve::natural_control_points(&[0_f32, 3_f32, 6_f32, 9_f32]);
+        assert_eq!(first, vec![1_f32, 4_f32, 7_f32]);
+        assert_eq!(second, vec![2_f32, 5_f32, 8_f32]);
+    }
+}
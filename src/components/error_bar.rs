@@ -0,0 +1,85 @@
+use svg::node::Node;
+use svg::node::element::{Group, Line};
+use crate::components::DatumRepresentation;
+use crate::chart::Orientation;
+
+/// Represents a whisker that spans the uncertainty range of a value, with caps at both ends.
+/// The orientation is the direction in which the whisker extends, the position is the
+/// coordinate of the whisker across that direction, and all values are in pixels.
+#[derive(Debug)]
+pub struct ErrorBar {
+    orientation: Orientation,
+    position: f32,
+    start: f32,
+    end: f32,
+    cap_width: f32,
+    color: String,
+}
+
+impl ErrorBar {
+    pub fn new(orientation: Orientation, position: f32, start: f32, end: f32, cap_width: f32, color: String) -> Self {
+        Self {
+            orientation,
+            position,
+            start,
+            end,
+            cap_width,
+            color,
+        }
+    }
+
+    /// Create a line between two (across, along) positions.
+    fn line(&self, from: (f32, f32), to: (f32, f32)) -> Line {
+        let ((x1, y1), (x2, y2)) = match self.orientation {
+            Orientation::Vertical => (from, to),
+            Orientation::Horizontal => ((from.1, from.0), (to.1, to.0)),
+        };
+
+        Line::new()
+            .set("x1", x1)
+            .set("y1", y1)
+            .set("x2", x2)
+            .set("y2", y2)
+            .set("stroke", self.color.as_ref())
+            .set("stroke-width", 1)
+    }
+}
+
+impl DatumRepresentation for ErrorBar {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", "error-bar");
+
+        let (cap_start, cap_end) = (self.position - self.cap_width / 2_f32, self.position + self.cap_width / 2_f32);
+
+        group.append(self.line((self.position, self.start), (self.position, self.end)));
+        group.append(self.line((cap_start, self.start), (cap_end, self.start)));
+        group.append(self.line((cap_start, self.end), (cap_end, self.end)));
+
+        Ok(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertical_error_bars_have_horizontal_caps_at_both_ends() {
+        let svg = ErrorBar::new(Orientation::Vertical, 50_f32, 80_f32, 20_f32, 10_f32, String::from("#333")).to_svg().unwrap().to_string();
+
+        assert!(svg.contains("<line stroke=\"#333\" stroke-width=\"1\" x1=\"50\" x2=\"50\" y1=\"80\" y2=\"20\"/>"));
+        assert!(svg.contains("<line stroke=\"#333\" stroke-width=\"1\" x1=\"45\" x2=\"55\" y1=\"80\" y2=\"80\"/>"));
+        assert!(svg.contains("<line stroke=\"#333\" stroke-width=\"1\" x1=\"45\" x2=\"55\" y1=\"20\" y2=\"20\"/>"));
+    }
+
+    #[test]
+    fn horizontal_error_bars_have_vertical_caps_at_both_ends() {
+        let svg = ErrorBar::new(Orientation::Horizontal, 50_f32, 20_f32, 80_f32, 10_f32, String::from("#333")).to_svg().unwrap().to_string();
+
+        assert!(svg.contains("<line stroke=\"#333\" stroke-width=\"1\" x1=\"20\" x2=\"80\" y1=\"50\" y2=\"50\"/>"));
+        assert!(svg.contains("<line stroke=\"#333\" stroke-width=\"1\" x1=\"20\" x2=\"20\" y1=\"45\" y2=\"55\"/>"));
+        assert!(svg.contains("<line stroke=\"#333\" stroke-width=\"1\" x1=\"80\" x2=\"80\" y1=\"45\" y2=\"55\"/>"));
+    }
+}
@@ -0,0 +1,77 @@
+use svg::node::Node;
+use svg::node::element::{Group, Rectangle};
+use svg::node::Text as TextNode;
+use svg::node::element::Text;
+use crate::components::DatumRepresentation;
+
+/// Represents a single cell of a heatmap.
+#[derive(Debug)]
+pub struct HeatmapCell {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    color: String,
+    label: Option<String>,
+    label_color: String,
+    font_family: String,
+    font_size: f32,
+}
+
+impl HeatmapCell {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, color: String, label: Option<String>, label_color: String) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            color,
+            label,
+            label_color,
+            font_family: String::from("sans-serif"),
+            font_size: 12_f32,
+        }
+    }
+
+    /// Set the font family and the font size of the cell's label.
+    pub fn set_label_font(mut self, font_family: String, font_size: f32) -> Self {
+        self.font_family = font_family;
+        self.font_size = font_size;
+        self
+    }
+}
+
+impl DatumRepresentation for HeatmapCell {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", "heatmap-cell");
+
+        group.append(
+            Rectangle::new()
+                .set("x", self.x)
+                .set("y", self.y)
+                .set("width", self.width)
+                .set("height", self.height)
+                .set("shape-rendering", "crispEdges")
+                .set("fill", self.color.as_ref())
+                .set("stroke", "none")
+        );
+
+        if let Some(label) = &self.label {
+            group.append(
+                Text::new()
+                    .set("x", self.x + self.width / 2_f32)
+                    .set("y", self.y + self.height / 2_f32)
+                    .set("text-anchor", "middle")
+                    .set("dy", ".35em")
+                    .set("font-family", self.font_family.as_str())
+                    .set("fill", self.label_color.as_ref())
+                    .set("font-size", format!("{}px", self.font_size))
+                    .add(TextNode::new(label.clone()))
+            );
+        }
+
+        Ok(group)
+    }
+}
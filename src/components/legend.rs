@@ -1,15 +1,38 @@
-use svg::node::element::{Group, Circle, Rectangle, Line};
+use svg::node::element::{Group, Rectangle, Line};
 use svg::Node;
 use svg::node::Text as TextNode;
 use svg::node::element::Text;
-use crate::MarkerType;
+use crate::{MarkerType, MarkerStyle};
+use crate::components::scatter::MarkerAppearance;
+use crate::colors::scale::ColorScale;
+use crate::components::style::SeriesStyle;
+use crate::text::FontMetrics;
+use crate::theme::{Theme, Presentation};
+
+/// The number of colors used to represent a continuous color scale in the legend.
+const COLOR_BAR_RESOLUTION: usize = 32;
 
 /// Represents the possible marker types that a legend entry can have.
 pub enum LegendMarkerType {
     Circle,
     Square,
     X,
+    TriangleUp,
+    TriangleDown,
+    Diamond,
+    Cross,
+    Star,
+    Pentagon,
+    Hexagon,
+    /// A marker drawn from an SVG path that fits within the square from (-1, -1) to (1, 1).
+    Custom(&'static str),
     Line,
+    /// A bar that displays a color scale, labeled with the limits of the scale's domain.
+    ColorBar {
+        colors: Vec<String>,
+        min_label: String,
+        max_label: String,
+    },
 }
 
 impl From<MarkerType> for LegendMarkerType {
@@ -18,6 +41,14 @@ impl From<MarkerType> for LegendMarkerType {
             MarkerType::Circle => LegendMarkerType::Circle,
             MarkerType::Square => LegendMarkerType::Square,
             MarkerType::X => LegendMarkerType::X,
+            MarkerType::TriangleUp => LegendMarkerType::TriangleUp,
+            MarkerType::TriangleDown => LegendMarkerType::TriangleDown,
+            MarkerType::Diamond => LegendMarkerType::Diamond,
+            MarkerType::Cross => LegendMarkerType::Cross,
+            MarkerType::Star => LegendMarkerType::Star,
+            MarkerType::Pentagon => LegendMarkerType::Pentagon,
+            MarkerType::Hexagon => LegendMarkerType::Hexagon,
+            MarkerType::Custom(path) => LegendMarkerType::Custom(path),
         }
     }
 }
@@ -25,97 +56,230 @@ impl From<MarkerType> for LegendMarkerType {
 /// Represents an entry in the chart's legend.
 pub struct LegendEntry {
     marker_type: LegendMarkerType,
+    marker_style: MarkerStyle,
     marker_size: usize,
     marker_to_label_gap: usize,
     color: String,
     stroke_type: String,
+    series_style: SeriesStyle,
     label: String,
+    key: String,
 }
 
 impl LegendEntry {
+    /// Create a legend entry that displays the colors of the scale as a bar, labeled with the
+    /// limits of the scale's domain formatted by `format`. Returns `None` if the scale has no domain.
+    pub(crate) fn from_color_scale(color_scale: &ColorScale, label: String, format: impl Fn(f32) -> String) -> Option<Self> {
+        let (min, max) = color_scale.get_domain()?;
+        let colors = color_scale.get_samples(COLOR_BAR_RESOLUTION).iter()
+            .map(|color| color.as_hex())
+            .collect::<Vec<String>>();
+        let marker_type = LegendMarkerType::ColorBar {
+            colors,
+            min_label: format(min),
+            max_label: format(max),
+        };
+
+        Some(Self::new(marker_type, String::from("none"), String::from("none"), label))
+    }
+
     /// Create a new legend entry.
     pub fn new(marker_type: LegendMarkerType, color: String, stroke_type: String, label: String) -> Self {
         Self {
             marker_type,
+            marker_style: MarkerStyle::Filled,
             marker_size: 7,
             marker_to_label_gap: 6,
             color,
             stroke_type,
+            series_style: SeriesStyle::new(),
             label,
+            key: String::new(),
         }
     }
 
+    /// Set the key of the series that the entry represents, which adds the class of the series
+    /// to the classes of the entry (e.g. `series-revenue`), like it does to the classes of the series.
+    pub fn set_key(mut self, key: &str) -> Self {
+        self.key = String::from(key);
+        self
+    }
+
+    /// Set the size of the entry's marker.
+    pub fn set_marker_size(mut self, marker_size: usize) -> Self {
+        self.marker_size = marker_size;
+        self
+    }
+
+    /// Set the style of the series that the entry represents, so that the entry's
+    /// marker is drawn with the same stroke and fill opacity as the series.
+    pub fn set_series_style(mut self, style: SeriesStyle) -> Self {
+        self.series_style = style;
+        self
+    }
+
+    /// Set whether the entry's marker is filled or hollow.
+    pub fn set_marker_style(mut self, marker_style: MarkerStyle) -> Self {
+        self.marker_style = marker_style;
+        self
+    }
+
+    /// Return legend entry height to compute the height of legend rows.
+    pub fn get_height(&self) -> usize {
+        self.marker_size * 2
+    }
+
     /// Return legend entry width to compute the placement of legend entries on the chart.
-    pub fn get_width(&self) -> usize {
-        // TODO ideally, compute the length of the given `label` in the given font and size
-        let avg_letter_width = 7; // this is for the default sans-serif 12px font + some buffer
-        avg_letter_width * self.label.len() + self.marker_size * 2 + self.marker_to_label_gap
+    pub fn get_width(&self, font_metrics: &FontMetrics, theme: &Theme) -> usize {
+        let font_size = theme.get_legend_font_size();
+        let marker_width = match &self.marker_type {
+            LegendMarkerType::ColorBar { min_label, max_label, .. } => {
+                let labels_width = font_metrics.text_width(min_label, font_size) + font_metrics.text_width(max_label, font_size);
+                labels_width.ceil() as usize + self.color_bar_width() + 2 * self.marker_to_label_gap
+            },
+            _ => self.marker_size * 2,
+        };
+        font_metrics.text_width(&self.label, font_size).ceil() as usize + marker_width + self.marker_to_label_gap
+    }
+
+    /// Return the color of the entry's marker.
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+
+    /// Return the key of the series that the entry represents.
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    /// Return the label of the entry.
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    /// Return whether the entry has the same label and color as the other one, as is the case
+    /// for entries of the same series that is displayed in multiple charts.
+    pub fn is_duplicate_of(&self, other: &LegendEntry) -> bool {
+        self.label == other.label && self.color == other.color
+    }
+
+    /// Return the width of the bar of a color bar marker.
+    fn color_bar_width(&self) -> usize {
+        self.marker_size * 14
     }
 
-    pub fn to_svg(&self) -> Result<Group, String> {
+    pub fn to_svg(&self, font_metrics: &FontMetrics, theme: &Theme) -> Result<Group, String> {
+        let font_size = theme.get_legend_font_size();
+        let key_style = theme.get_key_style(&self.key);
         let mut group = Group::new()
-            .set("class", "legend-entry");
-
-        match self.marker_type {
-            LegendMarkerType::Circle => group.append(
-                Circle::new()
-                    .set("cx", self.marker_size)
-                    .set("cy", self.marker_size)
-                    .set("r", self.marker_size)
-                    .set("fill", self.color.as_ref())
-                    .set("stroke", "none")
-            ),
-            LegendMarkerType::Square => group.append(
-                Rectangle::new()
-                    .set("x", 0)
-                    .set("y", 0)
-                    .set("width", 2 * self.marker_size)
-                    .set("height", 2 * self.marker_size)
-                    .set("fill", self.color.as_ref())
-                    .set("stroke", "none")
-            ),
-            LegendMarkerType::X => {
-                group.append(
-                    Line::new()
-                        .set("x1", 0)
-                        .set("y1", 0)
-                        .set("x2", 2 * self.marker_size)
-                        .set("y2", 2 * self.marker_size)
-                        .set("stroke", self.color.as_ref())
-                        .set("stroke-width", "2px")
-                );
-                group.append(
-                    Line::new()
-                        .set("x1", 2 * self.marker_size)
-                        .set("y1", 0)
-                        .set("x2", 0)
-                        .set("y2", 2 * self.marker_size)
-                        .set("stroke", self.color.as_ref())
-                        .set("stroke-width", "2px")
-                )
-            },
-            LegendMarkerType::Line => group.append(
-                Line::new()
+            .set("class", key_style.get_series_classes("legend-entry"));
+
+        let mut label_offset = 2 * self.marker_size + self.marker_to_label_gap;
+
+        match &self.marker_type {
+            LegendMarkerType::Line => {
+                let mut line = Line::new()
+                    .set("class", "line-path")
                     .set("x1", 0)
                     .set("y1", self.marker_size)
                     .set("x2", 2 * self.marker_size)
                     .set("y2", self.marker_size)
-                    .set("stroke", self.color.as_ref())
                     .set("stroke-width", "2px")
-                    .set("stroke-dasharray", self.stroke_type.as_ref())
-            ),
+                    .set("stroke-dasharray", self.stroke_type.as_ref());
+                Presentation::new()
+                    .set("stroke", &self.color, key_style.get_color())
+                    .assign_to(&mut line);
+                self.series_style.assign_stroke(&mut line);
+                group.append(line);
+            },
+            LegendMarkerType::ColorBar { colors, min_label, max_label } => {
+                // The bar is drawn as adjacent blocks rather than a gradient, so that
+                // quantized scales keep their discrete steps.
+                let bar_start = font_metrics.text_width(min_label, font_size).ceil() as usize + self.marker_to_label_gap;
+                let block_width = self.color_bar_width() as f32 / colors.len().max(1) as f32;
+
+                group.append(
+                    Text::new()
+                        .set("x", bar_start - self.marker_to_label_gap / 2)
+                        .set("y", self.marker_size)
+                        .set("dy", ".35em")
+                        .set("text-anchor", "end")
+                        .set("font-family", theme.get_font_family())
+                        .set("fill", theme.get_text_color())
+                        .set("font-size", format!("{}px", font_size))
+                        .add(TextNode::new(min_label.clone()))
+                );
+                for (i, color) in colors.iter().enumerate() {
+                    group.append(
+                        Rectangle::new()
+                            .set("x", bar_start as f32 + block_width * i as f32)
+                            .set("y", 0)
+                            .set("width", block_width)
+                            .set("height", 2 * self.marker_size)
+                            .set("shape-rendering", "crispEdges")
+                            .set("fill", color.as_ref())
+                            .set("stroke", "none")
+                    );
+                }
+                group.append(
+                    Text::new()
+                        .set("x", bar_start + self.color_bar_width() + self.marker_to_label_gap / 2)
+                        .set("y", self.marker_size)
+                        .set("dy", ".35em")
+                        .set("font-family", theme.get_font_family())
+                        .set("fill", theme.get_text_color())
+                        .set("font-size", format!("{}px", font_size))
+                        .add(TextNode::new(max_label.clone()))
+                );
+
+                label_offset = bar_start + self.color_bar_width() + self.marker_to_label_gap + font_metrics.text_width(max_label, font_size).ceil() as usize + self.marker_to_label_gap;
+            },
+            marker_type => {
+                let marker_type = match marker_type {
+                    LegendMarkerType::Square => MarkerType::Square,
+                    LegendMarkerType::X => MarkerType::X,
+                    LegendMarkerType::TriangleUp => MarkerType::TriangleUp,
+                    LegendMarkerType::TriangleDown => MarkerType::TriangleDown,
+                    LegendMarkerType::Diamond => MarkerType::Diamond,
+                    LegendMarkerType::Cross => MarkerType::Cross,
+                    LegendMarkerType::Star => MarkerType::Star,
+                    LegendMarkerType::Pentagon => MarkerType::Pentagon,
+                    LegendMarkerType::Hexagon => MarkerType::Hexagon,
+                    LegendMarkerType::Custom(path) => MarkerType::Custom(path),
+                    _ => MarkerType::Circle,
+                };
+                let size = self.marker_size as f32;
+                // Like the series, the marker is only outlined if the style sets a stroke width.
+                let stroke = self.series_style.get_stroke_width().map(|_| self.color.as_str());
+                let appearance = MarkerAppearance {
+                    color: &self.color,
+                    style: self.marker_style,
+                    stroke,
+                    styled_fill: key_style.get_color(),
+                };
+                marker_type.append_svg(&mut group, (size, size), size, &appearance);
+                if let Some(opacity) = self.series_style.get_fill_opacity() {
+                    group.assign("fill-opacity", opacity);
+                }
+                if stroke.is_some() {
+                    self.series_style.assign_stroke(&mut group);
+                }
+            },
         }
 
-        group.append(
-            Text::new()
-                .set("x", 2 * self.marker_size + self.marker_to_label_gap)
-                .set("y", self.marker_size)
-                .set("dy", ".35em")
-                .set("font-family", "sans-serif")
-                .set("fill", "#777")
-                .set("font-size", "12px")
-                .add(TextNode::new(self.label.clone()))
-        );
+        let mut label = Text::new()
+            .set("class", "legend-label")
+            .set("x", label_offset)
+            .set("y", self.marker_size)
+            .set("dy", ".35em")
+            .add(TextNode::new(self.label.clone()));
+        let font_size = format!("{}px", font_size);
+        Presentation::new()
+            .set("font-family", theme.get_font_family(), theme.in_stylesheet(theme.get_font_family()))
+            .set("fill", theme.get_text_color(), theme.in_stylesheet(theme.get_text_color()))
+            .set("font-size", &font_size, theme.in_stylesheet(&font_size))
+            .assign_to(&mut label);
+        group.append(label);
 
         Ok(group)
     }
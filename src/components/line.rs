@@ -4,51 +4,88 @@ use svg::node::element::path::Data;
 use svg::node::Node;
 use crate::components::DatumRepresentation;
 use crate::components::scatter::ScatterPoint;
+use crate::components::curve::Curve;
+use crate::components::style::SeriesStyle;
+use crate::theme::{KeyStyle, Presentation};
 
-/// Represents a point in a scatter plot.
+/// Represents a line in a line chart. The line consists of separate segments
+/// if it has gaps in it.
 #[derive(Debug)]
 pub struct LineSeries<T: Display, U: Display> {
-    points: Vec<ScatterPoint<T, U>>,
+    segments: Vec<Vec<ScatterPoint<T, U>>>,
     color: String,
+    curve: Curve,
+    style: SeriesStyle,
+    key_style: KeyStyle,
 }
 
 impl<T: Display, U: Display> LineSeries<T, U> {
     pub fn new(
         points: Vec<ScatterPoint<T, U>>,
         color: String
+    ) -> Self {
+        Self::from_segments(vec![points], color)
+    }
+
+    /// Create a line that consists of separate segments, with gaps between them.
+    pub fn from_segments(
+        segments: Vec<Vec<ScatterPoint<T, U>>>,
+        color: String
     ) -> Self {
         Self {
-            points,
+            segments,
             color,
+            curve: Curve::Linear,
+            style: SeriesStyle::new(),
+            key_style: KeyStyle::default(),
         }
     }
+
+    /// Set the style of the key of the data that the line represents, which adds
+    /// the class of the series to the classes of the line (e.g. `series-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Set the style of the line.
+    pub fn set_style(mut self, style: SeriesStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the curve that connects the points of the line.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
 }
 
 impl<T: Display, U: Display> DatumRepresentation for LineSeries<T, U> {
 
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new()
-            .set("class", "line");
+            .set("class", self.key_style.get_series_classes("line"));
 
         let mut data = Data::new();
-
-        for (i, point) in self.points.iter().enumerate() {
-            if i == 0 {
-                data = data.move_to((point.get_x(), point.get_y()));
-            } else {
-                data = data.line_to((point.get_x(), point.get_y()));
-            }
+        for segment in self.segments.iter() {
+            let coordinates = segment.iter().map(|point| (point.get_x(), point.get_y())).collect::<Vec<(f32, f32)>>();
+            data = self.curve.append_to(data, &coordinates, false);
         }
 
-        let line = Path::new()
+        let mut line = Path::new()
+            .set("class", "line-path")
             .set("fill", "none")
-            .set("stroke", self.color.as_ref())
             .set("stroke-width", 2)
             .set("d", data);
+        Presentation::new()
+            .set("stroke", &self.color, self.key_style.get_color())
+            .assign_to(&mut line);
+        self.style.assign_stroke(&mut line);
 
         group.append(line);
 
-        for point in self.points.iter() {
+        for point in self.segments.iter().flatten() {
             group.append(point.to_svg()?);
         }
 
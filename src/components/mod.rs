@@ -6,6 +6,15 @@ pub(crate) mod scatter;
 pub(crate) mod line;
 pub(crate) mod legend;
 pub(crate) mod area;
+pub(crate) mod box_plot;
+pub(crate) mod violin;
+pub(crate) mod pie;
+pub(crate) mod error_bar;
+pub(crate) mod candle;
+pub(crate) mod style;
+pub(crate) mod heatmap;
+pub(crate) mod curve;
+pub(crate) mod annotation;
 
 /// A trait that defines behavior of chart components.
 pub trait DatumRepresentation {
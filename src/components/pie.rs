@@ -0,0 +1,254 @@
+use std::f32::consts::PI;
+use svg::node::Node;
+use svg::node::element::{Group, Path, Polyline};
+use svg::node::element::path::Data;
+use svg::node::Text as TextNode;
+use svg::node::element::Text;
+use crate::components::DatumRepresentation;
+use crate::components::style::DatumStyle;
+use crate::theme::{KeyStyle, Presentation};
+
+/// Set the position of a pie slice's label.
+#[derive(Copy, Clone, Debug)]
+pub enum PieLabelPosition {
+    Inside,
+    /// Place the label outside of the pie, connected to its slice by a leader line.
+    Outside,
+}
+
+/// Set the content of a pie slice's label.
+#[derive(Copy, Clone, Debug)]
+pub enum PieLabelType {
+    Category,
+    Value,
+    Percentage,
+}
+
+/// The angles and the radii that delimit a slice of a pie or donut chart.
+#[derive(Debug, Copy, Clone)]
+pub struct SliceGeometry {
+    pub start_angle: f32,
+    pub end_angle: f32,
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+    /// The radius with which the corners of the slice are rounded.
+    pub corner_radius: f32,
+}
+
+/// Represents a slice of a pie or donut chart. Angles are expressed in radians,
+/// clockwise, starting from 12 o'clock, and the slice is drawn around the origin.
+#[derive(Debug)]
+pub struct PieSlice {
+    geometry: SliceGeometry,
+    color: String,
+    stroke: Option<String>,
+    opacity: Option<f32>,
+    label: Option<String>,
+    label_position: PieLabelPosition,
+    label_color: String,
+    font_family: String,
+    font_size: f32,
+    leader_line_color: String,
+    key_style: KeyStyle,
+}
+
+impl PieSlice {
+    pub fn new(geometry: SliceGeometry, color: String, label: Option<String>, label_position: PieLabelPosition) -> Self {
+        Self {
+            geometry,
+            color,
+            stroke: None,
+            opacity: None,
+            label,
+            label_position,
+            label_color: String::from("#333"),
+            font_family: String::from("sans-serif"),
+            font_size: 14_f32,
+            leader_line_color: String::from("#777"),
+            key_style: KeyStyle::default(),
+        }
+    }
+
+    /// Set the color, the font family and the font size of the slice's label.
+    pub fn set_label_style(mut self, color: String, font_family: String, font_size: f32) -> Self {
+        self.label_color = color;
+        self.font_family = font_family;
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set the color of the line that connects a label outside of the pie to the slice.
+    pub fn set_leader_line_color(mut self, color: String) -> Self {
+        self.leader_line_color = color;
+        self
+    }
+
+    /// Set the style of the key of the data that the slice represents, which adds
+    /// the class of the key to the classes of the slice (e.g. `key-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Override the appearance of the slice with the properties set in the style.
+    pub fn set_style(mut self, style: &DatumStyle) -> Self {
+        if let Some(fill) = style.get_fill() {
+            self.color = fill;
+        }
+        self.stroke = style.get_stroke();
+        self.opacity = style.get_opacity();
+        self
+    }
+
+    /// Return the point at the given angle and distance from the origin.
+    fn point(angle: f32, radius: f32) -> (f32, f32) {
+        (radius * angle.sin(), -radius * angle.cos())
+    }
+
+    /// Generate the path of the slice, rounding its corners if a corner radius is set.
+    fn path(&self) -> Data {
+        let (a0, a1) = (self.geometry.start_angle, self.geometry.end_angle);
+        let (r0, r1) = (self.geometry.inner_radius, self.geometry.outer_radius);
+        let span = a1 - a0;
+        let large_arc = |angle: f32| if angle > PI { 1 } else { 0 };
+
+        // A full ring cannot be drawn by a single arc command, so draw it as two halves.
+        if span >= 2_f32 * PI - 1e-4 {
+            let mut data = Data::new()
+                .move_to(Self::point(a0, r1))
+                .elliptical_arc_to((r1, r1, 0, 0, 1, Self::point(a0 + PI, r1).0, Self::point(a0 + PI, r1).1))
+                .elliptical_arc_to((r1, r1, 0, 0, 1, Self::point(a0, r1).0, Self::point(a0, r1).1))
+                .close();
+            if r0 > 0_f32 {
+                data = data
+                    .move_to(Self::point(a0, r0))
+                    .elliptical_arc_to((r0, r0, 0, 0, 0, Self::point(a0 + PI, r0).0, Self::point(a0 + PI, r0).1))
+                    .elliptical_arc_to((r0, r0, 0, 0, 0, Self::point(a0, r0).0, Self::point(a0, r0).1))
+                    .close();
+            }
+            return data;
+        }
+
+        // Limit the corner radius so that corners never overlap each other.
+        let half_span_sin = (span / 2_f32).sin();
+        let outer_corner = self.geometry.corner_radius
+            .min((r1 - r0) / 2_f32)
+            .min(r1 * half_span_sin / (1_f32 + half_span_sin))
+            .max(0_f32);
+        let inner_corner = match r0 > 0_f32 && half_span_sin < 1_f32 {
+            true => outer_corner.min(r0 * half_span_sin / (1_f32 - half_span_sin)),
+            false => 0_f32,
+        };
+
+        let mut data;
+
+        if outer_corner > 0_f32 {
+            let delta = (outer_corner / (r1 - outer_corner)).asin();
+            let radial_distance = (r1 - outer_corner) * delta.cos();
+            let start = Self::point(a0, radial_distance);
+            let arc_start = Self::point(a0 + delta, r1);
+            let arc_end = Self::point(a1 - delta, r1);
+            let end = Self::point(a1, radial_distance);
+            data = Data::new()
+                .move_to(start)
+                .elliptical_arc_to((outer_corner, outer_corner, 0, 0, 1, arc_start.0, arc_start.1))
+                .elliptical_arc_to((r1, r1, 0, large_arc(span - 2_f32 * delta), 1, arc_end.0, arc_end.1))
+                .elliptical_arc_to((outer_corner, outer_corner, 0, 0, 1, end.0, end.1));
+        } else {
+            let end = Self::point(a1, r1);
+            data = Data::new()
+                .move_to(Self::point(a0, r1))
+                .elliptical_arc_to((r1, r1, 0, large_arc(span), 1, end.0, end.1));
+        }
+
+        if r0 <= 0_f32 {
+            data = data.line_to((0, 0));
+        } else if inner_corner > 0_f32 {
+            let delta = (inner_corner / (r0 + inner_corner)).asin();
+            let radial_distance = (r0 + inner_corner) * delta.cos();
+            let arc_start = Self::point(a1 - delta, r0);
+            let arc_end = Self::point(a0 + delta, r0);
+            let end = Self::point(a0, radial_distance);
+            data = data
+                .line_to(Self::point(a1, radial_distance))
+                .elliptical_arc_to((inner_corner, inner_corner, 0, 0, 1, arc_start.0, arc_start.1))
+                .elliptical_arc_to((r0, r0, 0, large_arc(span - 2_f32 * delta), 0, arc_end.0, arc_end.1))
+                .elliptical_arc_to((inner_corner, inner_corner, 0, 0, 1, end.0, end.1));
+        } else {
+            let end = Self::point(a0, r0);
+            data = data
+                .line_to(Self::point(a1, r0))
+                .elliptical_arc_to((r0, r0, 0, large_arc(span), 0, end.0, end.1));
+        }
+
+        data.close()
+    }
+}
+
+impl DatumRepresentation for PieSlice {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", self.key_style.get_key_classes("pie-slice"));
+
+        if let Some(opacity) = self.opacity {
+            group.assign("opacity", opacity);
+        }
+
+        let mut slice = Path::new()
+            .set("stroke", self.stroke.as_deref().unwrap_or("none"))
+            .set("d", self.path());
+        Presentation::new()
+            .set("fill", &self.color, self.key_style.get_color())
+            .assign_to(&mut slice);
+        group.append(slice);
+
+        if let Some(label) = &self.label {
+            let mid_angle = (self.geometry.start_angle + self.geometry.end_angle) / 2_f32;
+
+            match self.label_position {
+                PieLabelPosition::Inside => {
+                    let (x, y) = Self::point(mid_angle, (self.geometry.inner_radius + self.geometry.outer_radius) / 2_f32);
+                    group.append(
+                        Text::new()
+                            .set("x", x)
+                            .set("y", y)
+                            .set("text-anchor", "middle")
+                            .set("dy", ".35em")
+                            .set("font-family", self.font_family.as_str())
+                            .set("fill", self.label_color.as_str())
+                            .set("font-size", format!("{}px", self.font_size))
+                            .add(TextNode::new(label.clone()))
+                    );
+                },
+                PieLabelPosition::Outside => {
+                    // The leader line goes out radially and then horizontally towards the label.
+                    let side = if mid_angle.sin() >= 0_f32 { 1_f32 } else { -1_f32 };
+                    let (x1, y1) = Self::point(mid_angle, self.geometry.outer_radius);
+                    let (x2, y2) = Self::point(mid_angle, self.geometry.outer_radius + 12_f32);
+                    let x3 = x2 + side * 12_f32;
+                    group.append(
+                        Polyline::new()
+                            .set("points", format!("{},{} {},{} {},{}", x1, y1, x2, y2, x3, y2))
+                            .set("fill", "none")
+                            .set("stroke", self.leader_line_color.as_str())
+                            .set("stroke-width", 1)
+                    );
+                    group.append(
+                        Text::new()
+                            .set("x", x3 + side * 4_f32)
+                            .set("y", y2)
+                            .set("text-anchor", if side > 0_f32 { "start" } else { "end" })
+                            .set("dy", ".35em")
+                            .set("font-family", self.font_family.as_str())
+                            .set("fill", self.label_color.as_str())
+                            .set("font-size", format!("{}px", self.font_size))
+                            .add(TextNode::new(label.clone()))
+                    );
+                },
+            }
+        }
+
+        Ok(group)
+    }
+}
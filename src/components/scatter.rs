@@ -1,16 +1,177 @@
+use std::f32::consts::PI;
 use std::fmt::Display;
-use svg::node::element::{Group, Circle, Rectangle, Line};
+use svg::node::element::{Group, Circle, Rectangle, Line, Path};
+use svg::node::element::path::Data;
 use svg::node::Node;
 use svg::node::Text as TextNode;
 use svg::node::element::Text;
 use crate::components::DatumRepresentation;
+use crate::components::style::DatumStyle;
+use crate::theme::{KeyStyle, Presentation};
 
 /// Define the possible types of points in a scatter plot.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum MarkerType {
     Circle,
     Square,
     X,
+    TriangleUp,
+    TriangleDown,
+    Diamond,
+    /// A plus-shaped marker.
+    Cross,
+    Star,
+    Pentagon,
+    Hexagon,
+    /// A marker drawn from an SVG path. The path should fit within the square
+    /// from (-1, -1) to (1, 1), as it is scaled by the size of the marker.
+    Custom(&'static str),
+}
+
+/// Define whether markers are filled with their color or only outlined with it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MarkerStyle {
+    Filled,
+    Hollow,
+}
+
+/// The colors and the style that a marker is drawn with.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct MarkerAppearance<'m> {
+    pub(crate) color: &'m str,
+    pub(crate) style: MarkerStyle,
+    /// The color that the marker is outlined with, if any.
+    pub(crate) stroke: Option<&'m str>,
+    /// The fill that the marker takes from the stylesheet of the chart (see `KeyStyle::get_color`).
+    pub(crate) styled_fill: Option<&'m str>,
+}
+
+impl MarkerType {
+    /// Append the SVG representation of a marker of the given size, centered
+    /// at the given position, to the group. If the appearance has a stroke color,
+    /// the marker is outlined with it.
+    pub(crate) fn append_svg(&self, group: &mut Group, center: (f32, f32), size: f32, appearance: &MarkerAppearance) {
+        let (cx, cy) = center;
+        let color = appearance.color;
+        let (fill, stroke) = match appearance.style {
+            MarkerStyle::Filled => (color, appearance.stroke),
+            MarkerStyle::Hollow => ("none", Some(appearance.stroke.unwrap_or(color))),
+        };
+        let fill = Presentation::new().set("fill", fill, appearance.styled_fill);
+
+        match self {
+            MarkerType::Circle => {
+                let mut marker = Circle::new()
+                    .set("cx", cx)
+                    .set("cy", cy)
+                    .set("r", size);
+                fill.assign_to(&mut marker);
+                Self::assign_stroke(&mut marker, stroke);
+                group.append(marker);
+            },
+            MarkerType::Square => {
+                let mut marker = Rectangle::new()
+                    .set("x", cx - size)
+                    .set("y", cy - size)
+                    .set("width", 2_f32 * size)
+                    .set("height", 2_f32 * size);
+                fill.assign_to(&mut marker);
+                Self::assign_stroke(&mut marker, stroke);
+                group.append(marker);
+            },
+            MarkerType::X => {
+                // The X marker is made of lines, so it is always drawn with the marker's color.
+                let color = stroke.unwrap_or(color);
+                group.append(
+                    Group::new()
+                        .add(
+                            Line::new()
+                                .set("x1", cx - size)
+                                .set("y1", cy - size)
+                                .set("x2", cx + size)
+                                .set("y2", cy + size)
+                                .set("stroke-width", "2px")
+                                .set("stroke", color)
+                        )
+                        .add(
+                            Line::new()
+                                .set("x1", cx + size)
+                                .set("y1", cy - size)
+                                .set("x2", cx - size)
+                                .set("y2", cy + size)
+                                .set("stroke-width", "2px")
+                                .set("stroke", color)
+                        )
+                );
+            },
+            MarkerType::Custom(path) => {
+                let mut marker = Path::new()
+                    .set("transform", format!("translate({},{}) scale({})", cx, cy, size))
+                    .set("vector-effect", "non-scaling-stroke")
+                    .set("d", *path);
+                fill.assign_to(&mut marker);
+                Self::assign_stroke(&mut marker, stroke);
+                group.append(marker);
+            },
+            _ => {
+                let mut data = Data::new();
+                for (i, (x, y)) in self.polygon(size).into_iter().enumerate() {
+                    data = match i {
+                        0 => data.move_to((cx + x, cy + y)),
+                        _ => data.line_to((cx + x, cy + y)),
+                    };
+                }
+                let mut marker = Path::new()
+                    .set("d", data.close());
+                fill.assign_to(&mut marker);
+                Self::assign_stroke(&mut marker, stroke);
+                group.append(marker);
+            },
+        }
+    }
+
+    /// Return the vertices of polygonal markers, relative to the center of the marker.
+    fn polygon(&self, size: f32) -> Vec<(f32, f32)> {
+        // Return the vertices of a regular polygon (or a star, if the inner radius is set),
+        // with the first vertex pointing upwards.
+        let regular = |nr_of_vertices: usize, radius: f32, inner_radius: Option<f32>| {
+            let nr_of_points = if inner_radius.is_some() { 2 * nr_of_vertices } else { nr_of_vertices };
+            (0..nr_of_points).map(|i| {
+                let angle = 2_f32 * PI * i as f32 / nr_of_points as f32;
+                let r = match inner_radius {
+                    Some(inner) if i % 2 == 1 => inner,
+                    _ => radius,
+                };
+                (r * angle.sin(), -r * angle.cos())
+            }).collect::<Vec<(f32, f32)>>()
+        };
+
+        match self {
+            MarkerType::TriangleUp => regular(3, size, None),
+            MarkerType::TriangleDown => regular(3, size, None).into_iter().map(|(x, y)| (x, -y)).collect(),
+            MarkerType::Diamond => vec![(0_f32, -size), (size * 0.75, 0_f32), (0_f32, size), (-size * 0.75, 0_f32)],
+            MarkerType::Cross => {
+                let arm = size / 3_f32;
+                vec![
+                    (-arm, -size), (arm, -size), (arm, -arm), (size, -arm), (size, arm), (arm, arm),
+                    (arm, size), (-arm, size), (-arm, arm), (-size, arm), (-size, -arm), (-arm, -arm),
+                ]
+            },
+            // The ratio between the inner and outer radius of a regular five-pointed star.
+            MarkerType::Star => regular(5, size, Some(size * 0.382)),
+            MarkerType::Pentagon => regular(5, size, None),
+            MarkerType::Hexagon => regular(6, size, None),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Set the stroke of a marker, if there is one.
+    fn assign_stroke<T: Node>(marker: &mut T, stroke: Option<&str>) {
+        if let Some(stroke) = stroke {
+            marker.assign("stroke", stroke);
+            marker.assign("stroke-width", "2px");
+        }
+    }
 }
 
 /// Define the possible locations of a point's label.
@@ -33,12 +194,19 @@ pub struct ScatterPoint<T: Display, U: Display> {
     label_visible: bool,
     point_visible: bool,
     marker_type: MarkerType,
-    marker_size: usize,
+    marker_style: MarkerStyle,
+    marker_size: f32,
     x: f32,
     y: f32,
     x_label: T,
     y_label: U,
     color: String,
+    stroke: Option<String>,
+    opacity: Option<f32>,
+    label_color: String,
+    font_family: String,
+    font_size: f32,
+    key_style: KeyStyle,
 }
 
 impl<T: Display, U: Display> ScatterPoint<T, U> {
@@ -46,7 +214,7 @@ impl<T: Display, U: Display> ScatterPoint<T, U> {
         x: f32,
         y: f32,
         marker_type: MarkerType,
-        marker_size: usize,
+        marker_size: f32,
         x_label: T,
         y_label: U,
         label_position: PointLabelPosition,
@@ -59,13 +227,54 @@ impl<T: Display, U: Display> ScatterPoint<T, U> {
             label_visible,
             point_visible,
             marker_type,
+            marker_style: MarkerStyle::Filled,
             marker_size,
             x,
             y,
             x_label,
             y_label,
             color,
+            stroke: None,
+            opacity: None,
+            label_color: String::from("#333"),
+            font_family: String::from("sans-serif"),
+            font_size: 14_f32,
+            key_style: KeyStyle::default(),
+        }
+    }
+
+    /// Set whether the point's marker is filled or hollow.
+    pub fn set_marker_style(mut self, marker_style: MarkerStyle) -> Self {
+        self.marker_style = marker_style;
+        self
+    }
+
+    /// Set the color, the font family and the font size of the point's label.
+    pub fn set_label_style(mut self, color: String, font_family: String, font_size: f32) -> Self {
+        self.label_color = color;
+        self.font_family = font_family;
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set the style of the key of the data that the point represents, which adds
+    /// the class of the key to the classes of the point (e.g. `key-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Override the appearance of the point with the properties set in the style.
+    pub fn set_style(mut self, style: &DatumStyle) -> Self {
+        if let Some(fill) = style.get_fill() {
+            self.color = fill;
         }
+        if let Some(marker_type) = style.get_marker_type() {
+            self.marker_type = marker_type;
+        }
+        self.stroke = style.get_stroke();
+        self.opacity = style.get_opacity();
+        self
     }
 
     /// Return the x coordinate of the point.
@@ -77,6 +286,11 @@ impl<T: Display, U: Display> ScatterPoint<T, U> {
     pub fn get_y(&self) -> f32 {
         self.y
     }
+
+    /// Return the size of the point's marker.
+    pub fn get_marker_size(&self) -> f32 {
+        self.marker_size
+    }
 }
 
 impl<T: Display, U: Display> DatumRepresentation for ScatterPoint<T, U> {
@@ -84,102 +298,70 @@ impl<T: Display, U: Display> DatumRepresentation for ScatterPoint<T, U> {
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new()
             .set("transform", format!("translate({},{})", self.x, self.y))
-            .set("class", "scatter-point");
+            .set("class", self.key_style.get_key_classes("scatter-point"));
 
-        match self.marker_type {
-            MarkerType::Circle if self.point_visible => {
-                group.append(
-                    Circle::new()
-                        .set("cx", 0)
-                        .set("cy", 0)
-                        .set("r", self.marker_size)
-                        .set("fill", self.color.as_ref())
-                );
-            },
-            MarkerType::Square if self.point_visible => {
-                group.append(
-                    Rectangle::new()
-                        .set("x", -(self.marker_size as i32))
-                        .set("y", -(self.marker_size as i32))
-                        .set("width", 2 * self.marker_size)
-                        .set("height", 2 * self.marker_size)
-                        .set("fill", self.color.as_ref())
-                );
-            },
-            MarkerType::X if self.point_visible => {
-                group.append(
-                    Group::new()
-                        .add(
-                            Line::new()
-                                .set("x1", -(self.marker_size as i32))
-                                .set("y1", -(self.marker_size as i32))
-                                .set("x2", self.marker_size)
-                                .set("y2", self.marker_size)
-                                .set("stroke-width", "2px")
-                                .set("stroke", self.color.as_ref())
-                        )
-                        .add(
-                            Line::new()
-                                .set("x1", self.marker_size)
-                                .set("y1", -(self.marker_size as i32))
-                                .set("x2", -(self.marker_size as i32))
-                                .set("y2", self.marker_size)
-                                .set("stroke-width", "2px")
-                                .set("stroke", self.color.as_ref())
-                        )
-                );
-            },
-            _ => {},
-        };
+        if let Some(opacity) = self.opacity {
+            group.assign("opacity", opacity);
+        }
+
+        if self.point_visible {
+            let appearance = MarkerAppearance {
+                color: &self.color,
+                style: self.marker_style,
+                stroke: self.stroke.as_deref(),
+                styled_fill: self.key_style.get_color(),
+            };
+            self.marker_type.append_svg(&mut group, (0_f32, 0_f32), self.marker_size, &appearance);
+        }
 
         if self.label_visible {
             let mut point_label = Text::new()
                 .set("dy", ".35em")
-                .set("font-family", "sans-serif")
-                .set("fill", "#333")
-                .set("font-size", "14px")
+                .set("font-family", self.font_family.as_str())
+                .set("fill", self.label_color.as_str())
+                .set("font-size", format!("{}px", self.font_size))
                 .add(TextNode::new(format!("({}, {})", self.x_label, self.y_label)));
 
-            let label_offset = self.marker_size as isize;
+            let label_offset = self.marker_size;
             match self.label_position {
                 PointLabelPosition::N => {
                     point_label.assign("x", 0);
-                    point_label.assign("y", -label_offset - 12);
+                    point_label.assign("y", -label_offset - 12_f32);
                     point_label.assign("text-anchor", "middle");
                 },
                 PointLabelPosition::NE => {
-                    point_label.assign("x", label_offset + 4);
-                    point_label.assign("y", -label_offset - 8);
+                    point_label.assign("x", label_offset + 4_f32);
+                    point_label.assign("y", -label_offset - 8_f32);
                     point_label.assign("text-anchor", "start");
                 },
                 PointLabelPosition::E => {
-                    point_label.assign("x", label_offset + 8);
+                    point_label.assign("x", label_offset + 8_f32);
                     point_label.assign("y", 0);
                     point_label.assign("text-anchor", "start");
                 },
                 PointLabelPosition::SE => {
-                    point_label.assign("x", label_offset + 4);
-                    point_label.assign("y", label_offset + 8);
+                    point_label.assign("x", label_offset + 4_f32);
+                    point_label.assign("y", label_offset + 8_f32);
                     point_label.assign("text-anchor", "start");
                 },
                 PointLabelPosition::S => {
                     point_label.assign("x", 0);
-                    point_label.assign("y", label_offset + 12);
+                    point_label.assign("y", label_offset + 12_f32);
                     point_label.assign("text-anchor", "middle");
                 },
                 PointLabelPosition::SW => {
-                    point_label.assign("x", -label_offset - 4);
-                    point_label.assign("y", label_offset + 8);
+                    point_label.assign("x", -label_offset - 4_f32);
+                    point_label.assign("y", label_offset + 8_f32);
                     point_label.assign("text-anchor", "end");
                 },
                 PointLabelPosition::W => {
-                    point_label.assign("x", -label_offset - 8);
+                    point_label.assign("x", -label_offset - 8_f32);
                     point_label.assign("y", 0);
                     point_label.assign("text-anchor", "end");
                 },
                 PointLabelPosition::NW => {
-                    point_label.assign("x", -label_offset - 4);
-                    point_label.assign("y", -label_offset - 8);
+                    point_label.assign("x", -label_offset - 4_f32);
+                    point_label.assign("y", -label_offset - 8_f32);
                     point_label.assign("text-anchor", "end");
                 },
             }
@@ -188,4 +370,58 @@ impl<T: Display, U: Display> DatumRepresentation for ScatterPoint<T, U> {
 
         Ok(group)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    fn marker_svg(marker_type: MarkerType, marker_style: MarkerStyle) -> String {
+        ScatterPoint::new(0_f32, 0_f32, marker_type, 6_f32, 1, 2, PointLabelPosition::N, false, true, String::from("#1f77b4"))
+            .set_marker_style(marker_style)
+            .to_svg().unwrap().to_string()
+    }
+
+    #[test]
+    fn polygonal_markers_have_a_vertex_for_every_corner() {
+        let vertices = |svg: &str| svg.matches(" L").count() + 1;
+        let triangle_up = marker_svg(MarkerType::TriangleUp, MarkerStyle::Filled);
+        let triangle_down = marker_svg(MarkerType::TriangleDown, MarkerStyle::Filled);
+
+        // The first vertex of triangles points upwards or downwards from the center.
+        assert!(triangle_up.contains("d=\"M0,-6 L") && vertices(&triangle_up) == 3);
+        assert!(triangle_down.contains("d=\"M0,6 L") && vertices(&triangle_down) == 3);
+        assert_eq!(vertices(&marker_svg(MarkerType::Star, MarkerStyle::Filled)), 10);
+        assert_eq!(vertices(&marker_svg(MarkerType::Pentagon, MarkerStyle::Filled)), 5);
+        assert_eq!(vertices(&marker_svg(MarkerType::Hexagon, MarkerStyle::Filled)), 6);
+        assert!(marker_svg(MarkerType::Diamond, MarkerStyle::Filled).contains("d=\"M0,-6 L4.5,0 L0,6 L-4.5,0 z\""));
+        assert!(marker_svg(MarkerType::Cross, MarkerStyle::Filled).contains("d=\"M-2,-6 L2,-6 L2,-2 L6,-2 L6,2 L2,2 L2,6 L-2,6 L-2,2 L-6,2 L-6,-2 L-2,-2 z\""));
+        assert!(marker_svg(MarkerType::Square, MarkerStyle::Filled).contains("<rect fill=\"#1f77b4\" height=\"12\" width=\"12\" x=\"-6\" y=\"-6\"/>"));
+    }
+
+    #[test]
+    fn custom_markers_are_scaled_to_the_size_of_the_marker() {
+        let svg = marker_svg(MarkerType::Custom("M-1,-1 L1,1 L-1,1 z"), MarkerStyle::Filled);
+
+        assert!(svg.contains("<path d=\"M-1,-1 L1,1 L-1,1 z\" fill=\"#1f77b4\" transform=\"translate(0,0) scale(6)\" vector-effect=\"non-scaling-stroke\"/>"));
+    }
+
+    #[test]
+    fn hollow_markers_are_outlined_with_their_color() {
+        assert!(marker_svg(MarkerType::Circle, MarkerStyle::Hollow).contains("<circle cx=\"0\" cy=\"0\" fill=\"none\" r=\"6\" stroke=\"#1f77b4\" stroke-width=\"2px\"/>"));
+        assert!(marker_svg(MarkerType::Diamond, MarkerStyle::Hollow).contains("fill=\"none\" stroke=\"#1f77b4\" stroke-width=\"2px\""));
+        // Custom markers keep the width of their outline when they are scaled.
+        let custom = marker_svg(MarkerType::Custom("M-1,-1 L1,1 L-1,1 z"), MarkerStyle::Hollow);
+        assert!(custom.contains("fill=\"none\" stroke=\"#1f77b4\" stroke-width=\"2px\""));
+    }
+
+    #[test]
+    fn x_marker_is_drawn_with_the_stroke_of_the_style() {
+        let point = ScatterPoint::new(0_f32, 0_f32, MarkerType::X, 5_f32, 1, 2, PointLabelPosition::N, false, true, String::from("#1f77b4"))
+            .set_style(&DatumStyle::new().set_stroke(Color::from_hex("#ff0000")));
+        let svg = point.to_svg().unwrap().to_string();
+
+        assert!(svg.contains("stroke=\"#ff0000\""));
+        assert!(!svg.contains("#1f77b4"));
+    }
+}
@@ -0,0 +1,269 @@
+use svg::node::Node;
+use crate::colors::Color;
+use crate::components::scatter::MarkerType;
+use crate::views::datum::{BarDatum, PointDatum};
+
+/// A function that maps a bar datum to the style that overrides the view's styling of it.
+pub(crate) type BarStyleEncoding<'a> = Box<dyn Fn(&dyn BarDatum) -> Option<DatumStyle> + 'a>;
+
+/// A function that maps a point datum to the style that overrides the view's styling of it.
+pub(crate) type PointStyleEncoding<'a, T, U> = Box<dyn Fn(&dyn PointDatum<T, U>) -> Option<DatumStyle> + 'a>;
+
+/// Represents the visual properties of a single datum that override the ones the view would
+/// otherwise assign to it (e.g. the color of its key). Properties that are not set keep the
+/// values of the view.
+#[derive(Debug, Clone)]
+pub struct DatumStyle {
+    fill: Option<String>,
+    stroke: Option<String>,
+    opacity: Option<f32>,
+    marker_type: Option<MarkerType>,
+    legend_label: Option<String>,
+}
+
+impl DatumStyle {
+    /// Create a new style that does not override anything.
+    pub fn new() -> Self {
+        Self {
+            fill: None,
+            stroke: None,
+            opacity: None,
+            marker_type: None,
+            legend_label: None,
+        }
+    }
+
+    /// Set the fill color of the datum.
+    pub fn set_fill(mut self, color: Color) -> Self {
+        self.fill = Some(color.as_hex());
+        self
+    }
+
+    /// Set the stroke color of the datum's outline.
+    pub fn set_stroke(mut self, color: Color) -> Self {
+        self.stroke = Some(color.as_hex());
+        self
+    }
+
+    /// Set the opacity (between 0 and 1) of the datum.
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Set the marker type of the datum. This only applies to views that represent data as points.
+    pub fn set_marker_type(mut self, marker_type: MarkerType) -> Self {
+        self.marker_type = Some(marker_type);
+        self
+    }
+
+    /// Set the label under which this style is displayed in the legend. Data that share
+    /// the same label are represented by a single legend entry. If not set, the style
+    /// does not appear in the legend.
+    pub fn set_legend_label(mut self, label: String) -> Self {
+        self.legend_label = Some(label);
+        self
+    }
+
+    /// Get the fill color of the datum.
+    pub fn get_fill(&self) -> Option<String> {
+        self.fill.clone()
+    }
+
+    /// Get the stroke color of the datum.
+    pub fn get_stroke(&self) -> Option<String> {
+        self.stroke.clone()
+    }
+
+    /// Get the opacity of the datum.
+    pub fn get_opacity(&self) -> Option<f32> {
+        self.opacity
+    }
+
+    /// Get the marker type of the datum.
+    pub fn get_marker_type(&self) -> Option<MarkerType> {
+        self.marker_type
+    }
+
+    /// Get the legend label of the style.
+    pub fn get_legend_label(&self) -> Option<String> {
+        self.legend_label.clone()
+    }
+}
+
+impl Default for DatumStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Define the shape at the ends of lines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+/// Define the shape at the corners of lines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// Represents the visual properties of a series (a line or an area) that override the
+/// view's defaults. Properties that are not set keep the values of the view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesStyle {
+    stroke_width: Option<f32>,
+    dash_array: Option<String>,
+    line_cap: Option<LineCap>,
+    line_join: Option<LineJoin>,
+    fill_opacity: Option<f32>,
+    marker_visible: Option<bool>,
+    marker_size: Option<f32>,
+}
+
+impl SeriesStyle {
+    /// Create a new style that does not override anything.
+    pub fn new() -> Self {
+        Self {
+            stroke_width: None,
+            dash_array: None,
+            line_cap: None,
+            line_join: None,
+            fill_opacity: None,
+            marker_visible: None,
+            marker_size: None,
+        }
+    }
+
+    /// Set the width of the series' line.
+    pub fn set_stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    /// Set the dash pattern of the series' line, as lengths of alternating dashes and gaps
+    /// (e.g. "5,3"), the same as the SVG `stroke-dasharray` attribute.
+    pub fn set_dash_array(mut self, dash_array: &str) -> Self {
+        self.dash_array = Some(dash_array.to_string());
+        self
+    }
+
+    /// Set the shape at the ends of the series' line.
+    pub fn set_line_cap(mut self, line_cap: LineCap) -> Self {
+        self.line_cap = Some(line_cap);
+        self
+    }
+
+    /// Set the shape at the corners of the series' line.
+    pub fn set_line_join(mut self, line_join: LineJoin) -> Self {
+        self.line_join = Some(line_join);
+        self
+    }
+
+    /// Set the opacity (between 0 and 1) of the series' fill. This only applies to areas.
+    pub fn set_fill_opacity(mut self, opacity: f32) -> Self {
+        self.fill_opacity = Some(opacity);
+        self
+    }
+
+    /// Set whether the markers of the series' points are displayed.
+    pub fn set_marker_visibility(mut self, visible: bool) -> Self {
+        self.marker_visible = Some(visible);
+        self
+    }
+
+    /// Set the size of the markers of the series' points.
+    pub fn set_marker_size(mut self, size: f32) -> Self {
+        self.marker_size = Some(size);
+        self
+    }
+
+    /// Get the width of the series' line.
+    pub fn get_stroke_width(&self) -> Option<f32> {
+        self.stroke_width
+    }
+
+    /// Get the dash pattern of the series' line.
+    pub fn get_dash_array(&self) -> Option<String> {
+        self.dash_array.clone()
+    }
+
+    /// Get the fill opacity of the series.
+    pub fn get_fill_opacity(&self) -> Option<f32> {
+        self.fill_opacity
+    }
+
+    /// Get whether the markers of the series' points are displayed.
+    pub fn get_marker_visibility(&self) -> Option<bool> {
+        self.marker_visible
+    }
+
+    /// Get the size of the markers of the series' points.
+    pub fn get_marker_size(&self) -> Option<f32> {
+        self.marker_size
+    }
+
+    /// Return a style with the properties of this style, overridden by the ones set in the other style.
+    pub(crate) fn merge(&self, other: &SeriesStyle) -> SeriesStyle {
+        SeriesStyle {
+            stroke_width: other.stroke_width.or(self.stroke_width),
+            dash_array: other.dash_array.clone().or_else(|| self.dash_array.clone()),
+            line_cap: other.line_cap.or(self.line_cap),
+            line_join: other.line_join.or(self.line_join),
+            fill_opacity: other.fill_opacity.or(self.fill_opacity),
+            marker_visible: other.marker_visible.or(self.marker_visible),
+            marker_size: other.marker_size.or(self.marker_size),
+        }
+    }
+
+    /// Assign the stroke properties that are set in the style to an SVG element.
+    pub(crate) fn assign_stroke<T: Node>(&self, node: &mut T) {
+        if let Some(width) = self.stroke_width {
+            node.assign("stroke-width", width);
+        }
+        if let Some(dash_array) = &self.dash_array {
+            node.assign("stroke-dasharray", dash_array.as_str());
+        }
+        if let Some(line_cap) = self.line_cap {
+            node.assign("stroke-linecap", match line_cap {
+                LineCap::Butt => "butt",
+                LineCap::Round => "round",
+                LineCap::Square => "square",
+            });
+        }
+        if let Some(line_join) = self.line_join {
+            node.assign("stroke-linejoin", match line_join {
+                LineJoin::Miter => "miter",
+                LineJoin::Round => "round",
+                LineJoin::Bevel => "bevel",
+            });
+        }
+    }
+}
+
+impl Default for SeriesStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merged_styles_keep_the_properties_that_are_not_overridden() {
+        let base = SeriesStyle::new().set_stroke_width(2_f32).set_dash_array("4,2");
+        let merged = base.merge(&SeriesStyle::default().set_stroke_width(3_f32).set_marker_visibility(false));
+
+        assert_eq!(merged.get_stroke_width(), Some(3_f32));
+        assert_eq!(merged.get_dash_array(), Some(String::from("4,2")));
+        assert_eq!(merged.get_marker_visibility(), Some(false));
+        assert_eq!(merged.get_fill_opacity(), None);
+    }
+}
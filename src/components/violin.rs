@@ -0,0 +1,117 @@
+use svg::node::Node;
+use svg::node::element::{Group, Line, Path};
+use svg::node::element::path::Data;
+use crate::components::DatumRepresentation;
+use crate::chart::Orientation;
+use crate::theme::{KeyStyle, Presentation};
+
+/// Represents a violin shape that mirrors the estimated density of a distribution
+/// on both sides of its center line. All the values are expressed in already scaled
+/// (pixel) coordinates.
+#[derive(Debug)]
+pub struct Violin {
+    orientation: Orientation,
+    center: f32,
+    /// Pairs of (value position, half width of the violin at that position).
+    contour: Vec<(f32, f32)>,
+    median: Option<(f32, f32)>,
+    color: String,
+    outline_color: String,
+    key_style: KeyStyle,
+}
+
+impl Violin {
+    pub fn new(
+        orientation: Orientation,
+        center: f32,
+        contour: Vec<(f32, f32)>,
+        median: Option<(f32, f32)>,
+        color: String,
+    ) -> Self {
+        Self {
+            orientation,
+            center,
+            contour,
+            median,
+            color,
+            outline_color: String::from("#333"),
+            key_style: KeyStyle::default(),
+        }
+    }
+
+    /// Set the color of the outlines of the violin and its median.
+    pub fn set_outline_color(mut self, color: String) -> Self {
+        self.outline_color = color;
+        self
+    }
+
+    /// Set the style of the key of the data that the violin represents, which adds
+    /// the class of the key to the classes of the violin (e.g. `key-revenue`).
+    pub(crate) fn set_key_style(mut self, key_style: KeyStyle) -> Self {
+        self.key_style = key_style;
+        self
+    }
+
+    /// Translate a (category, value) position to a (x, y) position based on orientation.
+    fn to_xy(&self, category_position: f32, value_position: f32) -> (f32, f32) {
+        match self.orientation {
+            Orientation::Vertical => (category_position, value_position),
+            Orientation::Horizontal => (value_position, category_position),
+        }
+    }
+}
+
+impl DatumRepresentation for Violin {
+
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new()
+            .set("class", self.key_style.get_key_classes("violin"));
+
+        if self.contour.is_empty() {
+            return Ok(group);
+        }
+
+        let mut data = Data::new();
+
+        for (i, (value_position, half_width)) in self.contour.iter().enumerate() {
+            let point = self.to_xy(self.center - half_width, *value_position);
+            if i == 0 {
+                data = data.move_to(point);
+            } else {
+                data = data.line_to(point);
+            }
+        }
+
+        for (value_position, half_width) in self.contour.iter().rev() {
+            data = data.line_to(self.to_xy(self.center + half_width, *value_position));
+        }
+
+        data = data.close();
+
+        let mut contour = Path::new()
+            .set("stroke", self.outline_color.as_str())
+            .set("stroke-width", 1)
+            .set("d", data);
+        Presentation::new()
+            .set("fill", &self.color, self.key_style.get_color())
+            .assign_to(&mut contour);
+        group.append(contour);
+
+        // Mark the median with a line spanning the violin's width at that position.
+        if let Some((median, half_width)) = self.median {
+            let (x1, y1) = self.to_xy(self.center - half_width, median);
+            let (x2, y2) = self.to_xy(self.center + half_width, median);
+            group.append(
+                Line::new()
+                    .set("x1", x1)
+                    .set("y1", y1)
+                    .set("x2", x2)
+                    .set("y2", y2)
+                    .set("stroke", self.outline_color.as_str())
+                    .set("stroke-width", 2)
+            );
+        }
+
+        Ok(group)
+    }
+}
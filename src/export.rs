@@ -0,0 +1,78 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use svg::Document;
+#[cfg(feature = "png")]
+use resvg::usvg;
+#[cfg(all(feature = "pdf", not(feature = "png")))]
+use svg2pdf::usvg;
+
+/// Save a document to a file whose format is given by the extension of the path.
+/// Documents can always be saved as SVG, while saving them as PNG or PDF requires
+/// the `png` or `pdf` feature respectively.
+pub(crate) fn save_document<P: AsRef<Path>>(document: &Document, path: P) -> Result<(), String> {
+    match path.as_ref().extension().and_then(OsStr::to_str) {
+        Some("svg") => svg::save(path, document).map_err(|e| format!("Encountered an error while saving the document: {:?}", e)),
+        Some("png") => save_png(document, path.as_ref()),
+        Some("pdf") => save_pdf(document, path.as_ref()),
+        Some(extension) => Err(format!("Saving documents as .{} files is not supported", extension)),
+        None => Err(String::from("Please provide a file extension (.svg, .png or .pdf) to save the document as")),
+    }
+}
+
+/// Parse the document into a tree that can be rendered, with the text converted to paths
+/// using the fonts installed on the system.
+#[cfg(any(feature = "png", feature = "pdf"))]
+fn to_render_tree(document: &Document) -> Result<usvg::Tree, String> {
+    use usvg::{TreeParsing, TreePostProc};
+
+    let mut tree = usvg::Tree::from_str(&document.to_string(), &usvg::Options::default())
+        .map_err(|e| format!("Encountered an error while rendering the document: {}", e))?;
+
+    let mut font_database = usvg::fontdb::Database::new();
+    font_database.load_system_fonts();
+    // The sans-serif family defaults to Arial, so fall back to another installed
+    // sans-serif font (or to any installed font) if Arial is not installed.
+    let sans_serif = usvg::fontdb::Query { families: &[usvg::fontdb::Family::SansSerif], ..Default::default() };
+    if font_database.query(&sans_serif).is_none() {
+        let families = font_database.faces()
+            .flat_map(|face| face.families.iter().map(|(family, _)| family.clone()))
+            .collect::<Vec<String>>();
+        if let Some(family) = families.iter().find(|family| family.contains("Sans")).or_else(|| families.first()) {
+            font_database.set_sans_serif_family(family.clone());
+        }
+    }
+    tree.postprocess(usvg::PostProcessingSteps::default(), &font_database);
+
+    Ok(tree)
+}
+
+/// Render the document to a PNG image of the size of the document.
+#[cfg(feature = "png")]
+fn save_png(document: &Document, path: &Path) -> Result<(), String> {
+    let tree = to_render_tree(document)?;
+    let size = tree.size.to_int_size();
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| String::from("Cannot render a document without a width or height"))?;
+    resvg::render(&tree, resvg::tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+    pixmap.save_png(path).map_err(|e| format!("Encountered an error while saving the document: {}", e))
+}
+
+#[cfg(not(feature = "png"))]
+fn save_png(_document: &Document, _path: &Path) -> Result<(), String> {
+    Err(String::from("Saving documents as PNG images requires the `png` feature"))
+}
+
+/// Convert the document to a single page PDF document.
+#[cfg(feature = "pdf")]
+fn save_pdf(document: &Document, path: &Path) -> Result<(), String> {
+    let tree = to_render_tree(document)?;
+    let pdf = svg2pdf::convert_tree(&tree, svg2pdf::Options::default());
+
+    std::fs::write(path, pdf).map_err(|e| format!("Encountered an error while saving the document: {}", e))
+}
+
+#[cfg(not(feature = "pdf"))]
+fn save_pdf(_document: &Document, _path: &Path) -> Result<(), String> {
+    Err(String::from("Saving documents as PDF documents requires the `pdf` feature"))
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use crate::{Chart, Grid};
+use crate::axis::AxisPosition;
+
+/// Define whether the panels of a facet share a scale along a dimension.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScaleSharing {
+    /// All panels use the same scale, built from the whole dataset.
+    Shared,
+    /// Every panel uses its own scale, built from the data of the panel.
+    Independent,
+}
+
+/// The scales of a facet along one dimension.
+enum FacetScales<S> {
+    Missing,
+    Shared(S),
+    Independent(Vec<S>),
+}
+
+impl<S> FacetScales<S> {
+    /// Get the scale of the panel at the given index.
+    fn get(&self, index: usize) -> Option<&S> {
+        match self {
+            FacetScales::Missing => None,
+            FacetScales::Shared(scale) => Some(scale),
+            FacetScales::Independent(scales) => scales.get(index),
+        }
+    }
+}
+
+/// A panel of a facet, which displays the data of a single facet value.
+pub struct FacetPanel<'f, D> {
+    value: &'f str,
+    data: &'f Vec<D>,
+    index: usize,
+}
+
+impl<'f, D> FacetPanel<'f, D> {
+    /// Get the facet value that the panel displays.
+    pub fn get_value(&self) -> &str {
+        self.value
+    }
+
+    /// Get the data of the panel, which are the data that have the panel's facet value.
+    pub fn get_data(&self) -> &Vec<D> {
+        self.data
+    }
+
+    /// Get the index of the panel, in the order in which the facet values first appear in the data.
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+}
+
+/// A Facet (also known as small multiples) splits a dataset by the value of a facet key
+/// and displays a chart of the same kind for every facet value, laid out in a grid.
+/// The X and Y scales of the panels are built by the facet, either once for the whole
+/// dataset (shared) or once per panel (independent), and every chart is then built
+/// from a template that receives the panel and its scales.
+pub struct Facet<D, X, Y> {
+    facets: Vec<(String, Vec<D>)>,
+    x_scales: FacetScales<X>,
+    y_scales: FacetScales<Y>,
+    columns: Option<usize>,
+    facet_titles: bool,
+    row_gutter: isize,
+    column_gutter: isize,
+    title: String,
+    legend_position: Option<AxisPosition>,
+}
+
+impl<D: Clone, X, Y> Facet<D, X, Y> {
+    /// Create a new facet that splits the data by the value that the key function returns for
+    /// every datum. The panels are ordered by the first appearance of their value in the data.
+    pub fn new(data: &[D], key: impl Fn(&D) -> String) -> Self {
+        let mut facets: Vec<(String, Vec<D>)> = Vec::new();
+        let mut indices: HashMap<String, usize> = HashMap::new();
+
+        for datum in data.iter() {
+            let value = key(datum);
+            match indices.get(&value) {
+                Some(index) => facets[*index].1.push(datum.clone()),
+                None => {
+                    indices.insert(value.clone(), facets.len());
+                    facets.push((value, vec![datum.clone()]));
+                },
+            }
+        }
+
+        Self {
+            facets,
+            x_scales: FacetScales::Missing,
+            y_scales: FacetScales::Missing,
+            columns: None,
+            facet_titles: true,
+            row_gutter: 20,
+            column_gutter: 20,
+            title: String::new(),
+            legend_position: None,
+        }
+    }
+
+    /// Set the function that builds the scale for the X dimension from the data that the scale
+    /// displays (the whole dataset for shared scales, or the data of a panel otherwise).
+    pub fn set_x_scale(mut self, sharing: ScaleSharing, build: impl Fn(&Vec<D>) -> X) -> Self {
+        self.x_scales = self.build_scales(sharing, build);
+        self
+    }
+
+    /// Set the function that builds the scale for the Y dimension from the data that the scale
+    /// displays (the whole dataset for shared scales, or the data of a panel otherwise).
+    pub fn set_y_scale(mut self, sharing: ScaleSharing, build: impl Fn(&Vec<D>) -> Y) -> Self {
+        self.y_scales = self.build_scales(sharing, build);
+        self
+    }
+
+    /// Set the amount of columns of the grid. By default, the panels are laid out
+    /// in a grid that is about as wide as it is tall.
+    pub fn set_columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Set whether every panel is titled with its facet value.
+    pub fn set_facet_titles(mut self, visible: bool) -> Self {
+        self.facet_titles = visible;
+        self
+    }
+
+    /// Set the distance between two rows and between two columns of panels.
+    pub fn set_gutters(mut self, row_gutter: isize, column_gutter: isize) -> Self {
+        self.row_gutter = row_gutter;
+        self.column_gutter = column_gutter;
+        self
+    }
+
+    /// Add a title above the panels.
+    pub fn add_title(mut self, title: String) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Add a legend at the specified side of the panels, shared by all of them.
+    /// To have the same series in the same color in every panel, set the same keys
+    /// on the views of all panels (e.g. with `set_keys`).
+    pub fn add_legend_at(mut self, position: AxisPosition) -> Self {
+        self.legend_position = Some(position);
+        self
+    }
+
+    /// Get the facet values, in the order of the panels.
+    pub fn get_facet_values(&self) -> Vec<String> {
+        self.facets.iter().map(|(value, _)| value.clone()).collect()
+    }
+
+    /// Build the scales of the panels along a dimension.
+    fn build_scales<S>(&self, sharing: ScaleSharing, build: impl Fn(&Vec<D>) -> S) -> FacetScales<S> {
+        match sharing {
+            ScaleSharing::Shared => {
+                let data = self.facets.iter().flat_map(|(_, data)| data.iter().cloned()).collect::<Vec<D>>();
+                FacetScales::Shared(build(&data))
+            },
+            ScaleSharing::Independent => FacetScales::Independent(self.facets.iter().map(|(_, data)| build(data)).collect()),
+        }
+    }
+
+    /// Build a chart for every panel from the template and lay the charts out in a grid.
+    /// The template receives the panel and its X and Y scales, and should not add a legend
+    /// to the chart, since the grid displays a legend that is shared by all panels.
+    pub fn build_grid<'s>(&'s self, template: impl Fn(&FacetPanel<D>, &'s X, &'s Y) -> Result<Chart<'s>, String>) -> Result<Grid<'s>, String> {
+        let columns = match self.columns {
+            Some(columns) => columns.max(1),
+            None => (self.facets.len() as f32).sqrt().ceil().max(1_f32) as usize,
+        };
+
+        let mut grid = Grid::new()
+            .set_gutters(self.row_gutter, self.column_gutter)
+            .add_title(self.title.clone());
+        if let Some(position) = self.legend_position {
+            grid = grid.add_legend_at(position);
+        }
+
+        for (index, (value, data)) in self.facets.iter().enumerate() {
+            let x_scale = match self.x_scales.get(index) {
+                Some(scale) => scale,
+                None => return Err("Please provide a scale for the X dimension before building the facet".to_string()),
+            };
+            let y_scale = match self.y_scales.get(index) {
+                Some(scale) => scale,
+                None => return Err("Please provide a scale for the Y dimension before building the facet".to_string()),
+            };
+
+            let panel = FacetPanel { value, data, index };
+            let mut chart = template(&panel, x_scale, y_scale)?;
+            if self.facet_titles {
+                chart = chart.add_title(value.clone());
+            }
+            grid = grid.add_chart(chart, index / columns, index % columns);
+        }
+
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn max_of(data: &Vec<(f32, &str)>) -> f32 {
+        data.iter().fold(f32::NEG_INFINITY, |acc, datum| acc.max(datum.0))
+    }
+
+    #[test]
+    fn panels_are_split_by_facet_value_in_order_of_appearance() {
+        let data = vec![(1_f32, "B"), (2_f32, "A"), (3_f32, "B")];
+        let facet = Facet::new(&data, |datum| datum.1.to_string())
+            .set_x_scale(ScaleSharing::Shared, max_of)
+            .set_y_scale(ScaleSharing::Shared, max_of);
+        let panels = RefCell::new(Vec::new());
+        facet.build_grid(|panel, _, _| {
+            panels.borrow_mut().push((panel.get_index(), panel.get_value().to_string(), panel.get_data().clone()));
+            Ok(Chart::new())
+        }).unwrap();
+
+        assert_eq!(facet.get_facet_values(), vec![String::from("B"), String::from("A")]);
+        assert_eq!(panels.into_inner(), vec![
+            (0, String::from("B"), vec![(1_f32, "B"), (3_f32, "B")]),
+            (1, String::from("A"), vec![(2_f32, "A")]),
+        ]);
+    }
+
+    #[test]
+    fn shared_scales_are_built_from_all_data_and_independent_ones_per_panel() {
+        let data = vec![(1_f32, "B"), (2_f32, "A"), (3_f32, "B")];
+        let facet = Facet::new(&data, |datum| datum.1.to_string())
+            .set_x_scale(ScaleSharing::Shared, max_of)
+            .set_y_scale(ScaleSharing::Independent, max_of);
+        let scales = RefCell::new(Vec::new());
+        facet.build_grid(|_, x, y| {
+            scales.borrow_mut().push((x as *const f32, *x, *y));
+            Ok(Chart::new())
+        }).unwrap();
+        let scales = scales.into_inner();
+
+        // Shared scales are the same instance in every panel.
+        assert_eq!(scales[0].0, scales[1].0);
+        assert_eq!((scales[0].1, scales[1].1), (3_f32, 3_f32));
+        assert_eq!((scales[0].2, scales[1].2), (3_f32, 2_f32));
+    }
+
+    #[test]
+    fn building_without_scales_is_rejected() {
+        let data = vec![(1_f32, "A")];
+        let facet = Facet::<_, f32, f32>::new(&data, |datum| datum.1.to_string())
+            .set_x_scale(ScaleSharing::Shared, max_of);
+
+        assert!(facet.build_grid(|_, _, _| Ok(Chart::new())).is_err());
+    }
+}
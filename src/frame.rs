@@ -0,0 +1,120 @@
+use svg::node::element::{Group, Text};
+use svg::node::Text as TextNode;
+use svg::Node;
+use crate::axis::AxisPosition;
+use crate::components::legend::LegendEntry;
+use crate::legend::Legend;
+use crate::text::FontMetrics;
+use crate::theme::Theme;
+
+/// The distance between the title, the legend and the content of a frame.
+const FRAME_GAP: isize = 10;
+
+/// A frame places a title above some content (a grid of charts, or a page of grids)
+/// and a legend at one of its sides, and sizes itself to fit all of them.
+pub(crate) struct Frame<'a> {
+    pub title: &'a str,
+    pub title_font_size: f32,
+    pub legend_position: Option<AxisPosition>,
+    pub legend_entries: Vec<LegendEntry>,
+    /// The top, right, bottom and left margins around the frame.
+    pub margins: (isize, isize, isize, isize),
+    pub font_metrics: &'a FontMetrics,
+    /// The theme that the title and the legend are styled with.
+    pub theme: Theme,
+}
+
+impl<'a> Frame<'a> {
+    /// Frame the content of the given size and return the SVG group of the frame,
+    /// along with its width and height.
+    pub fn render(self, content: Group, content_width: isize, content_height: isize) -> Result<(Group, isize, isize), String> {
+        let (margin_top, margin_right, margin_bottom, margin_left) = self.margins;
+        let legend_position = self.legend_position;
+
+        // Legends above or below the content are as wide as the content, while those
+        // beside it have a single column.
+        let available_legend_width = match legend_position {
+            Some(AxisPosition::Top) | Some(AxisPosition::Bottom) => content_width.max(0) as usize,
+            _ => 0,
+        };
+        let legend = Legend::new(unique_legend_entries(self.legend_entries), available_legend_width, self.font_metrics.clone())
+            .set_theme(self.theme.clone());
+        let (legend_width, legend_height) = legend.get_size();
+        let legend_space = |position: AxisPosition| -> isize {
+            match legend_position {
+                Some(legend_position) if legend_position == position => match position {
+                    AxisPosition::Top | AxisPosition::Bottom if legend_height > 0 => legend_height as isize + FRAME_GAP,
+                    AxisPosition::Left | AxisPosition::Right if legend_width > 0 => legend_width as isize + FRAME_GAP,
+                    _ => 0,
+                },
+                _ => 0,
+            }
+        };
+
+        let title_height = self.font_metrics.text_height(self.title_font_size);
+        let title_space = match self.title.is_empty() {
+            true => 0,
+            false => title_height.ceil() as isize + FRAME_GAP,
+        };
+
+        let mut content_left = margin_left + legend_space(AxisPosition::Left);
+        let content_top = margin_top + title_space + legend_space(AxisPosition::Top);
+        let mut width = content_left + content_width + legend_space(AxisPosition::Right) + margin_right;
+        let height = content_top + content_height + legend_space(AxisPosition::Bottom) + margin_bottom;
+
+        // Widen the frame evenly on both sides if the title does not fit.
+        let title_width = self.font_metrics.text_width(self.title, self.title_font_size).ceil() as isize + margin_left + margin_right;
+        if title_width > width {
+            content_left += (title_width - width) / 2;
+            width = title_width;
+        }
+
+        let mut group = Group::new();
+
+        if !self.title.is_empty() {
+            group.append(Group::new()
+                .set("class", "g-title")
+                .set("transform", format!("translate({},{})", width / 2, margin_top + (title_height / 2_f32).round() as isize))
+                .add(Text::new()
+                    .set("x", 0)
+                    .set("y", 0)
+                    .set("dy", ".35em")
+                    .set("fill", self.theme.get_title_color())
+                    .set("text-anchor", "middle")
+                    .set("font-size", format!("{}px", self.title_font_size))
+                    .set("font-family", self.theme.get_font_family())
+                    .add(TextNode::new(self.title))
+                ));
+        }
+
+        let mut content = content;
+        content.assign("transform", format!("translate({},{})", content_left, content_top));
+        group.append(content);
+
+        if let Some(position) = legend_position {
+            let (x_offset, y_offset) = match position {
+                AxisPosition::Top => (content_left, margin_top + title_space),
+                AxisPosition::Bottom => (content_left, content_top + content_height + FRAME_GAP),
+                AxisPosition::Left => (margin_left, content_top),
+                AxisPosition::Right => (content_left + content_width + FRAME_GAP, content_top),
+            };
+            let mut legend_group = legend.to_svg()?;
+            legend_group.assign("transform", format!("translate({},{})", x_offset, y_offset));
+            group.append(legend_group);
+        }
+
+        Ok((group, width, height))
+    }
+}
+
+/// Remove the entries that duplicate a previous entry, so that a series that is displayed
+/// in multiple charts appears only once in a shared legend.
+fn unique_legend_entries(entries: Vec<LegendEntry>) -> Vec<LegendEntry> {
+    let mut unique_entries: Vec<LegendEntry> = Vec::new();
+    for entry in entries.into_iter() {
+        if !unique_entries.iter().any(|existing| existing.is_duplicate_of(&entry)) {
+            unique_entries.push(entry);
+        }
+    }
+    unique_entries
+}
@@ -0,0 +1,237 @@
+use svg::node::element::Group;
+use svg::Node;
+use crate::Chart;
+use crate::axis::AxisPosition;
+use crate::components::legend::LegendEntry;
+use crate::frame::Frame;
+use crate::text::FontMetrics;
+use crate::theme::Theme;
+
+/// The font size of the grid title.
+const GRID_TITLE_FONT_SIZE: f32 = 18_f32;
+
+/// A chart placed in a grid, along with the cells that it spans.
+#[derive(Clone)]
+struct GridCell<'a> {
+    chart: Chart<'a>,
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+/// A Grid arranges multiple charts in rows and columns. Every column is as wide as the widest
+/// chart in it and every row is as tall as the tallest chart in it, and the cells are separated
+/// by gutters. A chart can span multiple rows and columns. The title and the legend of the
+/// grid are styled with the theme of its first chart.
+#[derive(Clone)]
+pub struct Grid<'a> {
+    cells: Vec<GridCell<'a>>,
+    row_gutter: isize,
+    column_gutter: isize,
+    title: String,
+    legend_position: Option<AxisPosition>,
+    font_metrics: FontMetrics,
+}
+
+impl<'a> Grid<'a> {
+    /// Create a new empty grid.
+    pub fn new() -> Self {
+        Self {
+            cells: Vec::new(),
+            row_gutter: 20,
+            column_gutter: 20,
+            title: String::new(),
+            legend_position: None,
+            font_metrics: FontMetrics::new(),
+        }
+    }
+
+    /// Add a chart to the cell at the given row and column (starting from 0).
+    pub fn add_chart(self, chart: Chart<'a>, row: usize, column: usize) -> Self {
+        self.add_chart_spanning(chart, row, column, 1, 1)
+    }
+
+    /// Add a chart that spans the given amount of rows and columns, starting from the cell
+    /// at the given row and column.
+    pub fn add_chart_spanning(mut self, chart: Chart<'a>, row: usize, column: usize, row_span: usize, column_span: usize) -> Self {
+        self.cells.push(GridCell { chart, row, column, row_span, column_span });
+        self
+    }
+
+    /// Set the distance between two rows and between two columns.
+    pub fn set_gutters(mut self, row_gutter: isize, column_gutter: isize) -> Self {
+        self.row_gutter = row_gutter;
+        self.column_gutter = column_gutter;
+        self
+    }
+
+    /// Add a title above the charts of the grid.
+    pub fn add_title(mut self, title: String) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Add a legend at the specified side of the grid, shared by all of its charts.
+    /// Series that appear in multiple charts are listed only once.
+    pub fn add_legend_at(mut self, position: AxisPosition) -> Self {
+        self.legend_position = Some(position);
+        self
+    }
+
+    /// Set the metrics of the font that the title and the legend of the grid are measured with.
+    pub fn set_font_metrics(mut self, font_metrics: FontMetrics) -> Self {
+        self.font_metrics = font_metrics;
+        self
+    }
+
+    /// Return the theme that the title and the legend of the grid are styled with,
+    /// which is the theme of its first chart.
+    pub(crate) fn get_theme(&self) -> Theme {
+        self.cells.first().map(|cell| cell.chart.get_theme().clone()).unwrap_or_else(Theme::light)
+    }
+
+    /// Return the legend entries of all charts in the grid.
+    pub(crate) fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        self.cells.iter().flat_map(|cell| cell.chart.get_legend_entries()).collect()
+    }
+
+    /// Compute the sizes of the rows or columns from the sizes of the charts that span them.
+    /// Charts that span multiple rows or columns enlarge the last of them if needed.
+    fn get_track_sizes(spans: &[(usize, usize, isize)], gutter: isize) -> Vec<isize> {
+        let count = spans.iter().map(|(start, span, _)| start + span).max().unwrap_or(0);
+        let mut sizes = vec![0; count];
+
+        let mut spans = spans.to_vec();
+        spans.sort_by_key(|(_, span, _)| *span);
+        for (start, span, size) in spans.into_iter() {
+            let available = sizes[start..start + span].iter().sum::<isize>() + gutter * (span as isize - 1);
+            if size > available {
+                sizes[start + span - 1] += size - available;
+            }
+        }
+
+        sizes
+    }
+
+    /// Generate the SVG for the grid and return it along with the width and height of the grid.
+    pub(crate) fn to_svg(&self) -> Result<(Group, isize, isize), String> {
+        for (i, cell) in self.cells.iter().enumerate() {
+            if cell.row_span == 0 || cell.column_span == 0 {
+                return Err(format!("The chart at row {}, column {} of the grid must span at least one row and column", cell.row, cell.column));
+            }
+            let overlaps = self.cells[..i].iter().any(|other| {
+                cell.row < other.row + other.row_span && other.row < cell.row + cell.row_span
+                    && cell.column < other.column + other.column_span && other.column < cell.column + cell.column_span
+            });
+            if overlaps {
+                return Err(format!("The chart at row {}, column {} of the grid overlaps another chart", cell.row, cell.column));
+            }
+        }
+
+        let row_heights = Self::get_track_sizes(
+            &self.cells.iter().map(|cell| (cell.row, cell.row_span, cell.chart.get_chart_height())).collect::<Vec<_>>(),
+            self.row_gutter,
+        );
+        let column_widths = Self::get_track_sizes(
+            &self.cells.iter().map(|cell| (cell.column, cell.column_span, cell.chart.get_chart_width())).collect::<Vec<_>>(),
+            self.column_gutter,
+        );
+        let offset = |sizes: &[isize], index: usize, gutter: isize| sizes[..index].iter().sum::<isize>() + gutter * index as isize;
+
+        let mut content = Group::new()
+            .set("class", "g-cells");
+
+        // Each chart is resolved right before it is rendered, since charts that share a scale
+        // set its default range to the extent of their own view.
+        for cell in self.cells.iter() {
+            let mut chart_group = cell.chart.get_resolved().to_svg()?;
+            chart_group.assign("transform", format!(
+                "translate({},{})",
+                offset(&column_widths, cell.column, self.column_gutter),
+                offset(&row_heights, cell.row, self.row_gutter),
+            ));
+            content.append(chart_group);
+        }
+
+        let total = |sizes: &[isize], gutter: isize| sizes.iter().sum::<isize>() + gutter * (sizes.len() as isize - 1).max(0);
+        let content_width = total(&column_widths, self.column_gutter);
+        let content_height = total(&row_heights, self.row_gutter);
+
+        let frame = Frame {
+            title: &self.title,
+            title_font_size: GRID_TITLE_FONT_SIZE,
+            legend_position: self.legend_position,
+            legend_entries: match self.legend_position {
+                Some(_) => self.get_legend_entries(),
+                None => Vec::new(),
+            },
+            margins: (0, 0, 0, 0),
+            font_metrics: &self.font_metrics,
+            theme: self.get_theme(),
+        };
+        let (mut group, width, height) = frame.render(content, content_width, content_height)?;
+        group.assign("class", "g-grid");
+
+        Ok((group, width, height))
+    }
+}
+
+impl<'a> Default for Grid<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleLinear, ScatterView, Theme};
+
+    #[test]
+    fn track_sizes() {
+        // A chart that spans two columns enlarges the second one, if the columns are too narrow.
+        assert_eq!(Grid::get_track_sizes(&[(0, 1, 100), (1, 1, 50), (0, 2, 200)], 10), vec![100, 90]);
+        assert_eq!(Grid::get_track_sizes(&[(0, 1, 100), (1, 1, 50), (0, 2, 120)], 10), vec![100, 50]);
+        // Empty tracks have no size.
+        assert_eq!(Grid::get_track_sizes(&[(1, 1, 100)], 10), vec![0, 100]);
+    }
+
+    #[test]
+    fn charts_that_share_a_scale_are_drawn_with_their_own_range() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 100_f32]);
+        let data = vec![(100_f32, 100_f32)];
+        let view = ScatterView::new().set_x_scale(&x).set_y_scale(&y).load_data(&data).unwrap();
+        let chart = |width: isize| Chart::new()
+            .set_width(width)
+            .set_height(200)
+            .set_margins(0, 0, 0, 0)
+            .add_axis_bottom(&x)
+            .add_axis_left(&y)
+            .add_view(&view);
+        let svg = Grid::new()
+            .add_chart(chart(200), 0, 0)
+            .add_chart(chart(400), 0, 1)
+            .to_svg()
+            .unwrap().0
+            .to_string();
+
+        // Without resolving each chart in turn, both charts would be drawn with the range of the last one.
+        assert!(svg.contains("<g class=\"scatter-point\" transform=\"translate(200,0)\">"));
+        assert!(svg.contains("<g class=\"scatter-point\" transform=\"translate(400,0)\">"));
+    }
+
+    #[test]
+    fn title_and_legend_take_the_theme_of_the_charts() {
+        let svg = Grid::new()
+            .add_chart(Chart::new().set_theme(Theme::dark()), 0, 0)
+            .add_title(String::from("Grid"))
+            .to_svg()
+            .unwrap().0
+            .to_string();
+
+        assert!(svg.contains(&format!("fill=\"{}\"", Theme::dark().get_title_color())));
+        assert!(!svg.contains("#777"));
+    }
+}
@@ -1,29 +1,67 @@
 use svg::node::element::Group;
 use svg::Node;
 use crate::components::legend::LegendEntry;
+use crate::text::FontMetrics;
+use crate::theme::Theme;
+
+/// The horizontal distance between two legend entries.
+const GAP_BETWEEN_LEGEND_ENTRIES: usize = 10;
 
 pub(crate) struct Legend {
     width: usize,
     entries: Vec<LegendEntry>,
+    font_metrics: FontMetrics,
+    theme: Theme,
 }
 
 impl Legend {
     /// Create a new legend instance.
-    pub fn new(entries: Vec<LegendEntry>, width: usize) -> Self {
+    pub fn new(entries: Vec<LegendEntry>, width: usize, font_metrics: FontMetrics) -> Self {
         Self {
             entries,
             width,
+            font_metrics,
+            theme: Theme::light(),
         }
     }
 
+    /// Set the theme that the entries are styled with.
+    pub fn set_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Compute the width and height of the legend, with the entries placed in as few rows as fit its width.
+    pub fn get_size(&self) -> (usize, usize) {
+        let max_entry_length = match self.entries.iter().map(|entry| entry.get_width(&self.font_metrics, &self.theme)).max() {
+            None => return (0, 0),
+            Some(len) => len,
+        };
+        let entries_per_row = match self.width >= max_entry_length {
+            true => ((self.width - max_entry_length) / (max_entry_length + GAP_BETWEEN_LEGEND_ENTRIES) + 1).min(self.entries.len()),
+            false => 1,
+        };
+        let nr_of_rows = self.entries.chunks(entries_per_row).count();
+
+        (
+            entries_per_row * (max_entry_length + GAP_BETWEEN_LEGEND_ENTRIES) - GAP_BETWEEN_LEGEND_ENTRIES,
+            nr_of_rows * self.get_row_height(),
+        )
+    }
+
+    /// Get the height of a row of entries. Rows are at least 20px high,
+    /// but grow to fit entries with bigger markers.
+    fn get_row_height(&self) -> usize {
+        self.entries.iter().map(|entry| entry.get_height() + 6).max().unwrap_or(0).max(20)
+    }
+
     pub fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new().set("class", "g-legend");
-        let max_entry_length = match self.entries.iter().map(|entry| entry.get_width()).max() {
+        let max_entry_length = match self.entries.iter().map(|entry| entry.get_width(&self.font_metrics, &self.theme)).max() {
             None => return Ok(group),
             Some(len) => len,
         };
-        let gap_between_legend_entries = 10;
-        let legend_row_height = 20;
+        let legend_row_height = self.get_row_height();
         let mut current_row_offset = 0;
         let mut acc_row_width = 0;
 
@@ -33,11 +71,11 @@ impl Legend {
                 current_row_offset += 1;
             }
 
-            let mut entry_group = entry.to_svg()?;
+            let mut entry_group = entry.to_svg(&self.font_metrics, &self.theme)?;
             entry_group.assign("transform", format!("translate({},{})", acc_row_width, current_row_offset * legend_row_height));
             group.append(entry_group);
 
-            acc_row_width += max_entry_length + gap_between_legend_entries;
+            acc_row_width += max_entry_length + GAP_BETWEEN_LEGEND_ENTRIES;
         }
 
         Ok(group)
@@ -23,6 +23,11 @@
 //! TODO represent the structure visually
 
 mod chart;
+mod grid;
+mod page;
+mod facet;
+mod frame;
+mod export;
 // mod view;
 mod scales;
 mod views;
@@ -30,22 +35,44 @@ mod components;
 mod colors;
 mod axis;
 mod legend;
+mod text;
+mod stats;
+mod theme;
 
-pub use crate::chart::Chart;
+pub use crate::chart::{Chart, Orientation, StylingMode};
+pub use crate::grid::Grid;
+pub use crate::page::Page;
+pub use crate::facet::{Facet, FacetPanel, ScaleSharing};
 pub use crate::scales::band::ScaleBand;
 pub use crate::scales::linear::ScaleLinear;
+pub use crate::scales::sqrt::ScaleSqrt;
 pub use crate::scales::Scale;
 pub use crate::views::vertical_bar::VerticalBarView;
 pub use crate::views::horizontal_bar::HorizontalBarView;
 pub use crate::views::scatter::ScatterView;
 pub use crate::views::line::LineSeriesView;
 pub use crate::views::area::AreaSeriesView;
-pub use crate::views::datum::{BarDatum, PointDatum};
-pub use crate::axis::{Axis, AxisPosition};
+pub use crate::views::box_plot::BoxPlotView;
+pub use crate::views::violin::ViolinView;
+pub use crate::views::pie::{PieView, PieSortOrder};
+pub use crate::views::heatmap::HeatmapView;
+pub use crate::views::candlestick::{CandlestickView, OhlcView};
+pub use crate::views::annotation::AnnotationView;
+pub use crate::views::datum::{BarDatum, PointDatum, HeatmapDatum, OhlcDatum, symmetric_bounds};
+pub use crate::axis::{Axis, AxisPosition, TickLabelCollision};
 pub use crate::components::bar::BarLabelPosition;
+pub use crate::components::box_plot::WhiskerExtent;
+pub use crate::components::pie::{PieLabelPosition, PieLabelType};
 pub use crate::components::line::LineSeries;
-pub use crate::components::scatter::{MarkerType, PointLabelPosition};
+pub use crate::components::scatter::{MarkerType, MarkerStyle, PointLabelPosition};
+pub use crate::components::curve::{Curve, GapMode};
+pub use crate::components::area::{AreaMode, StackOffset};
+pub use crate::components::style::{DatumStyle, SeriesStyle, LineCap, LineJoin};
+pub use crate::components::annotation::AnnotationStyle;
 pub use crate::colors::Color;
+pub use crate::text::FontMetrics;
+pub use crate::theme::Theme;
+pub use crate::colors::scale::{ColorScale, ColorScaleType};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,145 @@
+use std::path::Path;
+use svg::node::element::Group;
+use svg::Node;
+use crate::Grid;
+use crate::axis::AxisPosition;
+use crate::components::legend::LegendEntry;
+use crate::export::save_document;
+use crate::frame::Frame;
+use crate::text::FontMetrics;
+use crate::theme::Theme;
+
+/// The font size of the page title.
+const PAGE_TITLE_FONT_SIZE: f32 = 24_f32;
+
+/// A Page is the biggest entity that can be saved. It displays one or more grids of charts
+/// below each other, with an optional title and a legend shared by all of the charts.
+/// The page is sized to fit its grids, and its title and legend are styled with the theme
+/// of the first chart of its first grid.
+pub struct Page<'a> {
+    grids: Vec<Grid<'a>>,
+    grid_gap: isize,
+    margin_top: isize,
+    margin_right: isize,
+    margin_bottom: isize,
+    margin_left: isize,
+    title: String,
+    legend_position: Option<AxisPosition>,
+    font_metrics: FontMetrics,
+}
+
+impl<'a> Page<'a> {
+    /// Create a new empty page.
+    pub fn new() -> Self {
+        Self {
+            grids: Vec::new(),
+            grid_gap: 40,
+            margin_top: 20,
+            margin_right: 20,
+            margin_bottom: 20,
+            margin_left: 20,
+            title: String::new(),
+            legend_position: None,
+            font_metrics: FontMetrics::new(),
+        }
+    }
+
+    /// Add a grid below the grids that were added before.
+    pub fn add_grid(mut self, grid: Grid<'a>) -> Self {
+        self.grids.push(grid);
+        self
+    }
+
+    /// Set the distance between two grids.
+    pub fn set_grid_gap(mut self, gap: isize) -> Self {
+        self.grid_gap = gap;
+        self
+    }
+
+    /// Set the margins of the page to provided values.
+    pub fn set_margins(mut self, top: isize, right: isize, bottom: isize, left: isize) -> Self {
+        self.margin_top = top;
+        self.margin_right = right;
+        self.margin_bottom = bottom;
+        self.margin_left = left;
+        self
+    }
+
+    /// Add page title.
+    pub fn add_title(mut self, title: String) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Add a legend at the specified side of the page, shared by all of its charts.
+    /// Series that appear in multiple charts are listed only once.
+    pub fn add_legend_at(mut self, position: AxisPosition) -> Self {
+        self.legend_position = Some(position);
+        self
+    }
+
+    /// Set the metrics of the font that the title and the legend of the page are measured with.
+    pub fn set_font_metrics(mut self, font_metrics: FontMetrics) -> Self {
+        self.font_metrics = font_metrics;
+        self
+    }
+
+    /// Generate the SVG for the page and return it along with the width and height of the page.
+    fn to_svg(&self) -> Result<(Group, isize, isize), String> {
+        let mut content = Group::new()
+            .set("class", "g-grids");
+        let mut content_width = 0;
+        let mut content_height = 0;
+
+        for (i, grid) in self.grids.iter().enumerate() {
+            let (mut grid_group, grid_width, grid_height) = grid.to_svg()?;
+            if i > 0 {
+                content_height += self.grid_gap;
+            }
+            grid_group.assign("transform", format!("translate({},{})", 0, content_height));
+            content.append(grid_group);
+
+            content_width = content_width.max(grid_width);
+            content_height += grid_height;
+        }
+
+        let frame = Frame {
+            title: &self.title,
+            title_font_size: PAGE_TITLE_FONT_SIZE,
+            legend_position: self.legend_position,
+            legend_entries: match self.legend_position {
+                Some(_) => self.grids.iter().flat_map(|grid| grid.get_legend_entries()).collect::<Vec<LegendEntry>>(),
+                None => Vec::new(),
+            },
+            margins: (self.margin_top, self.margin_right, self.margin_bottom, self.margin_left),
+            font_metrics: &self.font_metrics,
+            theme: self.grids.first().map(|grid| grid.get_theme()).unwrap_or_else(Theme::light),
+        };
+        let (mut group, width, height) = frame.render(content, content_width, content_height)?;
+        group.assign("class", "g-page");
+
+        Ok((group, width, height))
+    }
+
+    /// Save the page to a file. The format of the file (SVG, PNG or PDF) is given
+    /// by the extension of the path.
+    pub fn save<P>(self, path: P) -> Result<(), String> where
+        P: AsRef<Path>
+    {
+        let (svg_content, width, height) = self.to_svg()
+            .map_err(|e| format!("Encountered an error while saving the page: {:?}", e))?;
+        let document = svg::Document::new()
+            .set("width", width)
+            .set("height", height)
+            .set("viewBox", (0, 0, width, height))
+            .add(svg_content);
+
+        save_document(&document, path)
+    }
+}
+
+impl<'a> Default for Page<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::RwLock;
 use crate::scales::{Scale, ScaleType};
 
 /// The scale to represent categorical data.
@@ -9,14 +10,19 @@ pub struct ScaleBand {
     domain: Vec<String>,
     /// The range limits of the drawable area on the chart.
     range: Vec<isize>,
+    /// Whether the range was set explicitly, rather than defaulting to the extent of a chart's view.
+    range_is_set: bool,
+    /// The range to use if no range was set explicitly. It is behind a lock, like the
+    /// offsets, the step and the bandwidth, so that the scale can be shared between threads.
+    default_range: RwLock<Option<Vec<isize>>>,
     /// The offsets of each entry from domain.
-    offsets: Vec<f32>,
+    offsets: RwLock<Vec<f32>>,
     /// The hash map that maps domain keys with corresponding offset entries.
     index: HashMap<String, usize>,
     /// The distance between the start of the first bar and the start of the next one.
-    step: f32,
+    step: RwLock<f32>,
     /// The width of a bar.
-    bandwidth: f32,
+    bandwidth: RwLock<f32>,
     /// The distance between bars as a percentage of the step (between 0 and 1).
     padding_inner: f32,
     /// The distance from the beginning/end of the chart to the first/last bar (between 0 and 1).
@@ -29,6 +35,10 @@ pub struct ScaleBand {
     r0: f32,
     /// The end value of the range.
     r1: f32,
+    /// The maximum amount of ticks to display, if not every category should have a tick.
+    tick_count: Option<usize>,
+    /// The explicit categories to display ticks for instead of all of them.
+    tick_values: Option<Vec<String>>,
 }
 
 impl ScaleBand {
@@ -37,15 +47,19 @@ impl ScaleBand {
         Self {
             domain: Vec::new(),
             range: vec![0, 1],
-            offsets: Vec::new(),
+            range_is_set: false,
+            default_range: RwLock::new(None),
+            offsets: RwLock::new(Vec::new()),
             index: HashMap::new(),
-            step: 1f32,
-            bandwidth: 1f32,
+            step: RwLock::new(1f32),
+            bandwidth: RwLock::new(1f32),
             padding_inner: 0.1,
             padding_outer: 0.1,
             align: 0.5,
             r0: 0f32,
             r1: 0f32,
+            tick_count: None,
+            tick_values: None,
         }
     }
 
@@ -63,6 +77,20 @@ impl ScaleBand {
         self
     }
 
+    /// Set the maximum amount of ticks to display. The ticks are placed at every n-th
+    /// category, starting with the first one. By default, every category has a tick.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = Some(tick_count);
+        self
+    }
+
+    /// Set the categories to display ticks for instead of all of them.
+    /// Values that are not part of the domain are ignored.
+    pub fn set_tick_values(mut self, tick_values: Vec<String>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
     /// Set the domain limits for the scale band.
     pub fn set_domain(mut self, range: Vec<String>) -> Self {
         // Deduplicate the domain range and keep order of entries.
@@ -78,6 +106,7 @@ impl ScaleBand {
         }
 
         self.domain = unique;
+        self.index = self.domain.iter().enumerate().map(|(i, domain)| (domain.clone(), i)).collect();
         self.rescale();
         self
     }
@@ -90,25 +119,34 @@ impl ScaleBand {
     /// Set the range limits for the scale band.
     pub fn set_range(mut self, range: Vec<isize>) -> Self {
         self.range = range;
+        self.range_is_set = true;
         self.rescale();
+        self.range.sort();
         self
     }
 
-    /// Get the range limits of the scale.
+    /// Get the range limits that were set for the scale. The scale maps its domain onto
+    /// the extent of the view of a chart instead if no range was set, see `set_default_range`.
     pub fn range(&self) -> &Vec<isize> {
         &self.range
     }
 
-    fn rescale(&mut self) {
+    /// Get the range limits that the scale maps its domain onto.
+    fn effective_range(&self) -> (isize, isize) {
+        match (self.range_is_set, self.default_range.read().unwrap().as_ref()) {
+            (false, Some(range)) => (range[0], range[1]),
+            _ => (self.range[0], self.range[1]),
+        }
+    }
+
+    fn rescale(&self) {
         let n = self.domain.len();
-        let r0 = self.range[0];
-        let r1 = self.range[1];
+        let (r0, r1) = self.effective_range();
         let reverse = r1 < r0;
         let mut start = r0 as f32;
         let mut stop = r1 as f32;
 
         if reverse {
-            self.range = vec![r1, r0];
             start = r1 as f32;
             stop = r0 as f32;
         }
@@ -121,35 +159,20 @@ impl ScaleBand {
                 1f32
             }
         };
-        self.step = (stop - start) / step_denominator;
+        let step = (stop - start) / step_denominator;
+        *self.step.write().unwrap() = step;
 
         // TODO implement rounding of step, start and bandwidth values if specified by user.
 
-        start += (stop - start - self.step * (n as f32 - self.padding_inner)) * self.align;
+        start += (stop - start - step * (n as f32 - self.padding_inner)) * self.align;
 
-        self.bandwidth = self.step * (1f32 - self.padding_inner);
-
-        self.offsets.clear();
-        for i in 0..n {
-            self.offsets.push(start + self.step * i as f32);
-        }
+        *self.bandwidth.write().unwrap() = step * (1f32 - self.padding_inner);
 
+        let mut offsets = (0..n).map(|i| start + step * i as f32).collect::<Vec<f32>>();
         if reverse {
-            self.offsets.reverse();
-        }
-
-        self.index.clear();
-        let mut processed_domains = Vec::new();
-        for domain in self.domain.iter() {
-            // Check for already existing keys to remove any duplicates in the domain vector.
-            if !self.index.contains_key(domain) {
-                self.index.insert(domain.clone(), processed_domains.len());
-                processed_domains.push(domain.clone());
-            }
+            offsets.reverse();
         }
-        // Re-assign domains with any duplicates removed.
-        self.domain.clear();
-        self.domain = processed_domains;
+        *self.offsets.write().unwrap() = offsets;
     }
 }
 
@@ -161,26 +184,81 @@ impl Scale<String> for ScaleBand {
 
     /// Get the range value for the given domain entry.
     fn scale(&self, domain: &String) -> f32 {
-        self.offsets[*self.index.get(domain).unwrap()]
+        self.offsets.read().unwrap()[*self.index.get(domain).unwrap()]
     }
 
     /// Get the bandwidth (if present).
     fn bandwidth(&self) -> Option<f32> {
-        Some(self.bandwidth)
+        Some(*self.bandwidth.read().unwrap())
     }
 
     /// Get the start range value.
     fn range_start(&self) -> f32 {
-        self.range[0] as f32
+        self.effective_range().0 as f32
     }
 
     /// Get the end range value.
     fn range_end(&self) -> f32 {
-        self.range[1] as f32
+        self.effective_range().1 as f32
     }
 
     /// Get the list of ticks that represent the scale on a chart axis.
     fn get_ticks(&self) -> Vec<String> {
-        self.domain.clone()
+        if let Some(tick_values) = &self.tick_values {
+            return tick_values.iter().filter(|value| self.index.contains_key(*value)).cloned().collect();
+        }
+
+        match self.tick_count {
+            Some(0) => Vec::new(),
+            Some(tick_count) => {
+                let step = self.domain.len().div_ceil(tick_count);
+                self.domain.iter().step_by(step.max(1)).cloned().collect()
+            },
+            None => self.domain.clone(),
+        }
     }
-}
\ No newline at end of file
+
+    /// Set the range to use if no range was set explicitly.
+    fn set_default_range(&self, range: Vec<isize>) {
+        if !self.range_is_set {
+            let mut range = range;
+            *self.default_range.write().unwrap() = Some(range.clone());
+            self.rescale();
+            range.sort();
+            *self.default_range.write().unwrap() = Some(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn months() -> ScaleBand {
+        let domain = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul"].iter().map(|month| month.to_string()).collect();
+        ScaleBand::new().set_domain(domain).set_range(vec![0, 700])
+    }
+
+    #[test]
+    fn tick_count_limits_the_ticks_to_every_nth_category() {
+        assert_eq!(months().set_tick_count(3).get_ticks(), vec!["Jan", "Apr", "Jul"]);
+        assert_eq!(months().set_tick_count(10).get_ticks().len(), 7);
+        assert!(months().set_tick_count(0).get_ticks().is_empty());
+    }
+
+    #[test]
+    fn tick_values_outside_of_the_domain_are_ignored() {
+        let scale = months().set_tick_values(vec![String::from("Feb"), String::from("Dec"), String::from("Jun")]);
+
+        assert_eq!(scale.get_ticks(), vec!["Feb", "Jun"]);
+    }
+
+    #[test]
+    fn scales_can_be_shared_between_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<ScaleBand>();
+        assert_send_sync::<crate::ScaleLinear>();
+        assert_send_sync::<crate::ScaleSqrt>();
+    }
+}
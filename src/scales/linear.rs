@@ -1,4 +1,5 @@
 use std::cmp::{max, Ordering};
+use std::sync::RwLock;
 use crate::scales::{Scale, ScaleType};
 
 /// The scale to represent categorical data.
@@ -8,8 +9,15 @@ pub struct ScaleLinear {
     domain: Vec<f32>,
     /// The range limits of the drawable area on the chart.
     range: Vec<isize>,
+    /// Whether the range was set explicitly, rather than defaulting to the extent of a chart's view.
+    range_is_set: bool,
+    /// The range to use if no range was set explicitly.
+    /// It is behind a lock so that the scale can be shared between threads.
+    default_range: RwLock<Option<Vec<isize>>>,
     /// The amount of ticks to display.
     tick_count: usize,
+    /// The explicit tick values to display instead of the computed ones.
+    tick_values: Option<Vec<f32>>,
 }
 
 impl ScaleLinear {
@@ -18,10 +26,26 @@ impl ScaleLinear {
         Self {
             domain: Vec::new(),
             range: vec![0, 1],
+            range_is_set: false,
+            default_range: RwLock::new(None),
             tick_count: 10,
+            tick_values: None,
         }
     }
 
+    /// Set the approximate amount of ticks to display. The actual amount may differ,
+    /// since the ticks are placed at round values.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self
+    }
+
+    /// Set explicit tick values to display instead of the computed ones.
+    pub fn set_tick_values(mut self, tick_values: Vec<f32>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
     /// Set the domain limits for the scale band.
     pub fn set_domain(mut self, range: Vec<f32>) -> Self {
         self.domain = range;
@@ -36,14 +60,24 @@ impl ScaleLinear {
     /// Set the range limits for the scale band.
     pub fn set_range(mut self, range: Vec<isize>) -> Self {
         self.range = range;
+        self.range_is_set = true;
         self
     }
 
-    /// Get the range limits of the scale.
+    /// Get the range limits that were set for the scale. The scale maps its domain onto
+    /// the extent of the view of a chart instead if no range was set, see `set_default_range`.
     pub fn range(&self) -> &Vec<isize> {
         &self.range
     }
 
+    /// Get the range limits that the scale maps its domain onto.
+    fn effective_range(&self) -> (f32, f32) {
+        match (self.range_is_set, self.default_range.read().unwrap().as_ref()) {
+            (false, Some(range)) => (range[0] as f32, range[1] as f32),
+            _ => (self.range[0] as f32, self.range[1] as f32),
+        }
+    }
+
     /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
     fn normalize(&self, a: f32, b: f32, x: f32) -> f32 {
         // If a == b then return 0.5
@@ -60,6 +94,26 @@ impl ScaleLinear {
         (b - a) * t + a
     }
 
+    /// Get the range value for the given domain entry, after applying the
+    /// transformation to both the entry and the domain limits.
+    pub(crate) fn scale_transformed(&self, domain: f32, transform: fn(f32) -> f32) -> f32 {
+        let a = transform(self.domain[0]);
+        let b = transform(self.domain[1]);
+        let normalized = self.normalize(a, b, transform(domain));
+        let (a, b) = self.effective_range();
+
+        self.interpolate(a, b, normalized)
+    }
+
+    /// Get the domain value for the given range value, where the domain is transformed
+    /// the same way as in `scale_transformed` and `untransform` is the inverse transformation.
+    pub(crate) fn invert_transformed(&self, range: f32, transform: fn(f32) -> f32, untransform: fn(f32) -> f32) -> f32 {
+        let (a, b) = self.effective_range();
+        let normalized = self.normalize(a, b, range);
+
+        untransform(self.interpolate(transform(self.domain[0]), transform(self.domain[1]), normalized))
+    }
+
     /// Compute the distance between the ticks.
     fn tick_step(&self, start: f32, stop: f32) -> f32 {
         let e10 = 50_f32.sqrt();
@@ -95,14 +149,12 @@ impl Scale<f32> for ScaleLinear {
 
     /// Get the range value for the given domain entry.
     fn scale(&self, domain: &f32) -> f32 {
-        let a = self.domain[0];
-        let b = self.domain[1];
-        let normalized = self.normalize(a, b, *domain);
-        let a = self.range[0] as f32;
-        let b = self.range[1] as f32;
-        let scaled = self.interpolate(a, b, normalized);
+        self.scale_transformed(*domain, |x| x)
+    }
 
-        scaled
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Option<f32> {
+        Some(self.invert_transformed(range, |x| x, |x| x))
     }
 
     /// Get the bandwidth (if present).
@@ -112,18 +164,33 @@ impl Scale<f32> for ScaleLinear {
 
     /// Get the start range value.
     fn range_start(&self) -> f32 {
-        self.range[0] as f32
+        self.effective_range().0
     }
 
     /// Get the end range value.
     fn range_end(&self) -> f32 {
-        self.range[1] as f32
+        self.effective_range().1
+    }
+
+    /// Set the range to use if no range was set explicitly.
+    fn set_default_range(&self, range: Vec<isize>) {
+        if !self.range_is_set {
+            *self.default_range.write().unwrap() = Some(range);
+        }
     }
 
     /// Get the list of ticks that represent the scale on a chart axis.
     fn get_ticks(&self) -> Vec<f32> {
+        if let Some(tick_values) = &self.tick_values {
+            return tick_values.clone();
+        }
+
         let mut ticks = Vec::new();
 
+        if self.tick_count == 0 {
+            return ticks;
+        }
+
         if self.domain[0] == self.domain[1] && self.tick_count > 0 {
             ticks.push(self.domain[0] as f32);
             return ticks;
@@ -1,11 +1,13 @@
 pub mod band;
 pub mod linear;
+pub mod sqrt;
 
-#[derive(PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
 pub enum ScaleType {
     Band,
     Ordinal,
     Linear,
+    Sqrt,
 }
 
 /// The Scale trait defines common operations on all scales.
@@ -16,6 +18,11 @@ pub trait Scale<T> {
     /// Get the range value for the given domain entry.
     fn scale(&self, domain: &T) -> f32;
 
+    /// Get the domain entry for the given range value. Only continuous scales can be inverted.
+    fn invert(&self, _range: f32) -> Option<T> {
+        None
+    }
+
     /// Get the bandwidth (if present).
     fn bandwidth(&self) -> Option<f32>;
 
@@ -32,4 +39,17 @@ pub trait Scale<T> {
 
     /// Get the list of ticks that represent the scale on a chart axis.
     fn get_ticks(&self) -> Vec<T>;
-}
\ No newline at end of file
+
+    /// Set the range to use if no range was set explicitly. Charts set the default
+    /// range of the scales of their axes to the extent of their view when they are rendered,
+    /// so a scale that is shared by charts of different sizes is drawn with the range of
+    /// each chart in turn. The scales keep the default range behind a lock, so they can
+    /// still be shared between threads.
+    fn set_default_range(&self, _range: Vec<isize>) {}
+}
+
+/// Get an identifier of a scale instance, which is the same for all references to the instance.
+/// It is used to check whether a view and an axis display the same scale.
+pub(crate) fn get_scale_id<T>(scale: &dyn Scale<T>) -> usize {
+    scale as *const dyn Scale<T> as *const () as usize
+}
@@ -0,0 +1,143 @@
+use crate::scales::{Scale, ScaleType};
+use crate::scales::linear::ScaleLinear;
+
+/// The scale to represent continuous data by mapping the square root of the domain onto the range.
+/// It is often used to encode values as the radius of circles, so that the area of a circle
+/// (rather than its radius) is proportional to the value it represents.
+#[derive(Debug)]
+pub struct ScaleSqrt {
+    /// The linear scale that maps the square root of the domain onto the range,
+    /// and computes the ticks from the domain itself.
+    linear: ScaleLinear,
+}
+
+impl ScaleSqrt {
+    /// Create a new square root scale with default values.
+    pub fn new() -> Self {
+        Self {
+            linear: ScaleLinear::new(),
+        }
+    }
+
+    /// Set the approximate amount of ticks to display. The actual amount may differ,
+    /// since the ticks are placed at round values.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.linear = self.linear.set_tick_count(tick_count);
+        self
+    }
+
+    /// Set explicit tick values to display instead of the computed ones.
+    pub fn set_tick_values(mut self, tick_values: Vec<f32>) -> Self {
+        self.linear = self.linear.set_tick_values(tick_values);
+        self
+    }
+
+    /// Set the domain limits for the scale.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.linear = self.linear.set_domain(range);
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        self.linear.domain()
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.linear = self.linear.set_range(range);
+        self
+    }
+
+    /// Get the range limits that were set for the scale. The scale maps its domain onto
+    /// the extent of the view of a chart instead if no range was set, see `set_default_range`.
+    pub fn range(&self) -> &Vec<isize> {
+        self.linear.range()
+    }
+
+    /// Apply the square root transformation, preserving the sign of the value.
+    fn transform(x: f32) -> f32 {
+        x.signum() * x.abs().sqrt()
+    }
+
+    /// Reverse the square root transformation, preserving the sign of the value.
+    fn untransform(x: f32) -> f32 {
+        x.signum() * x * x
+    }
+}
+
+impl Default for ScaleSqrt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scale<f32> for ScaleSqrt {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Sqrt
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, domain: &f32) -> f32 {
+        self.linear.scale_transformed(*domain, Self::transform)
+    }
+
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Option<f32> {
+        Some(self.linear.invert_transformed(range, Self::transform, Self::untransform))
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        self.linear.bandwidth()
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.linear.range_start()
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.linear.range_end()
+    }
+
+    /// Set the range to use if no range was set explicitly.
+    fn set_default_range(&self, range: Vec<isize>) {
+        self.linear.set_default_range(range);
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    /// Ticks are evenly spaced in the domain, the same way as for a linear scale.
+    fn get_ticks(&self) -> Vec<f32> {
+        self.linear.get_ticks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_maps_square_root_of_domain() {
+        let scale = ScaleSqrt::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec![0, 20]);
+
+        assert_eq!(scale.scale(&0_f32), 0_f32);
+        assert_eq!(scale.scale(&25_f32), 10_f32);
+        assert_eq!(scale.scale(&100_f32), 20_f32);
+    }
+
+    #[test]
+    fn invert_maps_range_back_to_domain() {
+        let scale = ScaleSqrt::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec![0, 20]);
+
+        assert_eq!(scale.invert(0_f32), Some(0_f32));
+        assert_eq!(scale.invert(10_f32), Some(25_f32));
+        assert_eq!(scale.invert(20_f32), Some(100_f32));
+    }
+}
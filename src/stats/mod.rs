@@ -0,0 +1,91 @@
+/// Return the p-quantile of an already sorted slice of values, using linear
+/// interpolation between the two closest ranks (the same method D3.js uses).
+pub(crate) fn quantile(sorted: &[f32], p: f32) -> f32 {
+    if sorted.is_empty() {
+        return f32::NAN;
+    }
+    if sorted.len() == 1 || p <= 0_f32 {
+        return sorted[0];
+    }
+    if p >= 1_f32 {
+        return sorted[sorted.len() - 1];
+    }
+
+    let i = (sorted.len() - 1) as f32 * p;
+    let i0 = i.floor() as usize;
+    let value0 = sorted[i0];
+    let value1 = sorted[i0 + 1];
+
+    value0 + (value1 - value0) * (i - i0 as f32)
+}
+
+/// Return the arithmetic mean of the values.
+pub(crate) fn mean(values: &[f32]) -> f32 {
+    if values.is_empty() {
+        return f32::NAN;
+    }
+
+    values.iter().sum::<f32>() / values.len() as f32
+}
+
+/// Return the sample standard deviation of the values.
+pub(crate) fn deviation(values: &[f32]) -> f32 {
+    if values.len() < 2 {
+        return 0_f32;
+    }
+
+    let mean = mean(values);
+    let sum_of_squares = values.iter().map(|value| (value - mean).powi(2)).sum::<f32>();
+
+    (sum_of_squares / (values.len() - 1) as f32).sqrt()
+}
+
+/// Estimate a kernel bandwidth for the sorted samples using Silverman's rule of thumb.
+pub(crate) fn silverman_bandwidth(sorted: &[f32]) -> f32 {
+    let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+    let spread = match iqr > 0_f32 {
+        true => deviation(sorted).min(iqr / 1.34),
+        false => deviation(sorted),
+    };
+    let bandwidth = 0.9 * spread * (sorted.len() as f32).powf(-0.2);
+
+    // Fall back to a unit bandwidth when all samples are identical.
+    if bandwidth > 0_f32 {
+        bandwidth
+    } else {
+        1_f32
+    }
+}
+
+/// Estimate the probability density of the samples at each of the given positions
+/// using a gaussian kernel with the provided bandwidth.
+pub(crate) fn gaussian_kde(samples: &[f32], bandwidth: f32, positions: &[f32]) -> Vec<f32> {
+    let normalization = 1_f32 / (samples.len() as f32 * bandwidth * (2_f32 * std::f32::consts::PI).sqrt());
+
+    positions.iter().map(|position| {
+        samples.iter().map(|sample| {
+            let u = (position - sample) / bandwidth;
+            (-0.5 * u * u).exp()
+        }).sum::<f32>() * normalization
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantile_interpolates_between_ranks() {
+        let values = vec![1_f32, 2_f32, 3_f32, 4_f32];
+        assert_eq!(quantile(&values, 0.5), 2.5);
+        assert_eq!(quantile(&values, 0.25), 1.75);
+        assert_eq!(quantile(&values, 1_f32), 4_f32);
+    }
+
+    #[test]
+    fn gaussian_kde_peaks_at_the_sample() {
+        let density = gaussian_kde(&[0_f32], 1_f32, &[-1_f32, 0_f32, 1_f32]);
+        assert!(density[1] > density[0]);
+        assert_eq!(density[0], density[2]);
+    }
+}
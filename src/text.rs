@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The advance widths of the printable ASCII characters (from ' ' to '~') of Helvetica,
+/// in thousandths of an em. Helvetica is metrically compatible with Arial and Liberation Sans,
+/// which are what most renderers use for the generic sans-serif font family.
+const HELVETICA_ADVANCES: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Glyph advance metrics of a font, used to measure text when laying out a chart.
+/// Measurements ignore kerning, so they are slight overestimates for most fonts.
+#[derive(Debug, Clone)]
+pub struct FontMetrics {
+    /// The advance widths of the characters, in ems. They are shared between clones,
+    /// since every axis and legend of a chart keeps a copy of the metrics.
+    advances: Arc<HashMap<char, f32>>,
+    /// The advance width of characters that are missing from `advances`, in ems.
+    default_advance: f32,
+    /// The distance from the baseline to the top of the tallest glyphs, in ems.
+    ascent: f32,
+    /// The distance from the baseline to the bottom of the lowest glyphs, in ems.
+    descent: f32,
+}
+
+impl FontMetrics {
+    /// Create the metrics of the default sans-serif font (Helvetica and its metric-compatible fonts).
+    pub fn new() -> Self {
+        let mut advances = HELVETICA_ADVANCES.iter().enumerate()
+            .map(|(i, advance)| ((b' ' + i as u8) as char, *advance as f32 / 1000_f32))
+            .collect::<HashMap<char, f32>>();
+        advances.insert('…', 1_f32);
+        advances.insert('–', 0.556);
+        advances.insert('—', 1_f32);
+        advances.insert('°', 0.4);
+        advances.insert('\u{a0}', 0.278);
+
+        Self {
+            advances: Arc::new(advances),
+            default_advance: 0.556,
+            ascent: 0.718,
+            descent: 0.207,
+        }
+    }
+
+    /// Load the metrics of a TrueType or OpenType font from the contents of its file.
+    pub fn from_font_data(data: &[u8]) -> Result<Self, String> {
+        let font = FontFile::new(data)?;
+
+        let head = font.table(b"head")?;
+        let units_per_em = font.read_u16(head + 18)? as f32;
+        if units_per_em == 0_f32 {
+            return Err("The font has zero units per em".to_string());
+        }
+
+        let hhea = font.table(b"hhea")?;
+        let ascent = font.read_i16(hhea + 4)? as f32 / units_per_em;
+        let descent = -(font.read_i16(hhea + 6)? as f32) / units_per_em;
+        let nr_of_h_metrics = font.read_u16(hhea + 34)? as usize;
+        if nr_of_h_metrics == 0 {
+            return Err("The font has no horizontal metrics".to_string());
+        }
+
+        // Glyphs after the last horizontal metric share its advance width.
+        let hmtx = font.table(b"hmtx")?;
+        let glyph_advance = |glyph: usize| -> Result<f32, String> {
+            let advance = font.read_u16(hmtx + 4 * glyph.min(nr_of_h_metrics - 1))?;
+            Ok(advance as f32 / units_per_em)
+        };
+
+        let mut advances = HashMap::new();
+        for (c, glyph) in font.character_map()? {
+            advances.insert(c, glyph_advance(glyph as usize)?);
+        }
+
+        Ok(Self {
+            advances: Arc::new(advances),
+            default_advance: glyph_advance(0)?,
+            ascent,
+            descent,
+        })
+    }
+
+    /// Get the width of a single line of text in the given font size.
+    pub fn text_width(&self, text: &str, font_size: f32) -> f32 {
+        text.chars()
+            .map(|c| *self.advances.get(&c).unwrap_or(&self.default_advance))
+            .sum::<f32>() * font_size
+    }
+
+    /// Get the height of a line of text in the given font size.
+    pub fn text_height(&self, font_size: f32) -> f32 {
+        (self.ascent + self.descent) * font_size
+    }
+
+    /// Get the distance from the baseline to the top of a line of text in the given font size.
+    pub fn ascent(&self, font_size: f32) -> f32 {
+        self.ascent * font_size
+    }
+
+    /// Get the distance from the baseline to the bottom of a line of text in the given font size.
+    pub fn descent(&self, font_size: f32) -> f32 {
+        self.descent * font_size
+    }
+
+    /// Get the width and height of the bounding box of a single line of text in the given font size.
+    pub fn bounding_box(&self, text: &str, font_size: f32) -> (f32, f32) {
+        (self.text_width(text, font_size), self.text_height(font_size))
+    }
+}
+
+impl Default for FontMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A minimal reader of the tables of a TrueType or OpenType font file.
+struct FontFile<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> FontFile<'a> {
+    fn new(data: &'a [u8]) -> Result<Self, String> {
+        let font = Self { data };
+        match font.read_u32(0)? {
+            // TrueType outlines, Apple TrueType and CFF (OpenType) outlines.
+            0x0001_0000 | 0x7472_7565 | 0x4f54_544f => Ok(font),
+            _ => Err("The data is not a TrueType or OpenType font".to_string()),
+        }
+    }
+
+    fn read_u16(&self, offset: usize) -> Result<u16, String> {
+        match self.data.get(offset..offset + 2) {
+            Some(bytes) => Ok(u16::from_be_bytes([bytes[0], bytes[1]])),
+            None => Err("Unexpected end of font data".to_string()),
+        }
+    }
+
+    fn read_i16(&self, offset: usize) -> Result<i16, String> {
+        Ok(self.read_u16(offset)? as i16)
+    }
+
+    fn read_u32(&self, offset: usize) -> Result<u32, String> {
+        match self.data.get(offset..offset + 4) {
+            Some(bytes) => Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+            None => Err("Unexpected end of font data".to_string()),
+        }
+    }
+
+    /// Get the offset of the table with the given tag.
+    fn table(&self, tag: &[u8; 4]) -> Result<usize, String> {
+        let nr_of_tables = self.read_u16(4)? as usize;
+        for i in 0..nr_of_tables {
+            let record = 12 + 16 * i;
+            if self.data.get(record..record + 4) == Some(&tag[..]) {
+                return Ok(self.read_u32(record + 8)? as usize);
+            }
+        }
+
+        Err(format!("The font has no '{}' table", String::from_utf8_lossy(tag)))
+    }
+
+    /// Map the characters of the font to their glyph ids, using the Unicode subtable
+    /// of the 'cmap' table (in format 4 or 12).
+    fn character_map(&self) -> Result<Vec<(char, u32)>, String> {
+        let cmap = self.table(b"cmap")?;
+        let nr_of_subtables = self.read_u16(cmap + 2)? as usize;
+
+        // Prefer full Unicode subtables over those restricted to the Basic Multilingual Plane.
+        let mut best: Option<(usize, usize)> = None;
+        for i in 0..nr_of_subtables {
+            let record = cmap + 4 + 8 * i;
+            let platform = self.read_u16(record)?;
+            let encoding = self.read_u16(record + 2)?;
+            let subtable = cmap + self.read_u32(record + 4)? as usize;
+            let format = self.read_u16(subtable)?;
+            let priority = match (platform, encoding, format) {
+                (3, 10, 12) | (0, _, 12) => 2,
+                (3, 1, 4) | (0, _, 4) => 1,
+                _ => continue,
+            };
+            match best {
+                Some((best_priority, _)) if best_priority >= priority => {},
+                _ => best = Some((priority, subtable)),
+            }
+        }
+
+        let subtable = match best {
+            Some((_, subtable)) => subtable,
+            None => return Err("The font has no Unicode character map".to_string()),
+        };
+
+        let mut mapping = Vec::new();
+        if self.read_u16(subtable)? == 12 {
+            let nr_of_groups = self.read_u32(subtable + 12)? as usize;
+            for i in 0..nr_of_groups {
+                let group = subtable + 16 + 12 * i;
+                let start = self.read_u32(group)?;
+                let end = self.read_u32(group + 4)?;
+                let start_glyph = self.read_u32(group + 8)?;
+                for code in start..=end {
+                    if let Some(c) = std::char::from_u32(code) {
+                        mapping.push((c, start_glyph.wrapping_add(code - start)));
+                    }
+                }
+            }
+        } else {
+            let nr_of_segments = self.read_u16(subtable + 6)? as usize / 2;
+            let end_codes = subtable + 14;
+            let start_codes = end_codes + 2 * nr_of_segments + 2;
+            let id_deltas = start_codes + 2 * nr_of_segments;
+            let id_range_offsets = id_deltas + 2 * nr_of_segments;
+            for i in 0..nr_of_segments {
+                let end = self.read_u16(end_codes + 2 * i)?;
+                let start = self.read_u16(start_codes + 2 * i)?;
+                let delta = self.read_u16(id_deltas + 2 * i)?;
+                let range_offset = self.read_u16(id_range_offsets + 2 * i)? as usize;
+                for code in start..=end {
+                    if code == 0xffff {
+                        break;
+                    }
+                    let glyph = if range_offset == 0 {
+                        code.wrapping_add(delta)
+                    } else {
+                        let address = id_range_offsets + 2 * i + range_offset + 2 * (code - start) as usize;
+                        match self.read_u16(address)? {
+                            0 => 0,
+                            glyph => glyph.wrapping_add(delta),
+                        }
+                    };
+                    if let (Some(c), true) = (std::char::from_u32(code as u32), glyph != 0) {
+                        mapping.push((c, glyph as u32));
+                    }
+                }
+            }
+        }
+
+        Ok(mapping)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assemble a font with the given tables.
+    fn font(tables: Vec<(&[u8; 4], Vec<u8>)>) -> Vec<u8> {
+        let mut data = vec![0, 1, 0, 0, 0, tables.len() as u8, 0, 0, 0, 0, 0, 0];
+        let mut offset = 12 + 16 * tables.len();
+        for (tag, table) in tables.iter() {
+            data.extend_from_slice(&tag[..]);
+            data.extend_from_slice(&[0, 0, 0, 0]);
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+            offset += table.len();
+        }
+        for (_, table) in tables.into_iter() {
+            data.extend(table);
+        }
+        data
+    }
+
+    fn words(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|value| value.to_be_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn default_metrics_measure_helvetica() {
+        let metrics = FontMetrics::new();
+        // 'A' is 667 and 'b' is 556 thousandths of an em wide.
+        assert!((metrics.text_width("Ab", 10_f32) - 12.23).abs() < 1e-4);
+    }
+
+    #[test]
+    fn metrics_from_font_data() {
+        let mut head = vec![0; 54];
+        head[18..20].copy_from_slice(&2048_u16.to_be_bytes());
+        let mut hhea = vec![0; 36];
+        hhea[4..6].copy_from_slice(&1536_i16.to_be_bytes());
+        hhea[6..8].copy_from_slice(&(-512_i16).to_be_bytes());
+        hhea[34..36].copy_from_slice(&2_u16.to_be_bytes());
+        // The advances of glyph 0 (.notdef) and glyph 1; glyph 2 shares the advance of glyph 1.
+        let hmtx = words(&[1024, 0, 512, 0]);
+        // Map 'A' and 'B' to glyphs 1 and 2 with a single segment (and the final 0xFFFF segment).
+        let cmap = [
+            words(&[0, 1, 3, 1, 0, 12]),
+            words(&[4, 32, 0, 4, 0, 0, 0]),
+            words(&[66, 0xffff, 0, 65, 0xffff, 0xffc0, 1, 0, 0]),
+        ].concat();
+
+        let data = font(vec![(b"head", head), (b"hhea", hhea), (b"hmtx", hmtx), (b"cmap", cmap)]);
+        let metrics = FontMetrics::from_font_data(&data).unwrap();
+
+        assert_eq!(metrics.text_width("AB", 16_f32), 8_f32);
+        assert_eq!(metrics.text_width("C", 16_f32), 8_f32);
+        assert_eq!(metrics.text_height(16_f32), 16_f32);
+    }
+}
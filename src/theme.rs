@@ -0,0 +1,637 @@
+use std::cell::RefCell;
+use svg::Node;
+use crate::chart::StylingMode;
+use crate::colors::Color;
+
+/// The foreground color that views draw their labels and outlines with.
+const DEFAULT_FOREGROUND_COLOR: &str = "#333";
+
+/// The font family that all text of a chart is displayed with by default.
+const DEFAULT_FONT_FAMILY: &str = "sans-serif";
+
+/// A set of styles that applies to a whole chart: the typography, the colors of the text
+/// and the axes, the stroke widths, the background, the gridlines, the palette of the views
+/// and the spacing around the title and the legend. Apply a theme with `Chart::set_theme`.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    font_family: String,
+    title_font_size: f32,
+    axis_label_font_size: f32,
+    tick_label_font_size: f32,
+    legend_font_size: f32,
+    label_font_size: f32,
+    title_color: String,
+    text_color: String,
+    foreground_color: String,
+    axis_color: String,
+    axis_stroke_width: f32,
+    axis_domain_visible: bool,
+    tick_size: usize,
+    background: Option<String>,
+    grid_line_color: String,
+    grid_line_dash_array: String,
+    minor_grid_line_color: String,
+    minor_grid_line_dash_array: String,
+    palette: Vec<Color>,
+    title_padding: f32,
+    legend_gap: f32,
+    styling_mode: StylingMode,
+    key_styles: Vec<(String, String, String)>,
+}
+
+impl Theme {
+    /// Create the default light theme: gray text and axes on a transparent background.
+    pub fn light() -> Self {
+        Self {
+            font_family: String::from(DEFAULT_FONT_FAMILY),
+            title_font_size: 24_f32,
+            axis_label_font_size: 14_f32,
+            tick_label_font_size: 12_f32,
+            legend_font_size: 12_f32,
+            label_font_size: 14_f32,
+            title_color: String::from("#777"),
+            text_color: String::from("#777"),
+            foreground_color: String::from(DEFAULT_FOREGROUND_COLOR),
+            axis_color: String::from("#bbbbbb"),
+            axis_stroke_width: 1_f32,
+            axis_domain_visible: true,
+            tick_size: 6,
+            background: None,
+            grid_line_color: String::from("#e5e5e5"),
+            grid_line_dash_array: String::new(),
+            minor_grid_line_color: String::from("#f2f2f2"),
+            minor_grid_line_dash_array: String::new(),
+            palette: Color::color_scheme_10(),
+            title_padding: 14_f32,
+            legend_gap: 10_f32,
+            styling_mode: StylingMode::Inline,
+            key_styles: Vec::new(),
+        }
+    }
+
+    /// Create a dark theme: light text and brighter colors on a dark background.
+    pub fn dark() -> Self {
+        Self::light()
+            .set_background(Color::from_hex("#1e1e1e"))
+            .set_title_color(Color::from_hex("#e0e0e0"))
+            .set_text_color(Color::from_hex("#aaaaaa"))
+            .set_foreground_color(Color::from_hex("#e0e0e0"))
+            .set_axis_color(Color::from_hex("#555555"))
+            .set_grid_line_style(Color::from_hex("#333333"), "")
+            .set_minor_grid_line_style(Color::from_hex("#2a2a2a"), "")
+            .set_palette(Color::color_scheme_tableau_10())
+    }
+
+    /// Create a minimal theme without axis lines and tick lines, which relies on
+    /// gridlines and tick labels alone.
+    pub fn minimal() -> Self {
+        Self::light()
+            .set_title_font_size(20_f32)
+            .set_title_color(Color::from_hex("#555555"))
+            .set_text_color(Color::from_hex("#999999"))
+            .set_axis_color(Color::from_hex("#dddddd"))
+            .set_axis_domain_visibility(false)
+            .set_tick_size(0)
+            .set_grid_line_style(Color::from_hex("#eeeeee"), "")
+            .set_palette(Color::color_scheme_tableau_10())
+    }
+
+    /// Create a high-contrast theme: black text and thicker axes in bigger fonts on a white
+    /// background, with a palette that remains distinguishable for color-blind readers.
+    pub fn high_contrast() -> Self {
+        Self::light()
+            .set_title_font_size(26_f32)
+            .set_axis_label_font_size(16_f32)
+            .set_tick_label_font_size(14_f32)
+            .set_legend_font_size(14_f32)
+            .set_background(Color::from_hex("#ffffff"))
+            .set_title_color(Color::from_hex("#000000"))
+            .set_text_color(Color::from_hex("#000000"))
+            .set_foreground_color(Color::from_hex("#000000"))
+            .set_axis_color(Color::from_hex("#000000"))
+            .set_axis_stroke_width(2_f32)
+            .set_grid_line_style(Color::from_hex("#999999"), "")
+            .set_minor_grid_line_style(Color::from_hex("#cccccc"), "")
+            .set_palette(Color::from_vec_of_hex_strings(vec![
+                "#0072b2", "#d55e00", "#009e73", "#cc79a7", "#e69f00", "#56b4e9", "#f0e442", "#000000",
+            ]))
+    }
+
+    /// Create a theme for printing: black text and axes on a white background, dashed gridlines
+    /// and a grayscale palette that survives black and white printers.
+    pub fn print() -> Self {
+        Self::light()
+            .set_background(Color::from_hex("#ffffff"))
+            .set_title_color(Color::from_hex("#000000"))
+            .set_text_color(Color::from_hex("#333333"))
+            .set_foreground_color(Color::from_hex("#000000"))
+            .set_axis_color(Color::from_hex("#000000"))
+            .set_grid_line_style(Color::from_hex("#bbbbbb"), "2 2")
+            .set_minor_grid_line_style(Color::from_hex("#dddddd"), "1 3")
+            .set_palette(Color::from_vec_of_hex_strings(vec![
+                "#252525", "#737373", "#bdbdbd", "#525252", "#969696", "#d9d9d9",
+            ]))
+    }
+
+    /// Set the font family of all text of the chart. Set the font metrics of the chart
+    /// as well (see `Chart::set_font_metrics`), so that the text is laid out correctly.
+    pub fn set_font_family(mut self, font_family: &str) -> Self {
+        self.font_family = String::from(font_family);
+        self
+    }
+
+    /// Set the font size of the chart title.
+    pub fn set_title_font_size(mut self, font_size: f32) -> Self {
+        self.title_font_size = font_size;
+        self
+    }
+
+    /// Set the font size of the axis labels.
+    pub fn set_axis_label_font_size(mut self, font_size: f32) -> Self {
+        self.axis_label_font_size = font_size;
+        self
+    }
+
+    /// Set the font size of the tick labels.
+    pub fn set_tick_label_font_size(mut self, font_size: f32) -> Self {
+        self.tick_label_font_size = font_size;
+        self
+    }
+
+    /// Set the font size of the labels of the legend entries.
+    pub fn set_legend_font_size(mut self, font_size: f32) -> Self {
+        self.legend_font_size = font_size;
+        self
+    }
+
+    /// Set the font size of the labels of the data, e.g. the labels of bars, points or heatmap cells.
+    pub fn set_label_font_size(mut self, font_size: f32) -> Self {
+        self.label_font_size = font_size;
+        self
+    }
+
+    /// Set the color of the chart title.
+    pub fn set_title_color(mut self, color: Color) -> Self {
+        self.title_color = color.as_hex();
+        self
+    }
+
+    /// Set the color of the axis labels, the tick labels and the legend labels.
+    pub fn set_text_color(mut self, color: Color) -> Self {
+        self.text_color = color.as_hex();
+        self
+    }
+
+    /// Set the color that views draw their data labels and outlines with
+    /// (e.g. bar labels, box plot whiskers or pie labels).
+    pub fn set_foreground_color(mut self, color: Color) -> Self {
+        self.foreground_color = color.as_hex();
+        self
+    }
+
+    /// Set the color of the axis lines and the tick lines.
+    pub fn set_axis_color(mut self, color: Color) -> Self {
+        self.axis_color = color.as_hex();
+        self
+    }
+
+    /// Set the stroke width of the axis lines and the tick lines.
+    pub fn set_axis_stroke_width(mut self, stroke_width: f32) -> Self {
+        self.axis_stroke_width = stroke_width;
+        self
+    }
+
+    /// Set whether the axis (domain) lines are displayed.
+    pub fn set_axis_domain_visibility(mut self, visible: bool) -> Self {
+        self.axis_domain_visible = visible;
+        self
+    }
+
+    /// Set the length of the tick lines.
+    pub fn set_tick_size(mut self, tick_size: usize) -> Self {
+        self.tick_size = tick_size;
+        self
+    }
+
+    /// Set the color that the background of the chart is filled with.
+    /// The background is transparent by default.
+    pub fn set_background(mut self, color: Color) -> Self {
+        self.background = Some(color.as_hex());
+        self
+    }
+
+    /// Set the stroke color and dash array (e.g. "4 2") of the major gridlines.
+    /// An empty dash array draws solid lines.
+    pub fn set_grid_line_style(mut self, color: Color, dash_array: &str) -> Self {
+        self.grid_line_color = color.as_hex();
+        self.grid_line_dash_array = String::from(dash_array);
+        self
+    }
+
+    /// Set the stroke color and dash array (e.g. "4 2") of the minor gridlines.
+    /// An empty dash array draws solid lines.
+    pub fn set_minor_grid_line_style(mut self, color: Color, dash_array: &str) -> Self {
+        self.minor_grid_line_color = color.as_hex();
+        self.minor_grid_line_dash_array = String::from(dash_array);
+        self
+    }
+
+    /// Set the palette of the views. Views that use the default colors are displayed
+    /// with the palette instead, while views with custom colors keep them.
+    pub fn set_palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Set the distance between the top of the chart and the top of the title.
+    pub fn set_title_padding(mut self, padding: f32) -> Self {
+        self.title_padding = padding;
+        self
+    }
+
+    /// Set the distance between the legend and the neighbouring elements of the chart.
+    pub fn set_legend_gap(mut self, gap: f32) -> Self {
+        self.legend_gap = gap;
+        self
+    }
+
+    pub(crate) fn get_font_family(&self) -> &str {
+        &self.font_family
+    }
+
+    pub(crate) fn get_title_font_size(&self) -> f32 {
+        self.title_font_size
+    }
+
+    pub(crate) fn get_axis_label_font_size(&self) -> f32 {
+        self.axis_label_font_size
+    }
+
+    pub(crate) fn get_tick_label_font_size(&self) -> f32 {
+        self.tick_label_font_size
+    }
+
+    pub(crate) fn get_legend_font_size(&self) -> f32 {
+        self.legend_font_size
+    }
+
+    pub(crate) fn get_title_color(&self) -> &str {
+        &self.title_color
+    }
+
+    pub(crate) fn get_text_color(&self) -> &str {
+        &self.text_color
+    }
+
+    pub(crate) fn get_axis_color(&self) -> &str {
+        &self.axis_color
+    }
+
+    pub(crate) fn get_axis_stroke_width(&self) -> f32 {
+        self.axis_stroke_width
+    }
+
+    pub(crate) fn is_axis_domain_visible(&self) -> bool {
+        self.axis_domain_visible
+    }
+
+    pub(crate) fn get_tick_size(&self) -> usize {
+        self.tick_size
+    }
+
+    pub(crate) fn get_background(&self) -> Option<&str> {
+        self.background.as_deref()
+    }
+
+    pub(crate) fn get_grid_line_style(&self) -> (&str, &str) {
+        (&self.grid_line_color, &self.grid_line_dash_array)
+    }
+
+    pub(crate) fn get_minor_grid_line_style(&self) -> (&str, &str) {
+        (&self.minor_grid_line_color, &self.minor_grid_line_dash_array)
+    }
+
+    pub(crate) fn get_title_padding(&self) -> f32 {
+        self.title_padding
+    }
+
+    pub(crate) fn get_legend_gap(&self) -> f32 {
+        self.legend_gap
+    }
+
+    /// Set how the elements of the chart that is rendered with the theme are styled, along
+    /// with the series (pairs of keys and colors) of the chart, which are given unique class names.
+    pub(crate) fn set_styling(mut self, styling_mode: StylingMode, series: &[(&str, &str)]) -> Self {
+        self.styling_mode = styling_mode;
+        self.key_styles = Vec::new();
+        for (key, color) in series.iter() {
+            if key.is_empty() || self.key_styles.iter().any(|(existing, _, _)| existing == key) {
+                continue;
+            }
+            // Different keys can have the same slug (e.g. "A/B" and "A B"), so later ones are numbered.
+            let base = class_slug(key);
+            let mut slug = base.clone();
+            let mut index = 1;
+            while slug.is_empty() || self.key_styles.iter().any(|(_, existing, _)| *existing == slug) {
+                index += 1;
+                slug = format!("{}-{}", base, index).trim_start_matches('-').to_string();
+            }
+            self.key_styles.push((key.to_string(), slug, color.to_string()));
+        }
+        self
+    }
+
+    /// Return whether the elements are styled with classes (see `StylingMode::Classes`).
+    pub(crate) fn is_styled_with_classes(&self) -> bool {
+        self.styling_mode == StylingMode::Classes
+    }
+
+    /// Return the given value of the theme if the elements are styled with classes, in which
+    /// case the stylesheet of the chart gives it to the elements that it applies to.
+    pub(crate) fn in_stylesheet<'v>(&self, value: &'v str) -> Option<&'v str> {
+        Some(value).filter(|_| self.is_styled_with_classes())
+    }
+
+    /// Return the classes and the color of the elements that represent the data of the given key.
+    pub(crate) fn get_key_style(&self, key: &str) -> KeyStyle {
+        match self.key_styles.iter().find(|(existing, _, _)| existing == key) {
+            Some((_, slug, color)) => KeyStyle {
+                slug: slug.clone(),
+                color: Some(color.clone()).filter(|_| self.is_styled_with_classes()),
+            },
+            None => KeyStyle { slug: class_slug(key), color: None },
+        }
+    }
+
+    /// Generate a stylesheet that styles the elements of a chart by their classes the way the
+    /// theme does, along with the colors of the series that are set with `set_styling`.
+    pub(crate) fn get_stylesheet(&self) -> String {
+        let rule = |selectors: &str, declarations: &[(&str, String)]| {
+            let declarations = declarations.iter()
+                .map(|(property, value)| format!("{}: {};", property, value))
+                .collect::<Vec<String>>();
+            format!("{} {{ {} }}", selectors, declarations.join(" "))
+        };
+        let text_rule = |selector: &str, color: &str, font_size: f32| rule(selector, &[
+            ("fill", color.to_string()),
+            ("font-family", self.font_family.clone()),
+            ("font-size", format!("{}px", font_size)),
+        ]);
+        let grid_rule = |selector: &str, color: &str, dash_array: &str| {
+            let mut declarations = vec![("stroke", color.to_string()), ("stroke-width", String::from("1px"))];
+            if !dash_array.is_empty() {
+                declarations.push(("stroke-dasharray", dash_array.to_string()));
+            }
+            rule(selector, &declarations)
+        };
+
+        let mut rules = Vec::new();
+        if let Some(background) = &self.background {
+            rules.push(rule(".g-background", &[("fill", background.clone())]));
+        }
+        rules.push(text_rule(".title", &self.title_color, self.title_font_size));
+        rules.push(text_rule(".axis-label", &self.text_color, self.axis_label_font_size));
+        rules.push(text_rule(".tick-label", &self.text_color, self.tick_label_font_size));
+        rules.push(text_rule(".legend-label", &self.text_color, self.legend_font_size));
+        rules.push(text_rule(".bar-label", &self.foreground_color, self.label_font_size));
+        rules.push(rule(".domain, .tick-line, .minor-tick", &[
+            ("stroke", self.axis_color.clone()),
+            ("stroke-width", format!("{}px", self.axis_stroke_width)),
+        ]));
+        rules.push(grid_rule(".grid .grid-line", &self.grid_line_color, &self.grid_line_dash_array));
+        rules.push(grid_rule(".minor-grid .grid-line", &self.minor_grid_line_color, &self.minor_grid_line_dash_array));
+        // The elements of a key and the groups of a series are filled with the color of the key,
+        // and the lines and the outlines of the areas of a series are stroked with it.
+        for (_, slug, color) in self.key_styles.iter() {
+            rules.push(rule(&format!(".key-{0}, .series-{0}", slug), &[("fill", color.clone())]));
+            rules.push(rule(&format!(".series-{0} .line-path, .series-{0} .area-path", slug), &[("stroke", color.clone())]));
+        }
+
+        rules.join("\n")
+    }
+}
+
+/// The classes of the elements that represent the data of a key, and the color that the
+/// stylesheet of the chart gives them (see `Theme::get_key_style`).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct KeyStyle {
+    slug: String,
+    color: Option<String>,
+}
+
+impl KeyStyle {
+    /// Return the classes of an element that represents the data of the key, e.g.
+    /// `bar-block key-revenue` for the `bar-block` role and the "Revenue" key.
+    /// Elements without a key only have the class of their role.
+    pub(crate) fn get_key_classes(&self, role: &str) -> String {
+        match self.slug.is_empty() {
+            true => String::from(role),
+            false => format!("{} key-{}", role, self.slug),
+        }
+    }
+
+    /// Return the classes of an element that groups the data of a series, e.g.
+    /// `line series-revenue` for the `line` role and the "Revenue" key.
+    pub(crate) fn get_series_classes(&self, role: &str) -> String {
+        match self.slug.is_empty() {
+            true => String::from(role),
+            false => format!("{} series-{}", role, self.slug),
+        }
+    }
+
+    /// Return the color that the stylesheet gives the elements of the key, which is only
+    /// set if the elements are styled with classes.
+    pub(crate) fn get_color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+}
+
+/// The presentation attributes of an element that the stylesheet of a chart can cover. A value
+/// is left out if the stylesheet gives the element the same value, and it is set in the `style`
+/// attribute of the element (which takes precedence over the stylesheet) if the values differ.
+#[derive(Debug, Default)]
+pub(crate) struct Presentation {
+    attributes: Vec<(&'static str, String)>,
+    styles: Vec<String>,
+}
+
+impl Presentation {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of a property, along with the value that the stylesheet gives the element,
+    /// if the element is styled with classes (see `Theme::in_stylesheet`).
+    pub(crate) fn set(mut self, property: &'static str, value: impl ToString, styled: Option<&str>) -> Self {
+        let value = value.to_string();
+        let normalize = |value: &str| value.trim().trim_end_matches("px").to_lowercase();
+        match styled {
+            None => self.attributes.push((property, value)),
+            Some(styled) if normalize(styled) == normalize(&value) => {},
+            Some(_) => self.styles.push(format!("{}: {};", property, value)),
+        }
+        self
+    }
+
+    /// Assign the presentation attributes to the element.
+    pub(crate) fn assign_to<T: Node>(self, node: &mut T) {
+        for (property, value) in self.attributes.into_iter() {
+            node.assign(property, value);
+        }
+        if !self.styles.is_empty() {
+            node.assign("style", self.styles.join(" "));
+        }
+    }
+}
+
+/// The styles that a view takes from the theme of the chart that renders it (see
+/// `View::set_theme`). The keys of the view are colored with the colors that were set
+/// on the view, or with the palette of the theme otherwise.
+#[derive(Debug)]
+pub(crate) struct ViewTheme {
+    colors: Option<Vec<Color>>,
+    theme: RefCell<Theme>,
+}
+
+impl ViewTheme {
+    pub(crate) fn new() -> Self {
+        Self {
+            colors: None,
+            theme: RefCell::new(Theme::light()),
+        }
+    }
+
+    /// Set the colors of the view, which take precedence over the palette of the theme.
+    pub(crate) fn set_colors(&mut self, colors: Vec<Color>) {
+        self.colors = Some(colors).filter(|colors| !colors.is_empty());
+    }
+
+    /// Set the theme of the chart that renders the view.
+    pub(crate) fn set_theme(&self, theme: &Theme) {
+        *self.theme.borrow_mut() = theme.clone();
+    }
+
+    /// Return the color of the key at the given index of the keys of the view.
+    pub(crate) fn get_color(&self, index: usize) -> String {
+        let theme = self.theme.borrow();
+        let colors = self.colors.as_ref().unwrap_or(&theme.palette);
+        match colors.is_empty() {
+            true => String::from(DEFAULT_FOREGROUND_COLOR),
+            false => colors[index % colors.len()].as_hex(),
+        }
+    }
+
+    /// Return the color of the given key, which is one of the given keys of the view.
+    pub(crate) fn get_key_color(&self, keys: &[String], key: &str) -> String {
+        self.get_color(keys.iter().position(|existing| existing == key).unwrap_or(0))
+    }
+
+    /// Return the color of the labels and the outlines of the view.
+    pub(crate) fn get_foreground_color(&self) -> String {
+        self.theme.borrow().foreground_color.clone()
+    }
+
+    /// Return the color of the axes, which the view draws neutral reference marks with.
+    pub(crate) fn get_axis_color(&self) -> String {
+        self.theme.borrow().axis_color.clone()
+    }
+
+    /// Return the color of the text of the axes and the legend, which the view draws
+    /// the lines that connect labels to their data with.
+    pub(crate) fn get_text_color(&self) -> String {
+        self.theme.borrow().text_color.clone()
+    }
+
+    /// Return the font family of the labels of the view.
+    pub(crate) fn get_font_family(&self) -> String {
+        self.theme.borrow().font_family.clone()
+    }
+
+    /// Return the font size of the labels of the view.
+    pub(crate) fn get_label_font_size(&self) -> f32 {
+        self.theme.borrow().label_font_size
+    }
+
+    /// Return the classes and the color of the elements that represent the data of the given key.
+    pub(crate) fn get_key_style(&self, key: &str) -> KeyStyle {
+        self.theme.borrow().get_key_style(key)
+    }
+
+    /// Return whether the elements of the view are styled with classes.
+    pub(crate) fn is_styled_with_classes(&self) -> bool {
+        self.theme.borrow().is_styled_with_classes()
+    }
+}
+
+/// Convert a key or a label to a string that can be part of a class name, e.g. "North America"
+/// to "north-america". Characters other than letters and digits are replaced with dashes.
+pub(crate) fn class_slug(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use svg::node::element::Rectangle;
+
+    #[test]
+    fn views_take_the_palette_of_the_theme_unless_they_have_colors() {
+        let keys = vec![String::from("A"), String::from("B")];
+        let mut view_theme = ViewTheme::new();
+        assert_eq!(view_theme.get_key_color(&keys, "B"), "#ff7f0e");
+
+        view_theme.set_theme(&Theme::print());
+        assert_eq!(view_theme.get_key_color(&keys, "B"), "#737373");
+        assert_eq!(view_theme.get_foreground_color(), "#000000");
+
+        // The colors of a view are kept, even if they are colors of the default palette.
+        view_theme.set_colors(vec![Color::from_hex("#1f77b4")]);
+        assert_eq!(view_theme.get_key_color(&keys, "B"), "#1f77b4");
+    }
+
+    #[test]
+    fn class_slugs_contain_letters_digits_and_dashes() {
+        assert_eq!(class_slug("North America"), "north-america");
+        assert_eq!(class_slug("  Q1 / 2020 "), "q1-2020");
+        assert_eq!(class_slug("foo"), "foo");
+    }
+
+    #[test]
+    fn keys_with_the_same_slug_get_unique_classes() {
+        let theme = Theme::light().set_styling(StylingMode::Classes, &[("A/B", "#111"), ("A B", "#222"), ("A/B", "#abcdef"), ("?", "#444")]);
+        assert_eq!(theme.get_key_style("A/B").get_key_classes("bar-block"), "bar-block key-a-b");
+        assert_eq!(theme.get_key_style("A B").get_key_classes("bar-block"), "bar-block key-a-b-2");
+        assert_eq!(theme.get_key_style("A B").get_series_classes("line"), "line series-a-b-2");
+        assert_eq!(theme.get_key_style("?").get_key_classes("bar-block"), "bar-block key-2");
+        assert_eq!(theme.get_key_style("A/B").get_color(), Some("#111"));
+        assert_eq!(theme.get_key_style("").get_key_classes("bar-block"), "bar-block");
+
+        let stylesheet = theme.get_stylesheet();
+        assert!(stylesheet.contains(".key-a-b-2, .series-a-b-2 { fill: #222; }"));
+        assert!(stylesheet.contains(".series-a-b .line-path, .series-a-b .area-path { stroke: #111; }"));
+        assert!(!stylesheet.contains("#abcdef"));
+
+        // Inline styles do not depend on the stylesheet.
+        let theme = theme.set_styling(StylingMode::Inline, &[("A/B", "#111")]);
+        assert_eq!(theme.get_key_style("A/B").get_color(), None);
+        assert_eq!(theme.in_stylesheet("#777"), None);
+    }
+
+    #[test]
+    fn presentation_leaves_out_the_values_of_the_stylesheet() {
+        let mut rect = Rectangle::new();
+        Presentation::new()
+            .set("fill", "#1f77b4", Some("#1f77b4"))
+            .set("stroke-width", 1, Some("1px"))
+            .set("stroke", "#ff0000", Some("#000"))
+            .set("opacity", 0.5, None)
+            .assign_to(&mut rect);
+
+        // The style of the element takes precedence over the stylesheet.
+        assert_eq!(rect.to_string(), "<rect opacity=\"0.5\" style=\"stroke: #ff0000;\"/>");
+    }
+}
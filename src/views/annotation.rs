@@ -0,0 +1,238 @@
+use svg::node::Node;
+use svg::node::element::Group;
+use crate::components::annotation::{AnnotationStyle, ReferenceLine, ReferenceBand, TextAnnotation, TextAnnotationType};
+use crate::components::DatumRepresentation;
+use crate::chart::Orientation;
+use crate::Scale;
+use crate::scales::get_scale_id;
+use crate::views::View;
+use crate::components::legend::LegendEntry;
+use crate::text::FontMetrics;
+use crate::theme::{Theme, ViewTheme};
+
+/// An annotation of the plot area, positioned in the domains of the X and Y scales.
+enum Annotation<T, U> {
+    VerticalRule(T, AnnotationStyle),
+    HorizontalRule(U, AnnotationStyle),
+    VerticalSpan(T, T, AnnotationStyle),
+    HorizontalSpan(U, U, AnnotationStyle),
+    Text(T, U, TextAnnotationType, AnnotationStyle),
+}
+
+/// A View that annotates the plot area with rules (e.g. targets or averages) and shaded
+/// spans (e.g. incident windows or forecast regions) that cross the whole plot area,
+/// as well as with text, arrows and callouts at points of the plot area.
+/// Annotations are positioned in data coordinates through the view's scales when the
+/// view is rendered. Values of band scales are positioned at the middle of their band by
+/// rules and points, and spans cover the whole bands of their start and end values.
+pub struct AnnotationView<'a, T, U> {
+    annotations: Vec<Annotation<T, U>>,
+    x_scale: Option<&'a dyn Scale<T>>,
+    y_scale: Option<&'a dyn Scale<U>>,
+    z_index: isize,
+    font_metrics: FontMetrics,
+    theme: ViewTheme,
+}
+
+impl<'a, T, U> AnnotationView<'a, T, U> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        Self {
+            annotations: Vec::new(),
+            x_scale: None,
+            y_scale: None,
+            z_index: 0,
+            font_metrics: FontMetrics::new(),
+            theme: ViewTheme::new(),
+        }
+    }
+
+    /// Set the scale for the X dimension.
+    pub fn set_x_scale(mut self, scale: &'a impl Scale<T>) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for the Y dimension.
+    pub fn set_y_scale(mut self, scale: &'a impl Scale<U>) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// Set the z-index of the view. Views with a lower z-index are drawn below views with a
+    /// higher one, and all other views have a z-index of 0. Set a negative z-index to draw
+    /// the annotations behind the data, e.g. for shaded spans.
+    pub fn set_z_index(mut self, z_index: isize) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Set the metrics of the font that the text of annotations is measured with when it is wrapped
+    /// or placed in a callout. Use the same metrics as the chart (see `Chart::set_font_metrics`).
+    pub fn set_font_metrics(mut self, font_metrics: FontMetrics) -> Self {
+        self.font_metrics = font_metrics;
+        self
+    }
+
+    /// Add a vertical rule at the given X value.
+    pub fn add_vertical_rule(mut self, x: T, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::VerticalRule(x, style));
+        self
+    }
+
+    /// Add a horizontal rule at the given Y value.
+    pub fn add_horizontal_rule(mut self, y: U, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::HorizontalRule(y, style));
+        self
+    }
+
+    /// Add a shaded span between the given X values.
+    pub fn add_vertical_span(mut self, x_start: T, x_end: T, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::VerticalSpan(x_start, x_end, style));
+        self
+    }
+
+    /// Add a shaded span between the given Y values.
+    pub fn add_horizontal_span(mut self, y_start: U, y_end: U, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::HorizontalSpan(y_start, y_end, style));
+        self
+    }
+
+    /// Add the label of the style as text at the given point.
+    pub fn add_text(mut self, x: T, y: U, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::Text(x, y, TextAnnotationType::Text, style));
+        self
+    }
+
+    /// Add the label of the style as text with an arrow that points to the given point,
+    /// e.g. to mark an event on a line chart.
+    pub fn add_arrow(mut self, x: T, y: U, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::Text(x, y, TextAnnotationType::Arrow, style));
+        self
+    }
+
+    /// Add the label of the style in a box that is connected to the given point by a line.
+    pub fn add_callout(mut self, x: T, y: U, style: AnnotationStyle) -> Self {
+        self.annotations.push(Annotation::Text(x, y, TextAnnotationType::Callout, style));
+        self
+    }
+}
+
+impl<'a, T, U> Default for AnnotationView<'a, T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the pixel positions of the edges of a value's band, which coincide for continuous scales.
+fn get_band_edges<V>(scale: &dyn Scale<V>, value: &V) -> (f32, f32) {
+    let start = scale.scale(value);
+    let bandwidth = scale.bandwidth().unwrap_or(0_f32);
+    match scale.is_range_reversed() {
+        true => (start, start - bandwidth),
+        false => (start, start + bandwidth),
+    }
+}
+
+/// Get the pixel position of a value, which is the middle of its band for band scales.
+fn get_position<V>(scale: &dyn Scale<V>, value: &V) -> f32 {
+    let (start, end) = get_band_edges(scale, value);
+    (start + end) / 2_f32
+}
+
+/// Get the pixel positions of the edges of a span between the given values.
+fn get_span_positions<V>(scale: &dyn Scale<V>, start: &V, end: &V) -> (f32, f32) {
+    let (start_0, start_1) = get_band_edges(scale, start);
+    let (end_0, end_1) = get_band_edges(scale, end);
+    let edges = [start_0, start_1, end_0, end_1];
+    (
+        edges.iter().cloned().fold(f32::INFINITY, f32::min),
+        edges.iter().cloned().fold(f32::NEG_INFINITY, f32::max),
+    )
+}
+
+impl<'a, T, U> View<'a> for AnnotationView<'a, T, U> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        let x_scale = match self.x_scale {
+            Some(scale) => scale,
+            None => return Err("Please provide a scale for the X dimension before rendering annotations".to_string()),
+        };
+        let y_scale = match self.y_scale {
+            Some(scale) => scale,
+            None => return Err("Please provide a scale for the Y dimension before rendering annotations".to_string()),
+        };
+
+        // Annotations cross the whole plot area, which is the extent of the ranges of the scales.
+        let x_extent = (x_scale.range_start(), x_scale.range_end());
+        let y_extent = (y_scale.range_start(), y_scale.range_end());
+
+        let mut group = Group::new()
+            .set("class", "annotations");
+        // The labels of annotations are displayed in the font family of the theme.
+        let font_family = self.theme.get_font_family();
+        let themed = |style: &AnnotationStyle| style.clone().set_font_family(font_family.clone());
+
+        for annotation in self.annotations.iter() {
+            let annotation_svg = match annotation {
+                Annotation::VerticalRule(x, style) => {
+                    ReferenceLine::new(Orientation::Vertical, get_position(x_scale, x), y_extent, themed(style)).to_svg()?
+                },
+                Annotation::HorizontalRule(y, style) => {
+                    ReferenceLine::new(Orientation::Horizontal, get_position(y_scale, y), x_extent, themed(style)).to_svg()?
+                },
+                Annotation::VerticalSpan(x_start, x_end, style) => {
+                    ReferenceBand::new(Orientation::Vertical, get_span_positions(x_scale, x_start, x_end), y_extent, themed(style)).to_svg()?
+                },
+                Annotation::HorizontalSpan(y_start, y_end, style) => {
+                    ReferenceBand::new(Orientation::Horizontal, get_span_positions(y_scale, y_start, y_end), x_extent, themed(style)).to_svg()?
+                },
+                Annotation::Text(x, y, annotation_type, style) => {
+                    let point = (get_position(x_scale, x), get_position(y_scale, y));
+                    TextAnnotation::new(*annotation_type, point, themed(style), self.font_metrics.clone()).to_svg()?
+                },
+            };
+            group.append(annotation_svg);
+        }
+
+        Ok(group)
+    }
+
+    /// Annotations carry their own labels and do not appear in the legend.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        Vec::new()
+    }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+
+    /// Return the z-index of the view.
+    fn get_z_index(&self) -> isize {
+        self.z_index
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScaleBand;
+
+    #[test]
+    fn spans_cover_the_bands_of_their_values() {
+        let scale = ScaleBand::new()
+            .set_domain(vec![String::from("A"), String::from("B"), String::from("C"), String::from("D")])
+            .set_range(vec![0, 400])
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+
+        assert_eq!(get_position(&scale, &String::from("B")), 150_f32);
+        assert_eq!(get_span_positions(&scale, &String::from("C"), &String::from("B")), (100_f32, 300_f32));
+    }
+}
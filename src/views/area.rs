@@ -1,22 +1,40 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use svg::node::Node;
 use svg::node::element::Group;
 use crate::components::scatter::{ScatterPoint, MarkerType, PointLabelPosition};
 use crate::colors::Color;
 use crate::Scale;
-use crate::views::datum::PointDatum;
+use crate::scales::get_scale_id;
+use crate::views::datum::{PointDatum, PointValue};
 use crate::views::View;
 use crate::components::DatumRepresentation;
 use std::fmt::Display;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
-use crate::components::area::AreaSeries;
+use crate::theme::{Theme, ViewTheme};
+use crate::components::area::{AreaSeries, AreaMode, RangeArea, StackOffset};
+use crate::components::curve::{Curve, GapMode};
+use crate::components::style::SeriesStyle;
 
-/// A View that represents data as a scatter plot.
+/// A View that represents data as areas. Data with multiple keys can be
+/// represented as overlaid areas, stacked areas or as ranges between lower and upper values.
 pub struct AreaSeriesView<'a, T: Display + Clone, U: Display + Clone> {
     labels_visible: bool,
     label_position: PointLabelPosition,
     marker_type: MarkerType,
-    entries: Vec<AreaSeries<T, U>>,
-    colors: Vec<Color>,
+    curve: Curve,
+    gap_mode: GapMode,
+    mode: AreaMode,
+    stack_offset: StackOffset,
+    fill_opacity: Option<f32>,
+    series_style: SeriesStyle,
+    key_series_styles: HashMap<String, SeriesStyle>,
+    applied_styles: HashMap<String, SeriesStyle>,
+    entries: Vec<PointValue<T, U>>,
+    stacked_values: Vec<f32>,
+    value_to_y: Option<fn(f32) -> U>,
+    keys: Vec<String>,
+    theme: ViewTheme,
     x_scale: Option<&'a dyn Scale<T>>,
     y_scale: Option<&'a dyn Scale<U>>,
     custom_data_label: String,
@@ -29,8 +47,19 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
             labels_visible: true,
             label_position: PointLabelPosition::NW,
             marker_type: MarkerType::Circle,
+            curve: Curve::Linear,
+            gap_mode: GapMode::Break,
+            mode: AreaMode::Overlay,
+            stack_offset: StackOffset::None,
+            fill_opacity: None,
+            series_style: SeriesStyle::new(),
+            key_series_styles: HashMap::new(),
+            applied_styles: HashMap::new(),
             entries: Vec::new(),
-            colors: Color::color_scheme_10(),
+            stacked_values: Vec::new(),
+            value_to_y: None,
+            keys: Vec::new(),
+            theme: ViewTheme::new(),
             x_scale: None,
             y_scale: None,
             custom_data_label: String::new(),
@@ -55,15 +84,72 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
         self
     }
 
-    /// Set the keys in case of a stacked bar chart.
+    /// Set the type of the markers that are displayed at the points of the areas.
     pub fn set_marker_type(mut self, marker_type: MarkerType) -> Self {
         self.marker_type = marker_type;
         self
     }
 
-    /// Set the color palette of the view.
+    /// Set the curve that connects the points of the area's boundary.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Set how missing values are treated. By default, the area is broken into
+    /// separate segments at missing values.
+    pub fn set_gap_mode(mut self, gap_mode: GapMode) -> Self {
+        self.gap_mode = gap_mode;
+        self
+    }
+
+    /// Set the keys of the data, which also determines the order in which the areas
+    /// are drawn and stacked. If not set, the keys are extracted from the data.
+    pub fn set_keys(mut self, keys: Vec<String>) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Set how the areas of multiple keys are combined. Points are only
+    /// displayed in the `Overlay` mode. Stacking needs numeric Y values, so
+    /// stacked areas are loaded with `load_stacked_data`.
+    pub fn set_mode(mut self, mode: AreaMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the baseline of stacked areas. This only applies in the `Stacked` mode.
+    /// Note that the `Expand`, `Silhouette` and `Wiggle` offsets change the range of
+    /// stacked values, so the domain of the Y scale should be set accordingly.
+    pub fn set_stack_offset(mut self, stack_offset: StackOffset) -> Self {
+        self.stack_offset = stack_offset;
+        self
+    }
+
+    /// Set the opacity (between 0 and 1) of the areas' fill. By default, stacked areas
+    /// and single areas are opaque, while overlaid areas of multiple keys are translucent.
+    pub fn set_fill_opacity(mut self, opacity: f32) -> Self {
+        self.fill_opacity = Some(opacity);
+        self
+    }
+
+    /// Set the style of the areas of all keys.
+    pub fn set_series_style(mut self, style: SeriesStyle) -> Self {
+        self.series_style = style;
+        self
+    }
+
+    /// Set the style of the area of a key. The properties set in it override the
+    /// ones set with `set_series_style`.
+    pub fn set_key_series_style(mut self, key: &str, style: SeriesStyle) -> Self {
+        self.key_series_styles.insert(key.to_string(), style);
+        self
+    }
+
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
     pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
-        self.colors = colors;
+        self.theme.set_colors(colors);
         self
     }
 
@@ -82,8 +168,19 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
         self
     }
 
-    /// Load and process a dataset of BarDatum points.
+    /// Load and process a dataset of PointDatum points.
     pub fn load_data(mut self, data: &Vec<impl PointDatum<T, U>>) -> Result<Self, String> {
+        if let AreaMode::Stacked = self.mode {
+            return Err("Stacked areas should be loaded with load_stacked_data".to_string());
+        }
+        self.prepare_data(data)?;
+
+        Ok(self)
+    }
+
+    /// Check the scales, assign a color to every key of the data and keep the data
+    /// to position the areas when the view is rendered.
+    fn prepare_data(&mut self, data: &[impl PointDatum<T, U>]) -> Result<(), String> {
         match self.x_scale {
             Some(_) => {},
             _ => return Err("Please provide a scale for the X dimension before loading data".to_string()),
@@ -93,28 +190,37 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
             _ => return Err("Please provide a scale for the Y dimension before loading data".to_string()),
         }
 
-        // Compute corresponding offsets to apply in case there is a non-zero bandwidth.
-        let y_bandwidth_offset = {
-            if self.y_scale.unwrap().is_range_reversed() {
-                -self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
-            } else {
-                self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
-            }
-        };
-        let x_bandwidth_offset = {
-            if self.x_scale.unwrap().is_range_reversed() {
-                -self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
-            } else {
-                self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
-            }
+        // If no keys were explicitly provided, extract the keys from the data.
+        if self.keys.is_empty() {
+            self.keys = Self::extract_keys(data);
+        }
+
+        // Overlaid areas of multiple keys are translucent by default.
+        let opacity = match (self.mode, self.keys.len() > 1) {
+            (AreaMode::Overlay, true) | (AreaMode::Range, true) => Some(self.fill_opacity.unwrap_or(0.5)),
+            (AreaMode::Overlay, false) => self.fill_opacity,
+            _ => Some(self.fill_opacity.unwrap_or(1_f32)),
         };
+        for key in self.keys.iter() {
+            self.applied_styles.insert(key.clone(), self.get_applied_style(key, opacity));
+        }
 
-        let mut points = data.iter().map(|datum| {
-            let scaled_x = self.x_scale.unwrap().scale(&datum.get_x());
-            let scaled_y = self.y_scale.unwrap().scale(&datum.get_y());
-            ScatterPoint::new(scaled_x + x_bandwidth_offset, scaled_y + y_bandwidth_offset, self.marker_type, 5, datum.get_x(), datum.get_y(), self.label_position, self.labels_visible, true, self.colors[0].as_hex())
-        }).collect::<Vec<ScatterPoint<T, U>>>();
+        self.entries = data.iter().map(|datum| PointValue::new(datum, None)).collect();
 
+        Ok(())
+    }
+
+    /// Create the areas of every key, positioned by the view's scales.
+    fn layout(&self) -> (Vec<RangeArea>, Vec<AreaSeries<&T, &U>>) {
+        match (self.mode, self.value_to_y) {
+            (AreaMode::Overlay, _) => (Vec::new(), self.overlaid_areas()),
+            (AreaMode::Stacked, Some(value_to_y)) => (self.stacked_areas(value_to_y), Vec::new()),
+            _ => (self.range_areas(), Vec::new()),
+        }
+    }
+
+    /// Create an area from the baseline for every key.
+    fn overlaid_areas(&self) -> Vec<AreaSeries<&T, &U>> {
         let y_origin = {
             if self.y_scale.unwrap().is_range_reversed() {
                 self.y_scale.unwrap().range_start()
@@ -122,12 +228,186 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
                 self.y_scale.unwrap().range_end()
             }
         };
-        let first = data.first().unwrap();
-        let last = data.last().unwrap();
-        points.push(ScatterPoint::new(self.x_scale.unwrap().scale(&last.get_x()) + x_bandwidth_offset, y_origin, self.marker_type, 5, data[0].get_x(), data[0].get_y(), self.label_position, false, false, "#fff".to_string()));
-        points.push(ScatterPoint::new(self.x_scale.unwrap().scale(&first.get_x()) + x_bandwidth_offset, y_origin, self.marker_type, 5, data[0].get_x(), data[0].get_y(), self.label_position, false, false, "#fff".to_string()));
+        let mut areas = Vec::new();
+
+        for key in self.keys.iter() {
+            let style = self.applied_styles.get(key).cloned().unwrap_or_default();
+            let marker_visible = style.get_marker_visibility().unwrap_or(true);
+            let marker_size = style.get_marker_size().unwrap_or(5_f32);
+            let key_data = self.entries.iter().filter(|datum| &datum.key == key).collect::<Vec<_>>();
+            let positions = key_data.iter()
+                .map(|datum| (self.scale_x(&datum.x), Some(self.scale_y(&datum.y)).filter(|y| datum.defined && y.is_finite())))
+                .collect::<Vec<(f32, Option<f32>)>>();
+            let color = self.theme.get_key_color(&self.keys, key);
+
+            // Interpolated values of missing data are not displayed as points.
+            let segments = self.gap_mode.segments(&positions).into_iter().map(|segment| {
+                segment.into_iter().map(|(i, x, y)| {
+                    let defined = positions[i].1.is_some();
+                    ScatterPoint::new(x, y, self.marker_type, marker_size, &key_data[i].x, &key_data[i].y, self.label_position, self.labels_visible && defined, defined && marker_visible, color.clone())
+                        .set_label_style(self.theme.get_foreground_color(), self.theme.get_font_family(), self.theme.get_label_font_size())
+                }).collect::<Vec<ScatterPoint<&T, &U>>>()
+            }).collect::<Vec<Vec<ScatterPoint<&T, &U>>>>();
+
+            areas.push(AreaSeries::new(segments, y_origin, color).set_curve(self.curve).set_style(style).set_key_style(self.theme.get_key_style(key)));
+        }
+
+        areas
+    }
+
+    /// Create an area between the lower and upper bounds of the data for every key.
+    fn range_areas(&self) -> Vec<RangeArea> {
+        let mut areas = Vec::new();
+
+        for key in self.keys.iter() {
+            let boundaries = self.entries.iter().filter(|datum| &datum.key == key).map(|datum| {
+                let bounds = datum.y_bounds.as_ref()
+                    .filter(|_| datum.defined)
+                    .map(|(lower, upper)| (self.scale_y(lower), self.scale_y(upper)));
+                (self.scale_x(&datum.x), bounds)
+            }).collect::<Vec<(f32, Option<(f32, f32)>)>>();
+            areas.extend(self.split_ranges(&boundaries, key));
+        }
+
+        areas
+    }
+
+    /// Stack the areas of the keys on top of each other. The data of the keys are
+    /// aligned by the positions of their X values, and missing values do not add to the stack.
+    fn stacked_areas(&self, value_to_y: fn(f32) -> U) -> Vec<RangeArea> {
+        // Collect the X positions in the order in which they first appear in the data,
+        // and sort them afterwards, as the keys can have different X values.
+        let mut x_values: Vec<f32> = Vec::new();
+        let mut x_indices: HashMap<u32, usize> = HashMap::new();
+        let mut values = vec![Vec::new(); self.keys.len()];
+        let key_indices = self.keys.iter().enumerate().map(|(i, key)| (key.clone(), i)).collect::<HashMap<String, usize>>();
+
+        for (datum, y) in self.entries.iter().zip(self.stacked_values.iter()) {
+            let key_index = match key_indices.get(&datum.key) {
+                Some(index) => *index,
+                None => continue,
+            };
+            let x = self.scale_x(&datum.x);
+            let x_index = *x_indices.entry(x.to_bits()).or_insert_with(|| {
+                x_values.push(x);
+                x_values.len() - 1
+            });
+            let series: &mut Vec<Option<f32>> = &mut values[key_index];
+            if series.len() <= x_index {
+                series.resize(x_index + 1, None);
+            }
+            series[x_index] = Some(*y).filter(|y| datum.defined && y.is_finite());
+        }
+
+        let mut order = (0..x_values.len()).collect::<Vec<usize>>();
+        order.sort_by(|a, b| x_values[*a].partial_cmp(&x_values[*b]).unwrap_or(Ordering::Equal));
+        let x_values = order.iter().map(|i| x_values[*i]).collect::<Vec<f32>>();
+        let values = values.iter()
+            .map(|series| order.iter().map(|i| series.get(*i).cloned().flatten()).collect())
+            .collect::<Vec<Vec<Option<f32>>>>();
+
+        let stacks = self.stack_offset.stack(&values.iter()
+            .map(|series| series.iter().map(|value| value.unwrap_or(0_f32)).collect())
+            .collect::<Vec<Vec<f32>>>());
+        let mut areas = Vec::new();
+
+        for ((key, series), stack) in self.keys.iter().zip(values.iter()).zip(stacks) {
+            let boundaries = stack.iter().enumerate().map(|(j, (lower, upper))| {
+                let defined = series.get(j).cloned().flatten().is_some();
+                (x_values[j], Some((self.scale_y(&value_to_y(*lower)), self.scale_y(&value_to_y(*upper)))).filter(|_| defined))
+            }).collect::<Vec<(f32, Option<(f32, f32)>)>>();
+            areas.extend(self.split_ranges(&boundaries, key));
+        }
+
+        areas
+    }
+
+    /// Split the lower and upper boundaries of a key's area at gaps and create the areas.
+    fn split_ranges(&self, boundaries: &[(f32, Option<(f32, f32)>)], key: &str) -> Vec<RangeArea> {
+        let upper = boundaries.iter().map(|(x, bounds)| (*x, bounds.map(|(_, upper)| upper).filter(|y| y.is_finite()))).collect::<Vec<(f32, Option<f32>)>>();
+        let lower = boundaries.iter().map(|(x, bounds)| (*x, bounds.map(|(lower, _)| lower).filter(|y| y.is_finite()))).collect::<Vec<(f32, Option<f32>)>>();
+        let color = self.theme.get_key_color(&self.keys, key);
+        let style = self.applied_styles.get(key).cloned().unwrap_or_default();
+        let opacity = style.get_fill_opacity().unwrap_or(1_f32);
+
+        self.gap_mode.segments(&upper).into_iter().zip(self.gap_mode.segments(&lower)).map(|(upper, lower)| {
+            let upper = upper.into_iter().map(|(_, x, y)| (x, y)).collect();
+            let lower = lower.into_iter().map(|(_, x, y)| (x, y)).collect();
+            RangeArea::new(upper, lower, color.clone(), opacity).set_curve(self.curve).set_style(style.clone()).set_key_style(self.theme.get_key_style(key))
+        }).collect()
+    }
+
+    /// Return the style of a key's area.
+    fn get_series_style(&self, key: &str) -> SeriesStyle {
+        match self.key_series_styles.get(key) {
+            Some(style) => self.series_style.merge(style),
+            None => self.series_style.clone(),
+        }
+    }
+
+    /// Return the style that a key's area is drawn with, which includes the
+    /// view's fill opacity unless the style overrides it.
+    fn get_applied_style(&self, key: &str, opacity: Option<f32>) -> SeriesStyle {
+        let style = self.get_series_style(key);
+        match (style.get_fill_opacity(), opacity) {
+            (None, Some(opacity)) => style.set_fill_opacity(opacity),
+            _ => style,
+        }
+    }
+
+    /// Return the position of an X value, in the middle of its band if there is one.
+    fn scale_x(&self, x: &T) -> f32 {
+        let x_scale = self.x_scale.unwrap();
+        let offset = match x_scale.is_range_reversed() {
+            true => -x_scale.bandwidth().unwrap() / 2_f32,
+            false => x_scale.bandwidth().unwrap() / 2_f32,
+        };
+        x_scale.scale(x) + offset
+    }
+
+    /// Return the position of a Y value, in the middle of its band if there is one.
+    fn scale_y(&self, y: &U) -> f32 {
+        let y_scale = self.y_scale.unwrap();
+        let offset = match y_scale.is_range_reversed() {
+            true => -y_scale.bandwidth().unwrap() / 2_f32,
+            false => y_scale.bandwidth().unwrap() / 2_f32,
+        };
+        y_scale.scale(y) + offset
+    }
 
-        self.entries.push(AreaSeries::new(points, self.colors[0].as_hex()));
+    /// Return the legend entry of a key, with a square that mirrors the style
+    /// that the key's area was drawn with.
+    fn legend_entry(&self, key: &str, label: String) -> LegendEntry {
+        let style = self.applied_styles.get(key).cloned().unwrap_or_default();
+        LegendEntry::new(LegendMarkerType::Square, self.theme.get_key_color(&self.keys, key), String::from("none"), label)
+            .set_key(key)
+            .set_series_style(style)
+    }
+
+    /// Extract the list of keys to use when stacking and coloring the areas.
+    fn extract_keys(data: &[impl PointDatum<T, U>]) -> Vec<String> {
+        let mut keys = Vec::new();
+        let mut map = HashMap::new();
+
+        for datum in data.iter() {
+            match map.insert(datum.get_key(), 0) {
+                Some(_) => {},
+                None => keys.push(datum.get_key()),
+            }
+        }
+
+        keys
+    }
+}
+
+impl<'a, T: Display + Clone, U: Display + Clone + Into<f32> + From<f32>> AreaSeriesView<'a, T, U> {
+    /// Load and process a dataset of PointDatum points, stacking the areas of
+    /// the keys on top of each other.
+    pub fn load_stacked_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, String> {
+        self.mode = AreaMode::Stacked;
+        self.prepare_data(data)?;
+        self.stacked_values = data.iter().map(|datum| datum.get_y().into()).collect();
+        self.value_to_y = Some(U::from);
 
         Ok(self)
     }
@@ -138,7 +418,13 @@ impl<'a, T: Display + Clone, U: Display + Clone> View<'a> for AreaSeriesView<'a,
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new();
 
-        for entry in self.entries.iter() {
+        let (ranges, areas) = self.layout();
+
+        for range in ranges.iter() {
+            group.append(range.to_svg()?);
+        }
+
+        for entry in areas.iter() {
             let child_svg = entry.to_svg()?;
             group.append(child_svg);
         }
@@ -150,11 +436,111 @@ impl<'a, T: Display + Clone, U: Display + Clone> View<'a> for AreaSeriesView<'a,
     fn get_legend_entries(&self) -> Vec<LegendEntry> {
         let mut entries = Vec::new();
 
-        // Area series currently does not support multiple keys per dataset,
-        // hence when displaying a legend, it will display the custom data label
-        // as the legend label.
-        entries.push(LegendEntry::new(LegendMarkerType::Square, self.colors[0].as_hex(), String::from("none"), self.custom_data_label.clone()));
+        // If there is a single key and it is an empty string (meaning
+        // the dataset consists only of X and Y dimension values), return
+        // the custom data label.
+        if self.keys.len() == 1 && self.keys[0].is_empty() {
+            entries.push(self.legend_entry(&self.keys[0], self.custom_data_label.clone()));
+        } else {
+            for key in self.keys.iter() {
+                entries.push(self.legend_entry(key, key.clone()));
+            }
+        }
 
         entries
     }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleBand, ScaleLinear};
+
+    struct Level(f32, &'static str);
+
+    impl PointDatum<f32, String> for Level {
+        fn get_x(&self) -> f32 {
+            self.0
+        }
+
+        fn get_y(&self) -> String {
+            self.1.to_string()
+        }
+
+        fn get_key(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn overlaid_areas_accept_a_band_y_scale() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![0, 100]);
+        let y = ScaleBand::new().set_domain(vec![String::from("low"), String::from("high")]).set_range(vec![100, 0]);
+        let data = vec![Level(0_f32, "low"), Level(1_f32, "high")];
+        let view = AreaSeriesView::new().set_x_scale(&x).set_y_scale(&y).load_data(&data).unwrap();
+
+        assert_eq!(view.layout().1.len(), 1);
+        assert!(AreaSeriesView::new().set_x_scale(&x).set_y_scale(&y).set_mode(AreaMode::Stacked).load_data(&data).is_err());
+    }
+
+    #[test]
+    fn stacked_areas_are_aligned_by_x_position() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        // The X values of the keys differ as strings but share their positions.
+        let data = vec![(0_f32, 2_f32, "A"), (1_f32, 2_f32, "A"), (-0_f32, 3_f32, "B"), (1_f32, 3_f32, "B")];
+        let view = AreaSeriesView::new().set_x_scale(&x).set_y_scale(&y).load_stacked_data(&data).unwrap();
+        let ranges = view.layout().0;
+        let svg = ranges[1].to_svg().unwrap().to_string();
+
+        assert_eq!(ranges.len(), 2);
+        assert!(svg.contains("M0,50 L100,50 L100,80 L0,80"));
+    }
+
+    #[test]
+    fn stacked_areas_are_drawn_in_the_order_of_their_x_positions() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 2_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        // The keys have different X values, and the X values of B are not in order.
+        let data = vec![(0_f32, 2_f32, "A"), (2_f32, 2_f32, "A"), (2_f32, 3_f32, "B"), (1_f32, 3_f32, "B")];
+        let view = AreaSeriesView::new().set_x_scale(&x).set_y_scale(&y).load_stacked_data(&data).unwrap();
+        let ranges = view.layout().0;
+        let svg = ranges.iter().map(|range| range.to_svg().unwrap().to_string()).collect::<Vec<String>>().join("");
+
+        // B is stacked on A where both have values, and A does not add to the stack where it has none.
+        assert!(svg.contains("M50,70 L100,50 L100,80 L50,100"));
+        assert!(svg.contains("M0,80 L0,100"));
+    }
+
+    #[test]
+    fn legend_entries_mirror_the_style_of_the_areas() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        let data = vec![(0_f32, 2_f32, "A"), (1_f32, 2_f32, "A"), (0_f32, 3_f32, "B"), (1_f32, 3_f32, "B")];
+        let view = AreaSeriesView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_key_series_style("B", SeriesStyle::new().set_fill_opacity(0.8).set_stroke_width(2_f32))
+            .load_data(&data).unwrap();
+        let theme = crate::Theme::light();
+        let font_metrics = crate::text::FontMetrics::new();
+        let legend = view.get_legend_entries().iter()
+            .map(|entry| entry.to_svg(&font_metrics, &theme).unwrap().to_string())
+            .collect::<Vec<String>>();
+
+        // Overlaid areas of multiple keys are translucent by default.
+        assert!(legend[0].contains("fill-opacity=\"0.5\"") && !legend[0].contains("stroke-width"));
+        assert!(legend[1].contains("fill-opacity=\"0.8\"") && legend[1].contains("stroke-width=\"2\""));
+    }
 }
+
@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+use svg::node::Node;
+use svg::node::element::Group;
+use crate::components::box_plot::{BoxPlot, BoxStatistics, WhiskerExtent};
+use crate::colors::Color;
+use crate::{Scale, BarDatum};
+use crate::scales::{ScaleType, get_scale_id};
+use crate::components::DatumRepresentation;
+use crate::views::View;
+use crate::chart::Orientation;
+use crate::components::legend::{LegendEntry, LegendMarkerType};
+use crate::theme::{Theme, ViewTheme};
+use crate::stats;
+
+/// The statistics of the samples of a category and key, which are positioned
+/// by the view's scales when the view is rendered.
+struct BoxSummary {
+    category: String,
+    key_index: usize,
+    quartiles: (f32, f32, f32),
+    whiskers: (f32, f32),
+    outliers: Vec<f32>,
+    mean: Option<f32>,
+}
+
+/// A View that summarizes the distribution of samples of each category as a box plot.
+/// Every `BarDatum` entry of the dataset represents a single sample, where the category
+/// and the key are used to group samples and the value is the sample itself.
+pub struct BoxPlotView<'a> {
+    orientation: Orientation,
+    whisker_extent: WhiskerExtent,
+    mean_visible: bool,
+    entries: Vec<BoxSummary>,
+    keys: Vec<String>,
+    theme: ViewTheme,
+    category_scale: Option<&'a dyn Scale<String>>,
+    value_scale: Option<&'a dyn Scale<f32>>,
+    custom_data_label: String,
+}
+
+impl<'a> BoxPlotView<'a> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        Self {
+            orientation: Orientation::Vertical,
+            whisker_extent: WhiskerExtent::InterquartileRange(1.5),
+            mean_visible: false,
+            entries: Vec::new(),
+            keys: Vec::new(),
+            theme: ViewTheme::new(),
+            category_scale: None,
+            value_scale: None,
+            custom_data_label: String::new(),
+        }
+    }
+
+    /// Set the orientation of the boxes. A vertical orientation places
+    /// categories along the X axis and values along the Y axis.
+    pub fn set_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the scale for the category dimension.
+    pub fn set_category_scale(mut self, scale: &'a impl Scale<String>) -> Self {
+        self.category_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for the value dimension.
+    pub fn set_value_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        self.value_scale = Some(scale);
+        self
+    }
+
+    /// Set the keys in case there are several boxes per category.
+    pub fn set_keys(mut self, keys: Vec<String>) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Set how far the whiskers should extend.
+    pub fn set_whisker_extent(mut self, whisker_extent: WhiskerExtent) -> Self {
+        self.whisker_extent = whisker_extent;
+        self
+    }
+
+    /// Set the visibility of the mean marker.
+    pub fn set_mean_visibility(mut self, mean_visibility: bool) -> Self {
+        self.mean_visible = mean_visibility;
+        self
+    }
+
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
+    pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
+        self.theme.set_colors(colors);
+        self
+    }
+
+    /// Set custom label for the dataset.
+    /// This will work when the dataset represents only a single
+    /// type of data (i.e. there are no different "keys" by which to
+    /// differentiate data), otherwise, this will have no effect.
+    pub fn set_custom_data_label(mut self, label: String) -> Self {
+        self.custom_data_label = label;
+        self
+    }
+
+    /// Load and process a dataset of BarDatum samples.
+    pub fn load_data(mut self, data: &[impl BarDatum]) -> Result<Self, String> {
+        match self.category_scale {
+            Some(scale) if scale.get_type() == ScaleType::Band => {},
+            _ => return Err("The category scale should be a Band scale.".to_string()),
+        }
+        match self.value_scale {
+            Some(scale) if scale.get_type() == ScaleType::Linear => {},
+            _ => return Err("The value scale should be a Linear scale.".to_string()),
+        }
+        if let WhiskerExtent::InterquartileRange(multiplier) = self.whisker_extent {
+            if multiplier.is_nan() || multiplier < 0_f32 {
+                return Err("The interquartile range multiplier should be a non-negative number.".to_string());
+            }
+        }
+
+        // If no keys were explicitly provided, extract the keys from the data.
+        if self.keys.is_empty() {
+            self.keys = Self::extract_keys(data);
+        }
+
+        // Group the samples by category and key, keeping the categories in the order
+        // in which they first appear in the data.
+        let mut categories: Vec<String> = Vec::new();
+        let mut samples: HashMap<(String, String), Vec<f32>> = HashMap::new();
+        // Samples that are not a number can't be placed on the value scale and are skipped.
+        for datum in data.iter().filter(|datum| !datum.get_value().is_nan()) {
+            if !categories.contains(&datum.get_category()) {
+                categories.push(datum.get_category());
+            }
+            samples.entry((datum.get_category(), datum.get_key())).or_default().push(datum.get_value());
+        }
+
+        for category in categories {
+            for (i, key) in self.keys.iter().enumerate() {
+                let mut values = match samples.remove(&(category.clone(), key.clone())) {
+                    Some(values) if !values.is_empty() => values,
+                    _ => continue,
+                };
+                values.sort_by(|a, b| a.total_cmp(b));
+
+                let q1 = stats::quantile(&values, 0.25);
+                let median = stats::quantile(&values, 0.5);
+                let q3 = stats::quantile(&values, 0.75);
+
+                let (whisker_low, whisker_high, outliers) = match self.whisker_extent {
+                    WhiskerExtent::InterquartileRange(multiplier) => {
+                        let iqr = q3 - q1;
+                        let (low_fence, high_fence) = (q1 - multiplier * iqr, q3 + multiplier * iqr);
+                        let inliers = values.iter().filter(|value| **value >= low_fence && **value <= high_fence).collect::<Vec<&f32>>();
+                        let outliers = values.iter().filter(|value| **value < low_fence || **value > high_fence).cloned().collect::<Vec<f32>>();
+                        // With a small multiplier the fences may not enclose any sample,
+                        // in which case the whiskers collapse onto the box.
+                        match (inliers.first(), inliers.last()) {
+                            (Some(low), Some(high)) => (**low, **high, outliers),
+                            _ => (q1, q3, outliers),
+                        }
+                    },
+                    WhiskerExtent::MinMax => (values[0], values[values.len() - 1], Vec::new()),
+                };
+
+                let mean = match self.mean_visible {
+                    true => Some(stats::mean(&values)),
+                    false => None,
+                };
+
+                self.entries.push(BoxSummary {
+                    category: category.clone(),
+                    key_index: i,
+                    quartiles: (q1, median, q3),
+                    whiskers: (whisker_low, whisker_high),
+                    outliers,
+                    mean,
+                });
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Create the boxes of the data, positioned by the view's scales.
+    fn layout(&self) -> Vec<BoxPlot> {
+        let category_scale = self.category_scale.unwrap();
+        let value_scale = self.value_scale.unwrap();
+        // Boxes of different keys are placed side by side within a category's band.
+        let slot_width = category_scale.bandwidth().unwrap() / self.keys.len() as f32;
+        let box_width = slot_width * 0.8;
+
+        self.entries.iter().map(|summary| {
+            let offset = category_scale.scale(&summary.category) + slot_width * summary.key_index as f32 + (slot_width - box_width) / 2_f32;
+            let (q1, median, q3) = summary.quartiles;
+            let (whisker_low, whisker_high) = summary.whiskers;
+
+            let statistics = BoxStatistics {
+                quartiles: (value_scale.scale(&q1), value_scale.scale(&median), value_scale.scale(&q3)),
+                whiskers: (value_scale.scale(&whisker_low), value_scale.scale(&whisker_high)),
+                outliers: summary.outliers.iter().map(|value| value_scale.scale(value)).collect(),
+                mean: summary.mean.map(|mean| value_scale.scale(&mean)),
+            };
+
+            BoxPlot::new(self.orientation, offset, box_width, statistics, self.theme.get_color(summary.key_index))
+                .set_outline_color(self.theme.get_foreground_color())
+                .set_key_style(self.theme.get_key_style(&self.keys[summary.key_index]))
+        }).collect()
+    }
+
+    /// Extract the list of keys to use when grouping and coloring the boxes.
+    fn extract_keys(data: &[impl BarDatum]) -> Vec<String> {
+        let mut keys = Vec::new();
+        let mut map = HashMap::new();
+
+        for datum in data.iter() {
+            match map.insert(datum.get_key(), 0) {
+                Some(_) => {},
+                None => keys.push(datum.get_key()),
+            }
+        }
+
+        keys
+    }
+}
+
+impl<'a> Default for BoxPlotView<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> View<'a> for BoxPlotView<'a> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new();
+
+        for entry in self.layout().iter() {
+            let child_svg = entry.to_svg()?;
+            group.append(child_svg);
+        }
+
+        Ok(group)
+    }
+
+    /// Return the legend entries that this view represents.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        let mut entries = Vec::new();
+
+        // If there is a single key and it is an empty string (meaning
+        // the dataset consists only of categories and values), return
+        // the custom data label.
+        if self.keys.len() == 1 && self.keys[0].is_empty() {
+            entries.push(LegendEntry::new(LegendMarkerType::Square, self.theme.get_color(0), String::from("none"), self.custom_data_label.clone()));
+        } else {
+            for key in self.keys.iter() {
+                entries.push(LegendEntry::new(LegendMarkerType::Square, self.theme.get_key_color(&self.keys, key), String::from("none"), key.clone()).set_key(key));
+            }
+        }
+
+        entries
+    }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        let category_scale = self.category_scale.map(get_scale_id);
+        let value_scale = self.value_scale.map(get_scale_id);
+        match self.orientation {
+            Orientation::Vertical => (category_scale, value_scale),
+            Orientation::Horizontal => (value_scale, category_scale),
+        }
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleBand, ScaleLinear};
+
+    #[test]
+    fn negative_interquartile_range_multiplier_is_rejected() {
+        let x = ScaleBand::new().set_domain(vec![String::from("A")]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        let view = BoxPlotView::new()
+            .set_category_scale(&x)
+            .set_value_scale(&y)
+            .set_whisker_extent(WhiskerExtent::InterquartileRange(-1_f32))
+            .load_data(&vec![("A", 1_f32), ("A", 2_f32)]);
+
+        assert!(view.is_err());
+    }
+
+    #[test]
+    fn boxes_tolerate_nan_samples_and_fences_without_inliers() {
+        let x = ScaleBand::new().set_domain(vec![String::from("A")]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        let view = BoxPlotView::new()
+            .set_category_scale(&x)
+            .set_value_scale(&y)
+            .set_whisker_extent(WhiskerExtent::InterquartileRange(0_f32))
+            .load_data(&vec![("A", 0_f32), ("A", f32::NAN), ("A", 10_f32)])
+            .unwrap();
+
+        assert_eq!(view.entries.len(), 1);
+        assert!(view.to_svg().is_ok());
+    }
+}
@@ -0,0 +1,366 @@
+use svg::node::Node;
+use svg::node::element::Group;
+use crate::components::candle::{Candle, CandlePrices, CandleStyle, VolumeBar};
+use crate::colors::Color;
+use crate::{Scale, OhlcDatum};
+use crate::scales::{ScaleType, get_scale_id};
+use crate::components::DatumRepresentation;
+use crate::views::View;
+use crate::components::legend::{LegendEntry, LegendMarkerType};
+
+/// The prices and volume of a period, which are positioned by the view's
+/// scales when the view is rendered.
+struct OhlcValue<T> {
+    x: T,
+    open: f32,
+    high: f32,
+    low: f32,
+    close: f32,
+    volume: Option<f32>,
+}
+
+/// A View that represents price data as candlesticks.
+///
+/// The X scale can either be a Band scale or a Linear scale. With a Band scale every
+/// period gets its own band, so periods without data (e.g. non-trading days) are simply
+/// left out of the scale's domain and no gaps appear between candles. With a Linear scale
+/// periods are placed according to their value, so such gaps remain visible unless gap
+/// skipping is enabled, and the width of the candles is derived from the smallest distance
+/// between two consecutive periods.
+pub struct CandlestickView<'a, T> {
+    style: CandleStyle,
+    up_color: Color,
+    down_color: Color,
+    up_label: String,
+    down_label: String,
+    gaps_skipped: bool,
+    entries: Vec<OhlcValue<T>>,
+    x_scale: Option<&'a dyn Scale<T>>,
+    y_scale: Option<&'a dyn Scale<f32>>,
+    volume_scale: Option<&'a dyn Scale<f32>>,
+}
+
+impl<'a, T> CandlestickView<'a, T> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        Self {
+            style: CandleStyle::Candlestick,
+            up_color: Color::from_hex("#26a69a"),
+            down_color: Color::from_hex("#ef5350"),
+            up_label: String::from("Increase"),
+            down_label: String::from("Decrease"),
+            gaps_skipped: false,
+            entries: Vec::new(),
+            x_scale: None,
+            y_scale: None,
+            volume_scale: None,
+        }
+    }
+
+    /// Set the scale for the X dimension.
+    pub fn set_x_scale(mut self, scale: &'a impl Scale<T>) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for the Y (price) dimension.
+    pub fn set_y_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for the volume layer. Volume bars are drawn upwards from zero,
+    /// so setting the scale's range to a strip at the bottom of the chart
+    /// places the volume bars underneath the candles. If not set, no volume is displayed.
+    pub fn set_volume_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        self.volume_scale = Some(scale);
+        self
+    }
+
+    /// Set the color of periods in which the price closed higher than it opened.
+    pub fn set_up_color(mut self, color: Color) -> Self {
+        self.up_color = color;
+        self
+    }
+
+    /// Set the color of periods in which the price closed lower than it opened.
+    pub fn set_down_color(mut self, color: Color) -> Self {
+        self.down_color = color;
+        self
+    }
+
+    /// Set the legend label of periods in which the price closed higher than it opened.
+    pub fn set_up_label(mut self, label: String) -> Self {
+        self.up_label = label;
+        self
+    }
+
+    /// Set the legend label of periods in which the price closed lower than it opened.
+    pub fn set_down_label(mut self, label: String) -> Self {
+        self.down_label = label;
+        self
+    }
+
+    /// Set whether gaps between periods (e.g. non-trading days) should be skipped on a
+    /// Linear X scale. When skipped, the periods are spaced evenly between the first and the
+    /// last one, in the order of their X values, so the axis ticks only mark the overall extent.
+    /// This has no effect on a Band scale, where periods without data are left out of the domain.
+    pub fn set_gap_skipping(mut self, gaps_skipped: bool) -> Self {
+        self.gaps_skipped = gaps_skipped;
+        self
+    }
+
+    /// Load and process a dataset of OhlcDatum points.
+    pub fn load_data(mut self, data: &[impl OhlcDatum<T>]) -> Result<Self, String> {
+        match self.x_scale {
+            Some(scale) if scale.get_type() == ScaleType::Band || scale.get_type() == ScaleType::Linear => {},
+            _ => return Err("The X axis scale should be a Band or a Linear scale.".to_string()),
+        }
+        match self.y_scale {
+            Some(scale) if scale.get_type() == ScaleType::Linear => {},
+            _ => return Err("The Y axis scale should be a Linear scale.".to_string()),
+        }
+
+        self.entries = data.iter().map(|datum| OhlcValue {
+            x: datum.get_x(),
+            open: datum.get_open(),
+            high: datum.get_high(),
+            low: datum.get_low(),
+            close: datum.get_close(),
+            volume: datum.get_volume(),
+        }).collect();
+
+        Ok(self)
+    }
+
+    /// Create the candles and volume bars of the data, positioned by the view's scales.
+    fn layout(&self) -> (Vec<Candle>, Vec<VolumeBar>) {
+        let x_scale = self.x_scale.unwrap();
+        let y_scale = self.y_scale.unwrap();
+        let mut candles = Vec::new();
+        let mut volume_bars = Vec::new();
+
+        let mut positions = self.entries.iter()
+            .map(|entry| x_scale.scale(&entry.x) + x_scale.bandwidth().unwrap() / 2_f32)
+            .collect::<Vec<f32>>();
+        if self.gaps_skipped && x_scale.get_type() == ScaleType::Linear {
+            positions = Self::evenly_spaced(&positions);
+        }
+        let width = match x_scale.get_type() {
+            ScaleType::Band => x_scale.bandwidth().unwrap(),
+            _ => Self::min_spacing(&positions) * 0.7,
+        };
+
+        for (entry, x) in self.entries.iter().zip(positions) {
+            let color = match entry.close >= entry.open {
+                true => self.up_color.as_hex(),
+                false => self.down_color.as_hex(),
+            };
+
+            if let (Some(volume_scale), Some(volume)) = (self.volume_scale, entry.volume) {
+                let base = volume_scale.scale(&0_f32);
+                volume_bars.push(VolumeBar::new(x, width, base, volume_scale.scale(&volume), color.clone()));
+            }
+
+            let prices = CandlePrices {
+                open: y_scale.scale(&entry.open),
+                high: y_scale.scale(&entry.high),
+                low: y_scale.scale(&entry.low),
+                close: y_scale.scale(&entry.close),
+            };
+            candles.push(Candle::new(self.style, x, width, prices, color));
+        }
+
+        (candles, volume_bars)
+    }
+
+    /// Return the positions spread evenly between the smallest and the largest one,
+    /// keeping the order of the original positions.
+    fn evenly_spaced(positions: &[f32]) -> Vec<f32> {
+        let mut order = (0..positions.len()).collect::<Vec<usize>>();
+        order.sort_by(|a, b| positions[*a].total_cmp(&positions[*b]));
+
+        let (first, last) = match (order.first(), order.last()) {
+            (Some(first), Some(last)) if order.len() > 1 => (positions[*first], positions[*last]),
+            _ => return positions.to_vec(),
+        };
+        let step = (last - first) / (order.len() - 1) as f32;
+
+        let mut spaced = vec![0_f32; positions.len()];
+        for (rank, index) in order.into_iter().enumerate() {
+            spaced[index] = first + step * rank as f32;
+        }
+
+        spaced
+    }
+
+    /// Return the smallest distance between two consecutive positions.
+    fn min_spacing(positions: &[f32]) -> f32 {
+        let mut sorted = positions.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let spacing = sorted.windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|distance| *distance > 0_f32)
+            .fold(f32::INFINITY, f32::min);
+
+        match spacing.is_finite() {
+            true => spacing,
+            false => 10_f32,
+        }
+    }
+}
+
+impl<'a, T> Default for CandlestickView<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> View<'a> for CandlestickView<'a, T> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new();
+        let (candles, volume_bars) = self.layout();
+
+        for volume_bar in volume_bars.iter() {
+            group.append(volume_bar.to_svg()?);
+        }
+
+        for entry in candles.iter() {
+            let child_svg = entry.to_svg()?;
+            group.append(child_svg);
+        }
+
+        Ok(group)
+    }
+
+    /// Return the legend entries that this view represents.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        vec![
+            LegendEntry::new(LegendMarkerType::Square, self.up_color.as_hex(), String::from("none"), self.up_label.clone()),
+            LegendEntry::new(LegendMarkerType::Square, self.down_color.as_hex(), String::from("none"), self.down_label.clone()),
+        ]
+    }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+}
+
+/// A View that represents price data as OHLC bars, where a vertical line spans the
+/// high and low prices, and ticks on the left and right mark the open and close prices.
+///
+/// Apart from the way periods are drawn, it behaves exactly like the [CandlestickView].
+pub struct OhlcView<'a, T> {
+    view: CandlestickView<'a, T>,
+}
+
+impl<'a, T> OhlcView<'a, T> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        let mut view = CandlestickView::new();
+        view.style = CandleStyle::Ohlc;
+
+        Self { view }
+    }
+
+    /// Set the scale for the X dimension.
+    pub fn set_x_scale(mut self, scale: &'a impl Scale<T>) -> Self {
+        self.view = self.view.set_x_scale(scale);
+        self
+    }
+
+    /// Set the scale for the Y (price) dimension.
+    pub fn set_y_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        self.view = self.view.set_y_scale(scale);
+        self
+    }
+
+    /// Set the scale for the volume layer. If not set, no volume is displayed.
+    pub fn set_volume_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        self.view = self.view.set_volume_scale(scale);
+        self
+    }
+
+    /// Set the color of periods in which the price closed higher than it opened.
+    pub fn set_up_color(mut self, color: Color) -> Self {
+        self.view = self.view.set_up_color(color);
+        self
+    }
+
+    /// Set the color of periods in which the price closed lower than it opened.
+    pub fn set_down_color(mut self, color: Color) -> Self {
+        self.view = self.view.set_down_color(color);
+        self
+    }
+
+    /// Set the legend label of periods in which the price closed higher than it opened.
+    pub fn set_up_label(mut self, label: String) -> Self {
+        self.view = self.view.set_up_label(label);
+        self
+    }
+
+    /// Set the legend label of periods in which the price closed lower than it opened.
+    pub fn set_down_label(mut self, label: String) -> Self {
+        self.view = self.view.set_down_label(label);
+        self
+    }
+
+    /// Set whether gaps between periods (e.g. non-trading days) should be skipped on a Linear X scale.
+    pub fn set_gap_skipping(mut self, gaps_skipped: bool) -> Self {
+        self.view = self.view.set_gap_skipping(gaps_skipped);
+        self
+    }
+
+    /// Load and process a dataset of OhlcDatum points.
+    pub fn load_data(mut self, data: &[impl OhlcDatum<T>]) -> Result<Self, String> {
+        self.view = self.view.load_data(data)?;
+        Ok(self)
+    }
+}
+
+impl<'a, T> Default for OhlcView<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> View<'a> for OhlcView<'a, T> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        self.view.to_svg()
+    }
+
+    /// Return the legend entries that this view represents.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        self.view.get_legend_entries()
+    }
+
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        self.view.get_scale_ids()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipped_gaps_space_periods_evenly_in_order() {
+        let positions = CandlestickView::<f32>::evenly_spaced(&[0_f32, 30_f32, 10_f32, 40_f32]);
+        assert_eq!(positions, vec![0_f32, 80_f32 / 3_f32, 40_f32 / 3_f32, 40_f32]);
+        assert_eq!(CandlestickView::<f32>::min_spacing(&[0_f32, f32::NAN, 10_f32]), 10_f32);
+    }
+
+    #[test]
+    fn legend_labels_are_configurable() {
+        let view = OhlcView::<f32>::new()
+            .set_up_label(String::from("Gain"))
+            .set_down_label(String::from("Loss"));
+        let labels = view.get_legend_entries().iter().map(|entry| entry.get_label().to_string()).collect::<Vec<String>>();
+
+        assert_eq!(labels, vec![String::from("Gain"), String::from("Loss")]);
+    }
+}
@@ -1,3 +1,6 @@
+use std::ops::{Add, Sub};
+use crate::components::style::DatumStyle;
+
 /// A trait that defines interaction with a data point used in a bar chart.
 /// This provides greater flexibility in using different data sources as one
 /// can simply implement this trait and be able to use that data in a bar chart.
@@ -12,6 +15,13 @@ pub trait BarDatum {
     /// (just return an empty string), but is required in a stacked bar chart
     /// as the stacked entries are differentiated by the key.
     fn get_key(&self) -> String;
+
+    /// Return the lower and upper bounds of the value (e.g. a confidence interval),
+    /// which are displayed as error bars. For a symmetric error, return the result of
+    /// `symmetric_bounds(value, error)`. Return `None` (the default) to display no error bar.
+    fn get_bounds(&self) -> Option<(f32, f32)> {
+        None
+    }
 }
 
 /// A trait that defines interaction with a data point used in a scatter/line plots.
@@ -26,6 +36,113 @@ pub trait PointDatum<T, U> {
     /// only a single type of data (just return an empty string), but is required
     /// in a chart that represents multiple categories of points.
     fn get_key(&self) -> String;
+
+    /// Return the lower and upper bounds of the Y value (e.g. a confidence interval),
+    /// which are displayed as error bars in a scatter plot or as a shaded band around
+    /// a line. For a symmetric error, return the result of `symmetric_bounds(y, error)`.
+    /// Return `None` (the default) to display no uncertainty.
+    fn get_y_bounds(&self) -> Option<(U, U)> {
+        None
+    }
+
+    /// Return the value that is encoded by the size of the point's marker
+    /// (e.g. in a bubble chart). Return `None` (the default) to use the default size.
+    fn get_size(&self) -> Option<f32> {
+        None
+    }
+
+    /// Return the value that is encoded by the color of the point's marker when the view
+    /// has a color scale. Return `None` (the default) to use the color of the datum's key.
+    fn get_color_value(&self) -> Option<f32> {
+        None
+    }
+
+    /// Return whether the datum has a value. Missing data are not drawn as markers, and
+    /// lines and areas treat them according to their gap mode. Data whose X or Y value is
+    /// NaN are treated as missing as well.
+    fn is_defined(&self) -> bool {
+        true
+    }
+}
+
+/// Return the lower and upper bounds of a value with a symmetric error (e.g. a standard
+/// error), to be returned from `BarDatum::get_bounds` or `PointDatum::get_y_bounds`.
+pub fn symmetric_bounds<U: Add<Output = U> + Sub<Output = U> + Copy>(value: U, error: U) -> (U, U) {
+    (value - error, value + error)
+}
+
+/// A trait that defines interaction with a data point used in a heatmap.
+pub trait HeatmapDatum {
+    /// Return the category of the datum along the X axis.
+    fn get_x(&self) -> String;
+
+    /// Return the category of the datum along the Y axis.
+    fn get_y(&self) -> String;
+
+    /// Return the value of the datum.
+    fn get_value(&self) -> f32;
+}
+
+/// A trait that defines interaction with a data point used in a financial (candlestick or OHLC) chart.
+pub trait OhlcDatum<T> {
+    /// Return the X value (e.g. the date) of the datum.
+    fn get_x(&self) -> T;
+
+    /// Return the opening price.
+    fn get_open(&self) -> f32;
+
+    /// Return the highest price.
+    fn get_high(&self) -> f32;
+
+    /// Return the lowest price.
+    fn get_low(&self) -> f32;
+
+    /// Return the closing price.
+    fn get_close(&self) -> f32;
+
+    /// Return the traded volume. This is optional and only
+    /// needed when the view displays a volume layer.
+    fn get_volume(&self) -> Option<f32> {
+        None
+    }
+}
+
+/// A bar datum as loaded into a view, which keeps it to position the bar blocks
+/// from the view's scales when it is rendered.
+pub(crate) struct BarValue {
+    pub(crate) key: String,
+    pub(crate) value: f32,
+    pub(crate) bounds: Option<(f32, f32)>,
+    pub(crate) style: Option<DatumStyle>,
+}
+
+/// A point datum as loaded into a view, which keeps it to position the point
+/// from the view's scales when it is rendered.
+pub(crate) struct PointValue<T, U> {
+    pub(crate) x: T,
+    pub(crate) y: U,
+    pub(crate) key: String,
+    pub(crate) y_bounds: Option<(U, U)>,
+    pub(crate) size: Option<f32>,
+    pub(crate) color_value: Option<f32>,
+    pub(crate) defined: bool,
+    pub(crate) style: Option<DatumStyle>,
+}
+
+impl<T, U> PointValue<T, U> {
+    /// Load a point datum along with the style that the view's style encoding returns for it.
+    pub(crate) fn new(datum: &impl PointDatum<T, U>, style: Option<DatumStyle>) -> Self {
+        Self {
+            x: datum.get_x(),
+            y: datum.get_y(),
+            key: datum.get_key(),
+            y_bounds: datum.get_y_bounds(),
+            size: datum.get_size(),
+            color_value: datum.get_color_value(),
+            defined: datum.is_defined(),
+            style,
+        }
+    }
 }
 
 impl BarDatum for (f32, &str) {
@@ -168,6 +285,24 @@ impl BarDatum for (&str, i32, &str) {
     }
 }
 
+impl BarDatum for (&str, f32, f32, f32) {
+    fn get_category(&self) -> String {
+        String::from(self.0)
+    }
+
+    fn get_value(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn get_bounds(&self) -> Option<(f32, f32)> {
+        Some((self.2, self.3))
+    }
+}
+
 impl PointDatum<f32, f32> for (f32, f32) {
     fn get_x(&self) -> f32 {
         self.0
@@ -252,6 +387,96 @@ impl PointDatum<f32, f32> for (f32, f32, String) {
     }
 }
 
+impl PointDatum<f32, f32> for (f32, f32, f32, f32) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn get_y_bounds(&self) -> Option<(f32, f32)> {
+        Some((self.2, self.3))
+    }
+}
+
+impl PointDatum<f32, f32> for (f32, f32, f32) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn get_size(&self) -> Option<f32> {
+        Some(self.2)
+    }
+}
+
+impl PointDatum<f32, f32> for (f32, f32, f32, &str) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::from(self.3)
+    }
+
+    fn get_size(&self) -> Option<f32> {
+        Some(self.2)
+    }
+}
+
+impl PointDatum<f32, f32> for (f32, Option<f32>) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1.unwrap_or(f32::NAN)
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn is_defined(&self) -> bool {
+        self.1.is_some()
+    }
+}
+
+impl PointDatum<f32, f32> for (f32, Option<f32>, &str) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1.unwrap_or(f32::NAN)
+    }
+
+    fn get_key(&self) -> String {
+        String::from(self.2)
+    }
+
+    fn is_defined(&self) -> bool {
+        self.1.is_some()
+    }
+}
+
 impl PointDatum<String, f32> for (String, f32) {
     fn get_x(&self) -> String {
         self.0.clone()
@@ -279,3 +504,203 @@ impl PointDatum<String, f32> for (String, isize) {
         String::new()
     }
 }
+
+impl HeatmapDatum for (&str, &str, f32) {
+    fn get_x(&self) -> String {
+        String::from(self.0)
+    }
+
+    fn get_y(&self) -> String {
+        String::from(self.1)
+    }
+
+    fn get_value(&self) -> f32 {
+        self.2
+    }
+}
+
+impl HeatmapDatum for (&str, &str, i32) {
+    fn get_x(&self) -> String {
+        String::from(self.0)
+    }
+
+    fn get_y(&self) -> String {
+        String::from(self.1)
+    }
+
+    fn get_value(&self) -> f32 {
+        self.2 as f32
+    }
+}
+
+impl HeatmapDatum for (String, String, f32) {
+    fn get_x(&self) -> String {
+        self.0.clone()
+    }
+
+    fn get_y(&self) -> String {
+        self.1.clone()
+    }
+
+    fn get_value(&self) -> f32 {
+        self.2
+    }
+}
+
+impl HeatmapDatum for (String, String, i32) {
+    fn get_x(&self) -> String {
+        self.0.clone()
+    }
+
+    fn get_y(&self) -> String {
+        self.1.clone()
+    }
+
+    fn get_value(&self) -> f32 {
+        self.2 as f32
+    }
+}
+
+impl OhlcDatum<String> for (&str, f32, f32, f32, f32) {
+    fn get_x(&self) -> String {
+        String::from(self.0)
+    }
+
+    fn get_open(&self) -> f32 {
+        self.1
+    }
+
+    fn get_high(&self) -> f32 {
+        self.2
+    }
+
+    fn get_low(&self) -> f32 {
+        self.3
+    }
+
+    fn get_close(&self) -> f32 {
+        self.4
+    }
+}
+
+impl OhlcDatum<String> for (&str, f32, f32, f32, f32, f32) {
+    fn get_x(&self) -> String {
+        String::from(self.0)
+    }
+
+    fn get_open(&self) -> f32 {
+        self.1
+    }
+
+    fn get_high(&self) -> f32 {
+        self.2
+    }
+
+    fn get_low(&self) -> f32 {
+        self.3
+    }
+
+    fn get_close(&self) -> f32 {
+        self.4
+    }
+
+    fn get_volume(&self) -> Option<f32> {
+        Some(self.5)
+    }
+}
+
+impl OhlcDatum<String> for (String, f32, f32, f32, f32) {
+    fn get_x(&self) -> String {
+        self.0.clone()
+    }
+
+    fn get_open(&self) -> f32 {
+        self.1
+    }
+
+    fn get_high(&self) -> f32 {
+        self.2
+    }
+
+    fn get_low(&self) -> f32 {
+        self.3
+    }
+
+    fn get_close(&self) -> f32 {
+        self.4
+    }
+}
+
+impl OhlcDatum<String> for (String, f32, f32, f32, f32, f32) {
+    fn get_x(&self) -> String {
+        self.0.clone()
+    }
+
+    fn get_open(&self) -> f32 {
+        self.1
+    }
+
+    fn get_high(&self) -> f32 {
+        self.2
+    }
+
+    fn get_low(&self) -> f32 {
+        self.3
+    }
+
+    fn get_close(&self) -> f32 {
+        self.4
+    }
+
+    fn get_volume(&self) -> Option<f32> {
+        Some(self.5)
+    }
+}
+
+impl OhlcDatum<f32> for (f32, f32, f32, f32, f32) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_open(&self) -> f32 {
+        self.1
+    }
+
+    fn get_high(&self) -> f32 {
+        self.2
+    }
+
+    fn get_low(&self) -> f32 {
+        self.3
+    }
+
+    fn get_close(&self) -> f32 {
+        self.4
+    }
+}
+
+impl OhlcDatum<f32> for (f32, f32, f32, f32, f32, f32) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_open(&self) -> f32 {
+        self.1
+    }
+
+    fn get_high(&self) -> f32 {
+        self.2
+    }
+
+    fn get_low(&self) -> f32 {
+        self.3
+    }
+
+    fn get_close(&self) -> f32 {
+        self.4
+    }
+
+    fn get_volume(&self) -> Option<f32> {
+        Some(self.5)
+    }
+}
@@ -0,0 +1,241 @@
+use svg::node::Node;
+use svg::node::element::Group;
+use crate::components::heatmap::HeatmapCell;
+use crate::colors::Color;
+use crate::colors::scale::ColorScale;
+use crate::{Scale, HeatmapDatum};
+use crate::scales::{ScaleType, get_scale_id};
+use crate::components::DatumRepresentation;
+use crate::views::View;
+use crate::components::legend::LegendEntry;
+use crate::theme::{Theme, ViewTheme};
+
+/// A View that represents data as a grid of colored cells, where the color of
+/// every cell is determined by its value through a color scale.
+pub struct HeatmapView<'a> {
+    labels_visible: bool,
+    rounding_precision: Option<usize>,
+    entries: Vec<(String, String, f32)>,
+    color_scale: ColorScale,
+    x_scale: Option<&'a dyn Scale<String>>,
+    y_scale: Option<&'a dyn Scale<String>>,
+    custom_data_label: String,
+    theme: ViewTheme,
+}
+
+impl<'a> HeatmapView<'a> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        Self {
+            labels_visible: false,
+            rounding_precision: None,
+            entries: Vec::new(),
+            color_scale: ColorScale::new(Color::color_scheme_blues()),
+            x_scale: None,
+            y_scale: None,
+            custom_data_label: String::new(),
+            theme: ViewTheme::new(),
+        }
+    }
+
+    /// Set the scale for the X dimension.
+    pub fn set_x_scale(mut self, scale: &'a impl Scale<String>) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for the Y dimension.
+    pub fn set_y_scale(mut self, scale: &'a impl Scale<String>) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale that maps values to cell colors.
+    pub fn set_color_scale(mut self, color_scale: ColorScale) -> Self {
+        self.color_scale = color_scale;
+        self
+    }
+
+    /// Set labels visibility. The color of a label is chosen
+    /// automatically so that it contrasts with the cell's color.
+    pub fn set_label_visibility(mut self, label_visibility: bool) -> Self {
+        self.labels_visible = label_visibility;
+        self
+    }
+
+    /// Set the precision to which value labels should be rounded.
+    pub fn set_label_rounding_precision(mut self, nr_of_digits: usize) -> Self {
+        self.rounding_precision = Some(nr_of_digits);
+        self
+    }
+
+    /// Set custom label for the dataset, displayed next to the color bar in the legend.
+    pub fn set_custom_data_label(mut self, label: String) -> Self {
+        self.custom_data_label = label;
+        self
+    }
+
+    /// Load and process a dataset of HeatmapDatum points.
+    /// Cells whose value is NaN are treated as missing and are left out.
+    pub fn load_data(mut self, data: &[impl HeatmapDatum]) -> Result<Self, String> {
+        match self.x_scale {
+            Some(scale) if scale.get_type() == ScaleType::Band => {},
+            _ => return Err("The X axis scale should be a Band scale.".to_string()),
+        }
+        match self.y_scale {
+            Some(scale) if scale.get_type() == ScaleType::Band => {},
+            _ => return Err("The Y axis scale should be a Band scale.".to_string()),
+        }
+
+        self.color_scale.validate()?;
+        self.entries = data.iter()
+            .filter(|datum| !datum.get_value().is_nan())
+            .map(|datum| (datum.get_x(), datum.get_y(), datum.get_value()))
+            .collect();
+
+        // If no domain was explicitly provided, use the extent of the data.
+        self.color_scale.fit_domain(self.entries.iter().map(|(_, _, value)| *value));
+
+        Ok(self)
+    }
+
+    /// Create the cells of the data, positioned by the view's scales.
+    fn layout(&self) -> Vec<HeatmapCell> {
+        let x_scale = self.x_scale.unwrap();
+        let y_scale = self.y_scale.unwrap();
+
+        self.entries.iter().map(|(x, y, value)| {
+            let color = self.color_scale.get_color(*value);
+            let label = match self.labels_visible {
+                true => Some(self.format_label(*value)),
+                false => None,
+            };
+
+            HeatmapCell::new(
+                x_scale.scale(x),
+                y_scale.scale(y),
+                x_scale.bandwidth().unwrap(),
+                y_scale.bandwidth().unwrap(),
+                color.as_hex(),
+                label,
+                color.contrasting_text_color(),
+            ).set_label_font(self.theme.get_font_family(), self.theme.get_label_font_size())
+        }).collect()
+    }
+
+    /// Format the label of a cell according to the rounding precision.
+    fn format_label(&self, value: f32) -> String {
+        match &self.rounding_precision {
+            None => value.to_string(),
+            Some(nr_of_digits) => format!("{:.1$}", value, nr_of_digits),
+        }
+    }
+}
+
+impl<'a> Default for HeatmapView<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> View<'a> for HeatmapView<'a> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new();
+
+        for entry in self.layout().iter() {
+            let child_svg = entry.to_svg()?;
+            group.append(child_svg);
+        }
+
+        Ok(group)
+    }
+
+    /// Return the legend entries that this view represents.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        LegendEntry::from_color_scale(&self.color_scale, self.custom_data_label.clone(), |value| self.format_label(value))
+            .into_iter()
+            .collect()
+    }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScaleBand;
+
+    fn band_scale(domain: &[&str]) -> ScaleBand {
+        ScaleBand::new()
+            .set_domain(domain.iter().map(|value| value.to_string()).collect())
+            .set_range(vec![0, 100])
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32)
+    }
+
+    #[test]
+    fn cells_are_colored_by_their_values() {
+        let x = band_scale(&["A", "B"]);
+        let y = band_scale(&["1"]);
+        let view = HeatmapView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_color_scale(ColorScale::new(Color::from_vec_of_hex_strings(vec!["#000000", "#ffffff"])))
+            .load_data(&vec![("A", "1", 0_f32), ("B", "1", 10_f32)])
+            .unwrap();
+        let svg = view.to_svg().unwrap().to_string();
+        assert!(svg.contains("fill=\"#000000\" height=\"100\" shape-rendering=\"crispEdges\" stroke=\"none\" width=\"50\" x=\"0\" y=\"0\""));
+        assert!(svg.contains("fill=\"#ffffff\" height=\"100\" shape-rendering=\"crispEdges\" stroke=\"none\" width=\"50\" x=\"50\" y=\"0\""));
+    }
+
+    #[test]
+    fn cells_without_a_value_are_left_out() {
+        let x = band_scale(&["A", "B", "C"]);
+        let y = band_scale(&["1"]);
+        let view = HeatmapView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_color_scale(ColorScale::new(Color::from_vec_of_hex_strings(vec!["#000000", "#ffffff"])))
+            .load_data(&vec![("A", "1", 0_f32), ("B", "1", f32::NAN), ("C", "1", 10_f32)])
+            .unwrap();
+        let svg = view.to_svg().unwrap().to_string();
+        assert_eq!(svg.matches("class=\"heatmap-cell\"").count(), 2);
+        assert_eq!(view.color_scale.get_domain(), Some((0_f32, 10_f32)));
+    }
+
+    #[test]
+    fn color_scales_with_invalid_colors_are_rejected() {
+        let x = band_scale(&["A"]);
+        let y = band_scale(&["1"]);
+        let result = HeatmapView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_color_scale(ColorScale::new(Color::from_vec_of_hex_strings(vec!["#000000", "#ééé"])))
+            .load_data(&vec![("A", "1", 0_f32)]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn labels_have_the_font_size_of_the_theme() {
+        let x = band_scale(&["A"]);
+        let y = band_scale(&["1"]);
+        let view = HeatmapView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_label_visibility(true)
+            .load_data(&vec![("A", "1", 1_f32)])
+            .unwrap();
+        view.set_theme(&Theme::light().set_label_font_size(9_f32));
+        let svg = view.to_svg().unwrap().to_string();
+        assert!(svg.contains("font-size=\"9px\""));
+    }
+}
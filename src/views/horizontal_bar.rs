@@ -2,26 +2,33 @@ use std::collections::HashMap;
 use svg::node::Node;
 use svg::node::element::Group;
 use crate::components::bar::{Bar, BarBlock, BarLabelPosition};
+use crate::components::error_bar::ErrorBar;
+use crate::components::style::{DatumStyle, BarStyleEncoding};
 use crate::colors::Color;
+use crate::colors::scale::ColorScale;
 use crate::{Scale, BarDatum};
-use crate::scales::ScaleType;
+use crate::views::datum::BarValue;
+use crate::scales::{ScaleType, get_scale_id};
 use crate::components::DatumRepresentation;
 use crate::views::View;
 use crate::chart::Orientation;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
+use crate::theme::{Theme, ViewTheme};
 
 /// A View that represents data as horizontal bars.
 pub struct HorizontalBarView<'a> {
     label_position: BarLabelPosition,
     labels_visible: bool,
     rounding_precision: Option<usize>,
-    entries: Vec<Bar>,
+    categories: Vec<(String, Vec<BarValue>)>,
     keys: Vec<String>,
-    colors: Vec<Color>,
-    color_map: HashMap<String, String>,
+    theme: ViewTheme,
+    color_scale: Option<ColorScale>,
     x_scale: Option<&'a dyn Scale<f32>>,
     y_scale: Option<&'a dyn Scale<String>>,
     custom_data_label: String,
+    style_encoding: Option<BarStyleEncoding<'a>>,
+    legend_styles: Vec<(String, Option<String>, String)>,
 }
 
 impl<'a> HorizontalBarView<'a> {
@@ -31,13 +38,15 @@ impl<'a> HorizontalBarView<'a> {
             label_position: BarLabelPosition::EndOutside,
             labels_visible: true,
             rounding_precision: None,
-            entries: Vec::new(),
+            categories: Vec::new(),
             keys: Vec::new(),
-            colors: Color::color_scheme_10(),
-            color_map: HashMap::new(),
+            theme: ViewTheme::new(),
+            color_scale: None,
             x_scale: None,
             y_scale: None,
             custom_data_label: String::new(),
+            style_encoding: None,
+            legend_styles: Vec::new(),
         }
     }
 
@@ -65,9 +74,10 @@ impl<'a> HorizontalBarView<'a> {
         self
     }
 
-    /// Set the color palette of the view.
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
     pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
-        self.colors = colors;
+        self.theme.set_colors(colors);
         self
     }
 
@@ -86,6 +96,23 @@ impl<'a> HorizontalBarView<'a> {
         self
     }
 
+    /// Set a function that maps every datum to a style that overrides the view's styling
+    /// of that datum (e.g. to highlight negative values). Returning `None` keeps the
+    /// view's styling. Styles with a legend label are displayed in the legend.
+    pub fn set_style_encoding(mut self, encoding: impl Fn(&dyn BarDatum) -> Option<DatumStyle> + 'a) -> Self {
+        self.style_encoding = Some(Box::new(encoding));
+        self
+    }
+
+    /// Set a color scale that colors every bar block by its value instead of by its key (e.g. to
+    /// shade bars from low to high values). If the scale has no domain, the extent of the data is
+    /// used. The legend then displays the color scale instead of the keys. A style encoding still
+    /// overrides the colors of the scale.
+    pub fn set_color_scale(mut self, color_scale: ColorScale) -> Self {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
     /// Set the precision to which value labels should be rounded.
     pub fn set_label_rounding_precision(mut self, nr_of_digits: usize) -> Self {
         self.rounding_precision = Some(nr_of_digits);
@@ -108,42 +135,79 @@ impl<'a> HorizontalBarView<'a> {
             self.keys = Self::extract_keys(&data);
         }
 
-        // HashMap to group all data related to a category. This is needed when there
-        // are many data entries under a single category as in a stacked bar chart.
-        let mut categories: HashMap<String, Vec<(&String, f32)>> = HashMap::new();
+        if let Some(color_scale) = self.color_scale.as_mut() {
+            color_scale.validate()?;
+            color_scale.fit_domain(data.iter().map(|datum| datum.get_value()));
+        }
+
+        // Group all data related to a category. This is needed when there are many
+        // data entries under a single category as in a stacked bar chart.
+        let mut categories: Vec<(String, Vec<BarValue>)> = Vec::new();
 
         // Organize entries based on the order of the keys first, since displayed data
         // should keep the order defined in the `keys` attribute.
-        for (i, key) in self.keys.iter_mut().enumerate() {
-            // Map the key to the corresponding color.
-            self.color_map.insert(key.clone(), self.colors[i % self.colors.len()].as_hex());
-
+        for key in self.keys.iter() {
             for entry in data.iter() {
                 if entry.get_key() == *key {
-                    let entry_category = entry.get_category();
-
-                    if !categories.contains_key(&entry_category) {
-                        categories.insert(entry.get_category(), Vec::new());
+                    let style = self.style_encoding.as_ref().and_then(|encoding| encoding(entry));
+                    if let Some(style) = &style {
+                        // Styles without a color of their own take the color of their key when they are rendered.
+                        let color = match (style.get_fill(), &self.color_scale) {
+                            (Some(fill), _) => Some(fill),
+                            (None, Some(color_scale)) => Some(color_scale.get_color(entry.get_value()).as_hex()),
+                            (None, None) => None,
+                        };
+                        if let Some(label) = style.get_legend_label() {
+                            if !self.legend_styles.iter().any(|(existing, _, _)| *existing == label) {
+                                self.legend_styles.push((label, color, key.clone()));
+                            }
+                        }
                     }
-                    if let Some(category_entries) = categories.get_mut(&entry_category) {
-                        category_entries.push((key, entry.get_value()));
+
+                    let value = BarValue { key: key.clone(), value: entry.get_value(), bounds: entry.get_bounds(), style };
+                    let entry_category = entry.get_category();
+                    match categories.iter_mut().find(|(category, _)| *category == entry_category) {
+                        Some((_, values)) => values.push(value),
+                        None => categories.push((entry_category, vec![value])),
                     }
                 }
             }
         }
+        self.categories = categories;
+
+        Ok(self)
+    }
 
-        // Create a Bar entry for each category data that was grouped in the previous step.
+    /// Create a Bar entry for each category, positioned by the view's scales, along with
+    /// the error bars of the entries that have bounds.
+    fn layout(&self) -> (Vec<Bar>, Vec<ErrorBar>) {
         let mut bars = Vec::new();
+        let mut error_bars = Vec::new();
         let x_range_is_reversed = self.x_scale.unwrap().is_range_reversed();
 
-        for (category, key_value_pairs) in categories.iter_mut() {
+        for (category, values) in self.categories.iter() {
             let mut value_acc = 0_f32;
             let mut bar_blocks = Vec::new();
             let mut stacked_start = self.x_scale.unwrap().scale(&value_acc);
             let mut stacked_end = stacked_start;
 
-            for (key, value) in key_value_pairs.iter() {
-                value_acc += *value;
+            for entry in values.iter() {
+                // The bounds of a stacked entry are relative to the start of its block.
+                if let Some((lower, upper)) = entry.bounds {
+                    let scale = self.x_scale.unwrap();
+                    let band_scale = self.y_scale.unwrap();
+                    let bandwidth = band_scale.bandwidth().unwrap();
+                    error_bars.push(ErrorBar::new(
+                        Orientation::Horizontal,
+                        band_scale.scale(category) + bandwidth / 2_f32,
+                        scale.scale(&(value_acc + lower)),
+                        scale.scale(&(value_acc + upper)),
+                        bandwidth / 4_f32,
+                        self.theme.get_foreground_color(),
+                    ));
+                }
+
+                value_acc += entry.value;
 
                 if x_range_is_reversed {
                     stacked_end = stacked_start;
@@ -152,18 +216,24 @@ impl<'a> HorizontalBarView<'a> {
                     stacked_start = stacked_end;
                     stacked_end = self.x_scale.unwrap().scale(&value_acc);
                 }
-                bar_blocks.push(BarBlock::new(stacked_start, stacked_end, *value, self.color_map.get(*key).unwrap().clone()));
+                let color = match &self.color_scale {
+                    Some(color_scale) => color_scale.get_color(entry.value).as_hex(),
+                    None => self.theme.get_key_color(&self.keys, &entry.key),
+                };
+                let mut bar_block = BarBlock::new(stacked_start, stacked_end, entry.value, color)
+                    .set_key_style(self.theme.get_key_style(&entry.key));
+                if let Some(style) = &entry.style {
+                    bar_block = bar_block.set_style(style);
+                }
+                bar_blocks.push(bar_block);
             }
 
-            let bar = Bar::new(bar_blocks, Orientation::Horizontal, category.to_string(), self.label_position, self.labels_visible, self.rounding_precision, self.y_scale.unwrap().bandwidth().unwrap(), self.y_scale.unwrap().scale(category));
-            bars.push(bar);
-        }
-
-        for bar in bars {
-            self.add_bar(bar);
+            bars.push(Bar::new(bar_blocks, Orientation::Horizontal, category.to_string(), self.label_position, self.labels_visible, self.rounding_precision, self.y_scale.unwrap().bandwidth().unwrap(), self.y_scale.unwrap().scale(category))
+                .set_label_style(self.theme.get_foreground_color(), self.theme.get_font_family(), self.theme.get_label_font_size())
+                .set_styled_with_classes(self.theme.is_styled_with_classes()));
         }
 
-        Ok(self)
+        (bars, error_bars)
     }
 
     /// Extract the list of keys to use when stacking and coloring the bars.
@@ -180,23 +250,23 @@ impl<'a> HorizontalBarView<'a> {
 
         keys
     }
-
-    /// Add a [Bar] entry to the dataset entries list.
-    fn add_bar(&mut self, bar: Bar) {
-        self.entries.push(bar);
-    }
 }
 
 impl<'a> View<'a> for HorizontalBarView<'a> {
     /// Generate the SVG representation of the view.
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new();
+        let (bars, error_bars) = self.layout();
 
-        for entry in self.entries.iter() {
+        for entry in bars.iter() {
             let child_svg = entry.to_svg()?;
             group.append(child_svg);
         }
 
+        for error_bar in error_bars.iter() {
+            group.append(error_bar.to_svg()?);
+        }
+
         Ok(group)
     }
 
@@ -204,17 +274,85 @@ impl<'a> View<'a> for HorizontalBarView<'a> {
     fn get_legend_entries(&self) -> Vec<LegendEntry> {
         let mut entries = Vec::new();
 
-        // If there is a single key and it is an empty string (meaning
-        // the dataset consists only of X and Y dimension values), return
-        // the custom data label.
-        if self.keys.len() == 1 && self.keys[0].len() == 0 {
-            entries.push(LegendEntry::new(LegendMarkerType::Square, self.color_map.get(&self.keys[0]).unwrap().clone(), String::from("none"), self.custom_data_label.clone()));
+        // If the bars are colored by a color scale, the scale represents the data. Otherwise, if
+        // there is a single key and it is an empty string (meaning the dataset consists only of
+        // X and Y dimension values), return the custom data label.
+        if let Some(color_scale) = &self.color_scale {
+            let format = |value: f32| match &self.rounding_precision {
+                None => value.to_string(),
+                Some(nr_of_digits) => format!("{:.1$}", value, nr_of_digits),
+            };
+            entries.extend(LegendEntry::from_color_scale(color_scale, self.custom_data_label.clone(), format));
+        } else if self.keys.len() == 1 && self.keys[0].len() == 0 {
+            entries.push(LegendEntry::new(LegendMarkerType::Square, self.theme.get_color(0), String::from("none"), self.custom_data_label.clone()));
         } else {
             for key in self.keys.iter() {
-                entries.push(LegendEntry::new(LegendMarkerType::Square, self.color_map.get(key).unwrap().clone(), String::from("none"), key.clone()));
+                entries.push(LegendEntry::new(LegendMarkerType::Square, self.theme.get_key_color(&self.keys, key), String::from("none"), key.clone()).set_key(key));
             }
         }
 
+        for (label, color, key) in self.legend_styles.iter() {
+            let color = color.clone().unwrap_or_else(|| self.theme.get_key_color(&self.keys, key));
+            entries.push(LegendEntry::new(LegendMarkerType::Square, color, String::from("none"), label.clone()));
+        }
+
         entries
     }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleBand, ScaleLinear};
+
+    struct Measurement(&'static str, f32, &'static str, Option<(f32, f32)>);
+
+    impl BarDatum for Measurement {
+        fn get_category(&self) -> String {
+            String::from(self.0)
+        }
+
+        fn get_value(&self) -> f32 {
+            self.1
+        }
+
+        fn get_key(&self) -> String {
+            String::from(self.2)
+        }
+
+        fn get_bounds(&self) -> Option<(f32, f32)> {
+            self.3
+        }
+    }
+
+    #[test]
+    fn error_bars_extend_along_the_bars() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 4_f32]).set_range(vec![0, 100]);
+        let y = ScaleBand::new().set_domain(vec![String::from("A"), String::from("B")]).set_range(vec![0, 100]);
+        let data = vec![
+            Measurement("A", 2_f32, "", Some((1_f32, 3_f32))),
+            Measurement("B", 3_f32, "", None),
+        ];
+        let view = HorizontalBarView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .load_data(&data)
+            .unwrap();
+        let svg = view.to_svg().unwrap().to_string();
+        assert_eq!(svg.matches("class=\"error-bar\"").count(), 1);
+        assert!(svg.contains("x1=\"25\" x2=\"75\""));
+        // The caps are perpendicular to the bar.
+        assert!(svg.contains("x1=\"25\" x2=\"25\""));
+        assert!(svg.contains("x1=\"75\" x2=\"75\""));
+    }
 }
@@ -2,26 +2,41 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use svg::node::Node;
 use svg::node::element::Group;
-use crate::components::scatter::{ScatterPoint, MarkerType, PointLabelPosition};
+use crate::components::scatter::{ScatterPoint, MarkerType, MarkerStyle, PointLabelPosition};
+use crate::components::style::{DatumStyle, PointStyleEncoding, SeriesStyle};
+use crate::components::area::RangeArea;
+use crate::components::curve::{Curve, GapMode};
 use crate::colors::Color;
 use crate::{Scale, LineSeries};
-use crate::views::datum::PointDatum;
+use crate::scales::get_scale_id;
+use crate::views::datum::{PointDatum, PointValue};
 use crate::views::View;
 use crate::components::DatumRepresentation;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
+use crate::theme::{Theme, ViewTheme};
+
+/// The upper and lower boundaries of a segment of a confidence band.
+type BandSegment = (Vec<(f32, f32)>, Vec<(f32, f32)>);
 
 /// A View that represents data as a scatter plot.
 pub struct LineSeriesView<'a, T: Display, U: Display> {
     labels_visible: bool,
     label_position: PointLabelPosition,
     marker_type: MarkerType,
-    entries: Vec<LineSeries<T, U>>,
-    colors: Vec<Color>,
+    marker_style: MarkerStyle,
+    curve: Curve,
+    gap_mode: GapMode,
+    series_style: SeriesStyle,
+    key_series_styles: HashMap<String, SeriesStyle>,
+    entries: Vec<PointValue<T, U>>,
+    band_opacity: f32,
     keys: Vec<String>,
-    color_map: HashMap<String, String>,
+    theme: ViewTheme,
     x_scale: Option<&'a dyn Scale<T>>,
     y_scale: Option<&'a dyn Scale<U>>,
     custom_data_label: String,
+    style_encoding: Option<PointStyleEncoding<'a, T, U>>,
+    legend_styles: Vec<(String, Option<String>, String, MarkerType)>,
 }
 
 impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
@@ -31,13 +46,20 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
             labels_visible: true,
             label_position: PointLabelPosition::NW,
             marker_type: MarkerType::Circle,
+            marker_style: MarkerStyle::Filled,
+            curve: Curve::Linear,
+            gap_mode: GapMode::Break,
+            series_style: SeriesStyle::new(),
+            key_series_styles: HashMap::new(),
             entries: Vec::new(),
+            band_opacity: 0.2,
             keys: Vec::new(),
-            colors: Color::color_scheme_10(),
-            color_map: HashMap::new(),
+            theme: ViewTheme::new(),
             x_scale: None,
             y_scale: None,
             custom_data_label: String::new(),
+            style_encoding: None,
+            legend_styles: Vec::new(),
         }
     }
 
@@ -65,15 +87,54 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
         self
     }
 
-    /// Set the keys in case of a stacked bar chart.
+    /// Set the type of the markers that are displayed at the points of the lines.
     pub fn set_marker_type(mut self, marker_type: MarkerType) -> Self {
         self.marker_type = marker_type;
         self
     }
 
-    /// Set the color palette of the view.
+    /// Set whether the markers are filled or hollow.
+    pub fn set_marker_style(mut self, marker_style: MarkerStyle) -> Self {
+        self.marker_style = marker_style;
+        self
+    }
+
+    /// Set the curve that connects the points of the lines and of their confidence bands.
+    pub fn set_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Set how missing values are treated. By default, lines are broken into
+    /// separate segments at missing values.
+    pub fn set_gap_mode(mut self, gap_mode: GapMode) -> Self {
+        self.gap_mode = gap_mode;
+        self
+    }
+
+    /// Set the style of the lines of all keys.
+    pub fn set_series_style(mut self, style: SeriesStyle) -> Self {
+        self.series_style = style;
+        self
+    }
+
+    /// Set the style of the line of a key. The properties set in it override the
+    /// ones set with `set_series_style`.
+    pub fn set_key_series_style(mut self, key: &str, style: SeriesStyle) -> Self {
+        self.key_series_styles.insert(key.to_string(), style);
+        self
+    }
+
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
     pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
-        self.colors = colors;
+        self.theme.set_colors(colors);
+        self
+    }
+
+    /// Set the opacity (between 0 and 1) of the confidence bands drawn around the lines.
+    pub fn set_band_opacity(mut self, opacity: f32) -> Self {
+        self.band_opacity = opacity;
         self
     }
 
@@ -92,6 +153,14 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
         self
     }
 
+    /// Set a function that maps every datum to a style that overrides the view's styling
+    /// of that datum's point marker (e.g. to dim points outside of a range). Returning `None` keeps
+    /// the view's styling. Styles with a legend label are displayed in the legend.
+    pub fn set_style_encoding(mut self, encoding: impl Fn(&dyn PointDatum<T, U>) -> Option<DatumStyle> + 'a) -> Self {
+        self.style_encoding = Some(Box::new(encoding));
+        self
+    }
+
     /// Load and process a dataset of BarDatum points.
     pub fn load_data(mut self, data: &Vec<impl PointDatum<T, U>>) -> Result<Self, String> {
         match self.x_scale {
@@ -108,39 +177,126 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
             self.keys = Self::extract_keys(&data);
         }
 
-        // Organize entries based on the order of the keys first, since displayed data
-        // should keep the order defined in the `keys` attribute.
-        for (i, key) in self.keys.iter_mut().enumerate() {
-            // Map the key to the corresponding color.
-            self.color_map.insert(key.clone(), self.colors[i % self.colors.len()].as_hex());
+        let mut legend_styles = Vec::new();
+
+        for datum in data.iter() {
+            let style = self.style_encoding.as_ref().filter(|_| datum.is_defined()).and_then(|encoding| encoding(datum));
+            if let Some(label) = style.as_ref().and_then(|style| style.get_legend_label()) {
+                if !legend_styles.iter().any(|(existing, _, _, _)| *existing == label) {
+                    // Styles without a color of their own take the color of their key when they are rendered.
+                    let color = style.as_ref().and_then(|style| style.get_fill());
+                    let marker_type = style.as_ref().and_then(|style| style.get_marker_type()).unwrap_or(self.marker_type);
+                    legend_styles.push((label, color, datum.get_key(), marker_type));
+                }
+            }
+            self.entries.push(PointValue::new(datum, style));
         }
 
+        self.legend_styles = legend_styles;
+
+        Ok(self)
+    }
+
+    /// Create the confidence bands and the lines of every key, positioned by the view's scales.
+    fn layout(&self) -> (Vec<RangeArea>, Vec<LineSeries<&T, &U>>) {
+        let mut bands = Vec::new();
+        let mut lines = Vec::new();
+
+        // Compute corresponding offsets to apply in case there is a non-zero bandwidth.
+        let y_bandwidth_offset = {
+            if self.y_scale.unwrap().is_range_reversed() {
+                -self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
+            } else {
+                self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
+            }
+        };
+        let x_bandwidth_offset = {
+            if self.x_scale.unwrap().is_range_reversed() {
+                -self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
+            } else {
+                self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
+            }
+        };
+
         for key in self.keys.iter() {
+            let style = self.get_series_style(key);
+            let marker_visible = style.get_marker_visibility().unwrap_or(true);
+            let marker_size = style.get_marker_size().unwrap_or(5_f32);
+            let color = self.theme.get_key_color(&self.keys, key);
+            let key_data = self.entries.iter().filter(|datum| &datum.key == key).collect::<Vec<_>>();
 
-            let points = data.iter().filter(|datum| &datum.get_key() == key).map(|datum| {
-                let scaled_x = self.x_scale.unwrap().scale(&datum.get_x());
-                let scaled_y = self.y_scale.unwrap().scale(&datum.get_y());
-                let y_bandwidth_offset = {
-                    if self.y_scale.unwrap().is_range_reversed() {
-                        -self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
-                    } else {
-                        self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
-                    }
-                };
-                let x_bandwidth_offset = {
-                    if self.x_scale.unwrap().is_range_reversed() {
-                        -self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
-                    } else {
-                        self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
+            // Compute the position of every datum, as well as the boundaries of the
+            // confidence band for the data that have bounds.
+            let mut positions = Vec::new();
+            let mut upper = Vec::new();
+            let mut lower = Vec::new();
+            for datum in key_data.iter() {
+                let scaled_x = self.x_scale.unwrap().scale(&datum.x) + x_bandwidth_offset;
+                let scaled_y = self.y_scale.unwrap().scale(&datum.y) + y_bandwidth_offset;
+                let bounds = datum.y_bounds.as_ref().filter(|_| datum.defined);
+                positions.push((scaled_x, Some(scaled_y).filter(|y| datum.defined && y.is_finite())));
+                upper.push((scaled_x, bounds.map(|(_, upper_y)| self.y_scale.unwrap().scale(upper_y) + y_bandwidth_offset)));
+                lower.push((scaled_x, bounds.map(|(lower_y, _)| self.y_scale.unwrap().scale(lower_y) + y_bandwidth_offset)));
+            }
+
+            // Interpolated values of missing data are not displayed as points.
+            let segments = self.gap_mode.segments(&positions).into_iter().map(|segment| {
+                segment.into_iter().map(|(i, x, y)| {
+                    let datum = key_data[i];
+                    let defined = positions[i].1.is_some();
+                    let point = ScatterPoint::new(x, y, self.marker_type, marker_size, &datum.x, &datum.y, self.label_position, self.labels_visible && defined, defined && marker_visible, color.clone())
+                        .set_marker_style(self.marker_style)
+                        .set_label_style(self.theme.get_foreground_color(), self.theme.get_font_family(), self.theme.get_label_font_size());
+                    match datum.style.as_ref().filter(|_| defined) {
+                        Some(style) => point.set_style(style),
+                        None => point,
                     }
-                };
-                ScatterPoint::new(scaled_x + x_bandwidth_offset, scaled_y + y_bandwidth_offset, self.marker_type, 5, datum.get_x(), datum.get_y(), self.label_position, self.labels_visible, true,self.color_map.get(&datum.get_key()).unwrap().clone())
-            }).collect::<Vec<ScatterPoint<T, U>>>();
+                }).collect::<Vec<ScatterPoint<&T, &U>>>()
+            }).collect::<Vec<Vec<ScatterPoint<&T, &U>>>>();
 
-            self.entries.push(LineSeries::new(points, self.color_map.get(key).unwrap().clone()));
+            for (upper, lower) in Self::band_segments(self.gap_mode, &upper, &lower) {
+                bands.push(RangeArea::new(upper, lower, color.clone(), self.band_opacity).set_curve(self.curve).set_key_style(self.theme.get_key_style(key)));
+            }
+            lines.push(LineSeries::from_segments(segments, color).set_curve(self.curve).set_style(style).set_key_style(self.theme.get_key_style(key)));
         }
 
-        Ok(self)
+        (bands, lines)
+    }
+
+    /// Split the upper and lower boundaries of a confidence band into the segments to draw.
+    /// A datum is only part of the band if both of its bounds are valid, so that both
+    /// boundaries break at the same gaps and their segments can be paired up.
+    fn band_segments(gap_mode: GapMode, upper: &[(f32, Option<f32>)], lower: &[(f32, Option<f32>)]) -> Vec<BandSegment> {
+        let defined = upper.iter().zip(lower.iter())
+            .map(|(upper, lower)| upper.1.is_some_and(f32::is_finite) && lower.1.is_some_and(f32::is_finite))
+            .collect::<Vec<bool>>();
+        let boundary_segments = |boundary: &[(f32, Option<f32>)]| {
+            let positions = boundary.iter().zip(defined.iter())
+                .map(|((x, y), defined)| (*x, y.filter(|_| *defined)))
+                .collect::<Vec<(f32, Option<f32>)>>();
+            gap_mode.segments(&positions).into_iter()
+                .map(|segment| segment.into_iter().map(|(_, x, y)| (x, y)).collect::<Vec<(f32, f32)>>())
+                .collect::<Vec<Vec<(f32, f32)>>>()
+        };
+
+        boundary_segments(upper).into_iter().zip(boundary_segments(lower)).collect()
+    }
+
+    /// Return the style of a key's lines.
+    fn get_series_style(&self, key: &str) -> SeriesStyle {
+        match self.key_series_styles.get(key) {
+            Some(style) => self.series_style.merge(style),
+            None => self.series_style.clone(),
+        }
+    }
+
+    /// Return the legend entry of a key, with a line that mirrors the style
+    /// that the key's line was drawn with.
+    fn legend_entry(&self, key: &str, label: String) -> LegendEntry {
+        let style = self.get_series_style(key);
+        LegendEntry::new(LegendMarkerType::Line, self.theme.get_key_color(&self.keys, key), String::from("none"), label)
+            .set_key(key)
+            .set_series_style(style)
     }
 
     /// Extract the list of keys to use when stacking and coloring the bars.
@@ -164,8 +320,13 @@ impl<'a, T: Display, U: Display> View<'a> for LineSeriesView<'a, T, U> {
     /// Generate the SVG representation of the view.
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new();
+        let (bands, lines) = self.layout();
 
-        for entry in self.entries.iter() {
+        for band in bands.iter() {
+            group.append(band.to_svg()?);
+        }
+
+        for entry in lines.iter() {
             let child_svg = entry.to_svg()?;
             group.append(child_svg);
         }
@@ -181,13 +342,69 @@ impl<'a, T: Display, U: Display> View<'a> for LineSeriesView<'a, T, U> {
         // the dataset consists only of X and Y dimension values), return
         // the custom data label.
         if self.keys.len() == 1 && self.keys[0].len() == 0 {
-            entries.push(LegendEntry::new(LegendMarkerType::Line, self.color_map.get(&self.keys[0]).unwrap().clone(), String::from("none"), self.custom_data_label.clone()));
+            entries.push(self.legend_entry(&self.keys[0], self.custom_data_label.clone()));
         } else {
             for key in self.keys.iter() {
-                entries.push(LegendEntry::new(LegendMarkerType::Line, self.color_map.get(key).unwrap().clone(), String::from("none"), key.clone()));
+                entries.push(self.legend_entry(key, key.clone()));
             }
         }
 
+        for (label, color, key, marker_type) in self.legend_styles.iter() {
+            let color = color.clone().unwrap_or_else(|| self.theme.get_key_color(&self.keys, key));
+            entries.push(LegendEntry::new(LegendMarkerType::from(*marker_type), color, String::from("none"), label.clone()).set_marker_style(self.marker_style));
+        }
+
         entries
     }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn band_boundaries_break_at_the_same_gaps() {
+        let upper = vec![(0_f32, Some(0_f32)), (1_f32, None), (2_f32, Some(2_f32)), (3_f32, Some(3_f32))];
+        let lower = vec![(0_f32, Some(1_f32)), (1_f32, Some(1_f32)), (2_f32, Some(3_f32)), (3_f32, None)];
+        let segments = LineSeriesView::<f32, f32>::band_segments(GapMode::Break, &upper, &lower);
+
+        assert_eq!(segments, vec![
+            (vec![(0_f32, 0_f32)], vec![(0_f32, 1_f32)]),
+            (vec![(2_f32, 2_f32)], vec![(2_f32, 3_f32)]),
+        ]);
+    }
+
+    #[test]
+    fn legend_entries_mirror_the_style_the_lines_are_drawn_with() {
+        let x = crate::ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![0, 100]);
+        let y = crate::ScaleLinear::new().set_domain(vec![0_f32, 1_f32]).set_range(vec![100, 0]);
+        let data = vec![(0_f32, 0_f32, "a"), (1_f32, 1_f32, "a"), (0_f32, 1_f32, "b"), (1_f32, 0_f32, "b")];
+        let view = LineSeriesView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_key_series_style("b", SeriesStyle::new().set_stroke_width(3_f32).set_dash_array("4,2"))
+            .load_data(&data).unwrap()
+            // Styles set after loading the data apply to the lines and the legend as well.
+            .set_series_style(SeriesStyle::new().set_stroke_width(8_f32));
+        let theme = crate::Theme::light();
+        let font_metrics = crate::text::FontMetrics::new();
+        let legend = view.get_legend_entries().iter()
+            .map(|entry| entry.to_svg(&font_metrics, &theme).unwrap().to_string())
+            .collect::<Vec<String>>();
+
+        assert!(legend[0].contains("stroke-width=\"8\"") && legend[0].contains("stroke-dasharray=\"none\""));
+        assert!(legend[1].contains("stroke-width=\"3\"") && legend[1].contains("stroke-dasharray=\"4,2\""));
+        assert!(view.layout().1[0].to_svg().unwrap().to_string().contains("stroke-width=\"8\""));
+        assert!(view.layout().1[1].to_svg().unwrap().to_string().contains("stroke-width=\"3\""));
+    }
 }
@@ -1,5 +1,6 @@
 use svg::node::element::Group;
 use crate::components::legend::LegendEntry;
+use crate::theme::Theme;
 
 pub mod vertical_bar;
 pub mod horizontal_bar;
@@ -7,10 +8,64 @@ pub mod scatter;
 pub mod datum;
 pub mod line;
 pub mod area;
+pub mod box_plot;
+pub mod violin;
+pub mod pie;
+pub mod heatmap;
+pub mod candlestick;
+pub mod annotation;
 
 /// A trait that defines a View of a dataset that can be rendered within a chart.
 pub trait View<'a> {
     fn to_svg(&self) -> Result<Group, String>;
 
     fn get_legend_entries(&self) -> Vec<LegendEntry>;
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions
+    /// (see `get_scale_id`), which have to match the scales of the axes that the view is bound to.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (None, None)
+    }
+
+    /// Return the z-index of the view. Views with a lower z-index are drawn below
+    /// views with a higher one, and views with the same z-index in the order they were added.
+    fn get_z_index(&self) -> isize {
+        0
+    }
+
+    /// Set the theme of the chart that renders the view. The chart sets its theme on its views
+    /// before it renders them or collects their legend entries, so that the keys of the views
+    /// without colors of their own take the colors of the palette of the theme, and their
+    /// labels and outlines the foreground color and the font family of the theme.
+    fn set_theme(&self, _theme: &Theme) {}
+
+    /// Set the size of the area in which the chart renders the view. Views whose data is
+    /// not positioned by scales (e.g. a pie) use it to place their data within the area.
+    fn set_size(&self, _width: f32, _height: f32) {}
+}
+
+impl<'a, V: View<'a> + ?Sized> View<'a> for &V {
+    fn to_svg(&self) -> Result<Group, String> {
+        (**self).to_svg()
+    }
+
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        (**self).get_legend_entries()
+    }
+
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (**self).get_scale_ids()
+    }
+
+    fn get_z_index(&self) -> isize {
+        (**self).get_z_index()
+    }
+
+    fn set_theme(&self, theme: &Theme) {
+        (**self).set_theme(theme)
+    }
+
+    fn set_size(&self, width: f32, height: f32) {
+        (**self).set_size(width, height)
+    }
 }
@@ -0,0 +1,425 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use svg::node::Node;
+use svg::node::element::Group;
+use crate::components::pie::{PieSlice, SliceGeometry, PieLabelPosition, PieLabelType};
+use crate::colors::Color;
+use crate::colors::scale::ColorScale;
+use crate::BarDatum;
+use crate::components::style::{DatumStyle, BarStyleEncoding};
+use crate::components::DatumRepresentation;
+use crate::views::View;
+use crate::components::legend::{LegendEntry, LegendMarkerType};
+use crate::theme::{Theme, ViewTheme};
+
+/// Define the order in which the slices of a pie are laid out.
+#[derive(Copy, Clone, Debug)]
+pub enum PieSortOrder {
+    /// Keep the order in which categories appear in the dataset.
+    Unsorted,
+    Ascending,
+    Descending,
+}
+
+/// A slice of the pie, which is drawn in the colors of the theme of the chart
+/// when the view is rendered.
+struct SliceValue {
+    category: String,
+    start_angle: f32,
+    end_angle: f32,
+    label: Option<String>,
+    style: Option<DatumStyle>,
+}
+
+/// A View that represents data as slices of a pie (or a donut, if an inner radius is set).
+/// Every category of the dataset is represented by a slice, and values of entries that
+/// share the same category are summed up.
+pub struct PieView<'a> {
+    center: Option<(f32, f32)>,
+    size: Cell<Option<(f32, f32)>>,
+    inner_radius: f32,
+    outer_radius: f32,
+    corner_radius: f32,
+    pad_angle: f32,
+    start_angle: f32,
+    end_angle: f32,
+    sort_order: PieSortOrder,
+    label_position: PieLabelPosition,
+    label_type: PieLabelType,
+    labels_visible: bool,
+    rounding_precision: Option<usize>,
+    entries: Vec<SliceValue>,
+    categories: Vec<String>,
+    theme: ViewTheme,
+    color_map: HashMap<String, String>,
+    color_scale: Option<ColorScale>,
+    style_encoding: Option<BarStyleEncoding<'a>>,
+}
+
+impl<'a> PieView<'a> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        Self {
+            center: None,
+            size: Cell::new(None),
+            inner_radius: 0_f32,
+            outer_radius: 100_f32,
+            corner_radius: 0_f32,
+            pad_angle: 0_f32,
+            start_angle: 0_f32,
+            end_angle: 360_f32,
+            sort_order: PieSortOrder::Descending,
+            label_position: PieLabelPosition::Inside,
+            label_type: PieLabelType::Value,
+            labels_visible: true,
+            rounding_precision: None,
+            entries: Vec::new(),
+            categories: Vec::new(),
+            theme: ViewTheme::new(),
+            color_map: HashMap::new(),
+            color_scale: None,
+            style_encoding: None,
+        }
+    }
+
+    /// Set the position of the pie's center, relative to the top left corner of the view.
+    /// If not set, the pie is placed in the center of the view.
+    pub fn set_center(mut self, x: f32, y: f32) -> Self {
+        self.center = Some((x, y));
+        self
+    }
+
+    /// Set the inner radius of the pie. A positive value turns the pie into a donut.
+    pub fn set_inner_radius(mut self, radius: f32) -> Self {
+        self.inner_radius = radius;
+        self
+    }
+
+    /// Set the outer radius of the pie.
+    pub fn set_outer_radius(mut self, radius: f32) -> Self {
+        self.outer_radius = radius;
+        self
+    }
+
+    /// Set the radius with which the corners of the slices are rounded.
+    pub fn set_corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Set the angle in degrees that separates adjacent slices.
+    pub fn set_pad_angle(mut self, angle: f32) -> Self {
+        self.pad_angle = angle;
+        self
+    }
+
+    /// Set the angle in degrees at which the first slice starts.
+    /// Angles are measured clockwise, starting from 12 o'clock.
+    pub fn set_start_angle(mut self, angle: f32) -> Self {
+        self.start_angle = angle;
+        self
+    }
+
+    /// Set the angle in degrees at which the last slice ends.
+    /// Angles are measured clockwise, starting from 12 o'clock, and the
+    /// end angle should not be smaller than the start angle.
+    pub fn set_end_angle(mut self, angle: f32) -> Self {
+        self.end_angle = angle;
+        self
+    }
+
+    /// Set the order in which slices are laid out.
+    pub fn set_sort_order(mut self, sort_order: PieSortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Set the positioning of the labels.
+    pub fn set_label_position(mut self, label_position: PieLabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    /// Set what the labels display.
+    pub fn set_label_type(mut self, label_type: PieLabelType) -> Self {
+        self.label_type = label_type;
+        self
+    }
+
+    /// Set labels visibility.
+    pub fn set_label_visibility(mut self, label_visibility: bool) -> Self {
+        self.labels_visible = label_visibility;
+        self
+    }
+
+    /// Set the precision to which value and percentage labels should be rounded.
+    pub fn set_label_rounding_precision(mut self, nr_of_digits: usize) -> Self {
+        self.rounding_precision = Some(nr_of_digits);
+        self
+    }
+
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
+    pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
+        self.theme.set_colors(colors);
+        self
+    }
+
+    /// Set a color scale that colors every slice by its value instead of by its category.
+    /// If the scale has no domain, the extent of the slices' values is used.
+    pub fn set_color_scale(mut self, color_scale: ColorScale) -> Self {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
+    /// Set a function that maps every slice, represented by its category and its summed up
+    /// value, to a style that overrides the view's styling of that slice (e.g. to highlight
+    /// the largest slice). Returning `None` keeps the view's styling. The legend displays
+    /// every category with the fill color of its slice, so legend labels of styles are ignored.
+    pub fn set_style_encoding(mut self, encoding: impl Fn(&dyn BarDatum) -> Option<DatumStyle> + 'a) -> Self {
+        self.style_encoding = Some(Box::new(encoding));
+        self
+    }
+
+    /// Load and process a dataset of BarDatum points.
+    pub fn load_data(mut self, data: &[impl BarDatum]) -> Result<Self, String> {
+        if self.outer_radius <= 0_f32 || self.inner_radius < 0_f32 || self.inner_radius >= self.outer_radius {
+            return Err("The outer radius should be positive and greater than the inner radius.".to_string());
+        }
+        if self.start_angle.is_nan() || self.end_angle.is_nan() || self.end_angle < self.start_angle {
+            return Err("The end angle should not be smaller than the start angle.".to_string());
+        }
+
+        // Sum up the values of each category, keeping the order in which categories appear.
+        let mut values: HashMap<String, f32> = HashMap::new();
+        for datum in data.iter() {
+            let category = datum.get_category();
+            if !values.contains_key(&category) {
+                self.categories.push(category.clone());
+            }
+            *values.entry(category).or_insert(0_f32) += datum.get_value();
+        }
+
+        let mut slices = self.categories.iter()
+            .map(|category| (category, values.get(category).unwrap().max(0_f32)))
+            .collect::<Vec<(&String, f32)>>();
+        match self.sort_order {
+            PieSortOrder::Unsorted => {},
+            PieSortOrder::Ascending => slices.sort_by(|a, b| a.1.total_cmp(&b.1)),
+            PieSortOrder::Descending => slices.sort_by(|a, b| b.1.total_cmp(&a.1)),
+        }
+
+        // Lay out the slices the same way D3.js does: every slice receives a share of
+        // the available angle proportional to its value, plus the pad angle.
+        let total = slices.iter().map(|slice| slice.1).sum::<f32>();
+        let angle_span = (self.end_angle - self.start_angle).to_radians();
+        let pad_angle = self.pad_angle.to_radians().min(angle_span.abs() / slices.len().max(1) as f32);
+        let angle_per_unit = match total > 0_f32 {
+            true => (angle_span - slices.len() as f32 * pad_angle) / total,
+            false => 0_f32,
+        };
+        let mut angle = self.start_angle.to_radians();
+
+        if let Some(color_scale) = self.color_scale.as_mut() {
+            color_scale.validate()?;
+            color_scale.fit_domain(slices.iter().map(|slice| slice.1));
+        }
+
+        for (category, value) in slices.into_iter() {
+            let slice_span = value * angle_per_unit + pad_angle;
+            let (start_angle, end_angle) = (angle + pad_angle / 2_f32, angle + slice_span - pad_angle / 2_f32);
+            angle += slice_span;
+
+            let label = match self.labels_visible {
+                true => Some(self.format_label(category, value, total)),
+                false => None,
+            };
+
+            // Slices that are not colored by the color scale or their style take
+            // the color of their category when they are rendered.
+            if let Some(color_scale) = &self.color_scale {
+                self.color_map.insert(category.clone(), color_scale.get_color(value).as_hex());
+            }
+            let style = self.style_encoding.as_ref().and_then(|encoding| encoding(&(category.clone(), value)));
+            if let Some(fill) = style.as_ref().and_then(|style| style.get_fill()) {
+                // Keep the color of the legend entry the same as the color of the slice.
+                self.color_map.insert(category.clone(), fill);
+            }
+
+            self.entries.push(SliceValue { category: category.clone(), start_angle, end_angle, label, style });
+        }
+
+        Ok(self)
+    }
+
+    /// Return the color of a category's slice.
+    fn get_color(&self, category: &str) -> String {
+        match self.color_map.get(category) {
+            Some(color) => color.clone(),
+            None => self.theme.get_key_color(&self.categories, category),
+        }
+    }
+
+    /// Format the label of a slice according to the label type.
+    fn format_label(&self, category: &str, value: f32, total: f32) -> String {
+        let round = |number: f32| match &self.rounding_precision {
+            None => number.to_string(),
+            Some(nr_of_digits) => format!("{:.1$}", number, nr_of_digits),
+        };
+
+        match self.label_type {
+            PieLabelType::Category => category.to_string(),
+            PieLabelType::Value => round(value),
+            // Percentages are rounded to whole numbers unless a precision is set.
+            PieLabelType::Percentage => {
+                let percentage = if total > 0_f32 { value / total * 100_f32 } else { 0_f32 };
+                format!("{:.1$}%", percentage, self.rounding_precision.unwrap_or(0))
+            },
+        }
+    }
+}
+
+impl<'a> Default for PieView<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> View<'a> for PieView<'a> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        let (x, y) = match (self.center, self.size.get()) {
+            (Some(center), _) => center,
+            (None, Some((width, height))) => (width / 2_f32, height / 2_f32),
+            (None, None) => (self.outer_radius, self.outer_radius),
+        };
+        let mut group = Group::new()
+            .set("transform", format!("translate({},{})", x, y));
+
+        for entry in self.entries.iter() {
+            let geometry = SliceGeometry {
+                start_angle: entry.start_angle,
+                end_angle: entry.end_angle,
+                inner_radius: self.inner_radius,
+                outer_radius: self.outer_radius,
+                corner_radius: self.corner_radius,
+            };
+            let mut slice = PieSlice::new(geometry, self.get_color(&entry.category), entry.label.clone(), self.label_position)
+                .set_label_style(self.theme.get_foreground_color(), self.theme.get_font_family(), self.theme.get_label_font_size())
+                .set_leader_line_color(self.theme.get_text_color())
+                .set_key_style(self.theme.get_key_style(&entry.category));
+            if let Some(style) = &entry.style {
+                slice = slice.set_style(style);
+            }
+            group.append(slice.to_svg()?);
+        }
+
+        Ok(group)
+    }
+
+    /// Return the legend entries that this view represents.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        self.categories.iter()
+            .map(|category| LegendEntry::new(LegendMarkerType::Square, self.get_color(category), String::from("none"), category.clone()).set_key(category))
+            .collect()
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+
+    /// Set the size of the area in which the chart renders the view.
+    fn set_size(&self, width: f32, height: f32) {
+        self.size.set(Some((width, height)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn end_angle_before_start_angle_is_rejected() {
+        let view = PieView::new()
+            .set_start_angle(90_f32)
+            .set_end_angle(-90_f32)
+            .load_data(&vec![("A", 1_f32)]);
+
+        assert!(view.is_err());
+    }
+
+    #[test]
+    fn slices_are_sorted_by_value() {
+        let view = PieView::new()
+            .set_sort_order(PieSortOrder::Descending)
+            .set_label_type(PieLabelType::Category)
+            .load_data(&vec![("A", 1_f32), ("B", f32::NAN), ("C", 3_f32)])
+            .unwrap();
+        let svg = view.to_svg().unwrap().to_string();
+
+        assert_eq!(view.entries.len(), 3);
+        assert!(svg.find("\nC\n").unwrap() < svg.find("\nA\n").unwrap());
+    }
+
+    #[test]
+    fn legend_entries_have_the_colors_of_styled_slices() {
+        let view = PieView::new()
+            .set_style_encoding(|datum| match datum.get_value() > 2_f32 {
+                true => Some(DatumStyle::new().set_fill(Color::from_hex("#ff0000"))),
+                false => None,
+            })
+            .load_data(&vec![("A", 1_f32), ("C", 3_f32)])
+            .unwrap();
+        let colors = view.get_legend_entries().iter().map(|entry| entry.get_color().to_string()).collect::<Vec<String>>();
+
+        assert!(view.to_svg().unwrap().to_string().contains("fill=\"#ff0000\""));
+        assert_eq!(colors, vec![String::from("#1f77b4"), String::from("#ff0000")]);
+    }
+
+    #[test]
+    fn labels_show_the_share_of_the_total() {
+        let view = PieView::new().set_label_type(PieLabelType::Percentage);
+        assert_eq!(view.format_label("A", 1_f32, 4_f32), "25%");
+        assert_eq!(view.format_label("A", 1_f32, 0_f32), "0%");
+    }
+
+    #[test]
+    fn style_encodings_can_borrow_local_values() {
+        let threshold = 2_f32;
+        let highlight = DatumStyle::new().set_fill(Color::from_hex("#ff0000"));
+        let view = PieView::new()
+            .set_style_encoding(|datum| match datum.get_value() > threshold {
+                true => Some(highlight.clone()),
+                false => None,
+            })
+            .load_data(&vec![("A", 1_f32), ("C", 3_f32)])
+            .unwrap();
+
+        assert!(view.to_svg().unwrap().to_string().contains("fill=\"#ff0000\""));
+    }
+
+    #[test]
+    fn pie_is_centered_in_the_view_by_default() {
+        let view = PieView::new().load_data(&vec![("A", 1_f32)]).unwrap();
+        view.set_size(400_f32, 300_f32);
+        assert!(view.to_svg().unwrap().to_string().contains("transform=\"translate(200,150)\""));
+
+        let view = PieView::new().set_center(50_f32, 60_f32).load_data(&vec![("A", 1_f32)]).unwrap();
+        view.set_size(400_f32, 300_f32);
+        assert!(view.to_svg().unwrap().to_string().contains("transform=\"translate(50,60)\""));
+    }
+
+    #[test]
+    fn outside_labels_are_styled_with_the_theme() {
+        let view = PieView::new()
+            .set_label_position(PieLabelPosition::Outside)
+            .load_data(&vec![("A", 1_f32), ("B", 1_f32)])
+            .unwrap();
+        view.set_theme(&Theme::light().set_text_color(Color::from_hex("#123456")).set_label_font_size(11_f32));
+        let svg = view.to_svg().unwrap().to_string();
+
+        assert_eq!(svg.matches("stroke=\"#123456\"").count(), 2);
+        assert_eq!(svg.matches("font-size=\"11px\"").count(), 2);
+    }
+}
@@ -2,26 +2,39 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use svg::node::Node;
 use svg::node::element::Group;
-use crate::components::scatter::{ScatterPoint, MarkerType, PointLabelPosition};
+use crate::components::scatter::{ScatterPoint, MarkerType, MarkerStyle, PointLabelPosition};
+use crate::components::style::{DatumStyle, PointStyleEncoding};
+use crate::components::error_bar::ErrorBar;
 use crate::colors::Color;
+use crate::colors::scale::ColorScale;
 use crate::Scale;
-use crate::views::datum::PointDatum;
+use crate::scales::get_scale_id;
+use crate::views::datum::{PointDatum, PointValue};
 use crate::views::View;
+use crate::chart::Orientation;
 use crate::components::DatumRepresentation;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
+use crate::theme::{Theme, ViewTheme};
+
+/// The range of the radii of the markers, which a size scale without a range maps its domain onto.
+const DEFAULT_SIZE_RANGE: [isize; 2] = [0, 20];
 
 /// A View that represents data as a scatter plot.
 pub struct ScatterView<'a, T: Display, U: Display> {
     labels_visible: bool,
     label_position: PointLabelPosition,
     marker_type: MarkerType,
-    entries: Vec<ScatterPoint<T, U>>,
-    colors: Vec<Color>,
+    marker_style: MarkerStyle,
+    entries: Vec<PointValue<T, U>>,
     keys: Vec<String>,
-    color_map: HashMap<String, String>,
+    theme: ViewTheme,
+    color_scale: Option<ColorScale>,
     x_scale: Option<&'a dyn Scale<T>>,
     y_scale: Option<&'a dyn Scale<U>>,
+    size_scale: Option<&'a dyn Scale<f32>>,
     custom_data_label: String,
+    style_encoding: Option<PointStyleEncoding<'a, T, U>>,
+    legend_styles: Vec<(String, Option<String>, String, MarkerType)>,
 }
 
 impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
@@ -31,13 +44,17 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
             labels_visible: true,
             label_position: PointLabelPosition::NW,
             marker_type: MarkerType::Circle,
+            marker_style: MarkerStyle::Filled,
             entries: Vec::new(),
             keys: Vec::new(),
-            colors: Color::color_scheme_10(),
-            color_map: HashMap::new(),
+            theme: ViewTheme::new(),
+            color_scale: None,
             x_scale: None,
             y_scale: None,
+            size_scale: None,
             custom_data_label: String::new(),
+            style_encoding: None,
+            legend_styles: Vec::new(),
         }
     }
 
@@ -53,6 +70,25 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
         self
     }
 
+    /// Set the scale that maps the size value of every datum (see `PointDatum::get_size`)
+    /// to the radius of its marker. A `ScaleSqrt` makes the area of the markers
+    /// proportional to the values, which is usually the desired encoding.
+    /// If no range was set on the scale, the radii range from 0 to 20 pixels.
+    pub fn set_size_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        scale.set_default_range(DEFAULT_SIZE_RANGE.to_vec());
+        self.size_scale = Some(scale);
+        self
+    }
+
+    /// Set a color scale that colors the marker of every datum by its color value (see
+    /// `PointDatum::get_color_value`) instead of by its key. If the scale has no domain, the
+    /// extent of the color values is used. The legend then displays the color scale instead
+    /// of the keys. A style encoding still overrides the colors of the scale.
+    pub fn set_color_scale(mut self, color_scale: ColorScale) -> Self {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
     /// Set the keys in case of a stacked bar chart.
     pub fn set_keys(mut self, keys: Vec<String>) -> Self {
         self.keys = keys;
@@ -65,15 +101,22 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
         self
     }
 
-    /// Set the keys in case of a stacked bar chart.
+    /// Set the type of the markers that represent the points.
     pub fn set_marker_type(mut self, marker_type: MarkerType) -> Self {
         self.marker_type = marker_type;
         self
     }
 
-    /// Set the color palette of the view.
+    /// Set whether the markers are filled or hollow.
+    pub fn set_marker_style(mut self, marker_style: MarkerStyle) -> Self {
+        self.marker_style = marker_style;
+        self
+    }
+
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
     pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
-        self.colors = colors;
+        self.theme.set_colors(colors);
         self
     }
 
@@ -92,6 +135,14 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
         self
     }
 
+    /// Set a function that maps every datum to a style that overrides the view's styling
+    /// of that datum's point (e.g. to dim points outside of a range). Returning `None` keeps
+    /// the view's styling. Styles with a legend label are displayed in the legend.
+    pub fn set_style_encoding(mut self, encoding: impl Fn(&dyn PointDatum<T, U>) -> Option<DatumStyle> + 'a) -> Self {
+        self.style_encoding = Some(Box::new(encoding));
+        self
+    }
+
     /// Load and process a dataset of BarDatum points.
     pub fn load_data(mut self, data: &Vec<impl PointDatum<T, U>>) -> Result<Self, String> {
         match self.x_scale {
@@ -108,16 +159,44 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
             self.keys = Self::extract_keys(&data);
         }
 
-        // Organize entries based on the order of the keys first, since displayed data
-        // should keep the order defined in the `keys` attribute.
-        for (i, key) in self.keys.iter_mut().enumerate() {
-            // Map the key to the corresponding color.
-            self.color_map.insert(key.clone(), self.colors[i % self.colors.len()].as_hex());
+        if let Some(color_scale) = self.color_scale.as_mut() {
+            color_scale.validate()?;
+            color_scale.fit_domain(data.iter().filter_map(|datum| datum.get_color_value()));
         }
 
         for datum in data.iter() {
-            let scaled_x = self.x_scale.unwrap().scale(&datum.get_x());
-            let scaled_y = self.y_scale.unwrap().scale(&datum.get_y());
+            let style = self.style_encoding.as_ref().and_then(|encoding| encoding(datum));
+            if let Some(label) = style.as_ref().and_then(|style| style.get_legend_label()) {
+                if !self.legend_styles.iter().any(|(existing, _, _, _)| *existing == label) {
+                    // Styles without a color of their own take the color of their key when they are rendered.
+                    let color = match (style.as_ref().and_then(|style| style.get_fill()), &self.color_scale, datum.get_color_value()) {
+                        (Some(fill), _, _) => Some(fill),
+                        (None, Some(color_scale), Some(value)) => Some(color_scale.get_color(value).as_hex()),
+                        _ => None,
+                    };
+                    let marker_type = style.as_ref().and_then(|style| style.get_marker_type()).unwrap_or(self.marker_type);
+                    self.legend_styles.push((label, color, datum.get_key(), marker_type));
+                }
+            }
+            self.entries.push(PointValue::new(datum, style));
+        }
+
+        Ok(self)
+    }
+
+    /// Create the points of the data, positioned by the view's scales, along with
+    /// the error bars of the data that have bounds.
+    fn layout(&self) -> (Vec<ScatterPoint<&T, &U>>, Vec<ErrorBar>) {
+        let mut points = Vec::new();
+        let mut error_bars = Vec::new();
+
+        for datum in self.entries.iter() {
+            let scaled_x = self.x_scale.unwrap().scale(&datum.x);
+            let scaled_y = self.y_scale.unwrap().scale(&datum.y);
+            // Missing data are not displayed.
+            if !datum.defined || !scaled_x.is_finite() || !scaled_y.is_finite() {
+                continue;
+            }
             let y_bandwidth_offset = {
                 if self.y_scale.unwrap().is_range_reversed() {
                     -self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
@@ -132,10 +211,40 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
                     self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
                 }
             };
-            self.entries.push(ScatterPoint::new(scaled_x + x_bandwidth_offset, scaled_y + y_bandwidth_offset, self.marker_type, 5, datum.get_x(), datum.get_y(), self.label_position, self.labels_visible, true, self.color_map.get(&datum.get_key()).unwrap().clone()));
+            if let Some((lower, upper)) = &datum.y_bounds {
+                error_bars.push(ErrorBar::new(
+                    Orientation::Vertical,
+                    scaled_x + x_bandwidth_offset,
+                    self.y_scale.unwrap().scale(lower) + y_bandwidth_offset,
+                    self.y_scale.unwrap().scale(upper) + y_bandwidth_offset,
+                    10_f32,
+                    self.theme.get_key_color(&self.keys, &datum.key),
+                ));
+            }
+            let marker_size = match (self.size_scale, datum.size) {
+                (Some(size_scale), Some(size)) => size_scale.scale(&size),
+                _ => 5_f32,
+            };
+            let color = match (&self.color_scale, datum.color_value) {
+                (Some(color_scale), Some(value)) => color_scale.get_color(value).as_hex(),
+                _ => self.theme.get_key_color(&self.keys, &datum.key),
+            };
+            let mut point = ScatterPoint::new(scaled_x + x_bandwidth_offset, scaled_y + y_bandwidth_offset, self.marker_type, marker_size, &datum.x, &datum.y, self.label_position, self.labels_visible, true, color)
+                .set_marker_style(self.marker_style)
+                .set_label_style(self.theme.get_foreground_color(), self.theme.get_font_family(), self.theme.get_label_font_size())
+                .set_key_style(self.theme.get_key_style(&datum.key));
+            if let Some(style) = &datum.style {
+                point = point.set_style(style);
+            }
+            points.push(point);
         }
 
-        Ok(self)
+        // Draw bigger markers first, so that they do not cover the smaller ones.
+        if self.size_scale.is_some() {
+            points.sort_by(|a, b| b.get_marker_size().total_cmp(&a.get_marker_size()));
+        }
+
+        (points, error_bars)
     }
 
     /// Extract the list of keys to use when stacking and coloring the bars.
@@ -159,8 +268,13 @@ impl<'a, T: Display, U: Display> View<'a> for ScatterView<'a, T, U> {
     /// Generate the SVG representation of the view.
     fn to_svg(&self) -> Result<Group, String> {
         let mut group = Group::new();
+        let (points, error_bars) = self.layout();
+
+        for error_bar in error_bars.iter() {
+            group.append(error_bar.to_svg()?);
+        }
 
-        for entry in self.entries.iter() {
+        for entry in points.iter() {
             let child_svg = entry.to_svg()?;
             group.append(child_svg);
         }
@@ -172,17 +286,129 @@ impl<'a, T: Display, U: Display> View<'a> for ScatterView<'a, T, U> {
     fn get_legend_entries(&self) -> Vec<LegendEntry> {
         let mut entries = Vec::new();
 
-        // If there is a single key and it is an empty string (meaning
-        // the dataset consists only of X and Y dimension values), return
-        // the custom data label.
-        if self.keys.len() == 1 && self.keys[0].len() == 0 {
-            entries.push(LegendEntry::new(LegendMarkerType::from(self.marker_type), self.color_map.get(&self.keys[0]).unwrap().clone(), String::from("none"), self.custom_data_label.clone()));
+        // If the markers are colored by a color scale, the scale represents the data. Otherwise, if
+        // there is a single key and it is an empty string (meaning the dataset consists only of
+        // X and Y dimension values), return the custom data label.
+        if let Some(color_scale) = &self.color_scale {
+            entries.extend(LegendEntry::from_color_scale(color_scale, self.custom_data_label.clone(), |value| value.to_string()));
+        } else if self.keys.len() == 1 && self.keys[0].len() == 0 {
+            entries.push(LegendEntry::new(LegendMarkerType::from(self.marker_type), self.theme.get_color(0), String::from("none"), self.custom_data_label.clone()).set_marker_style(self.marker_style));
         } else {
             for key in self.keys.iter() {
-                entries.push(LegendEntry::new(LegendMarkerType::from(self.marker_type), self.color_map.get(key).unwrap().clone(), String::from("none"), key.clone()));
+                entries.push(LegendEntry::new(LegendMarkerType::from(self.marker_type), self.theme.get_key_color(&self.keys, key), String::from("none"), key.clone()).set_key(key).set_marker_style(self.marker_style));
+            }
+        }
+
+        for (label, color, key, marker_type) in self.legend_styles.iter() {
+            let color = color.clone().unwrap_or_else(|| self.theme.get_key_color(&self.keys, key));
+            entries.push(LegendEntry::new(LegendMarkerType::from(*marker_type), color, String::from("none"), label.clone()).set_marker_style(self.marker_style));
+        }
+
+        // Add reference markers that show which sizes correspond to which values.
+        if let Some(size_scale) = self.size_scale {
+            let ticks = size_scale.get_ticks().into_iter().filter(|tick| *tick > 0_f32).collect::<Vec<f32>>();
+            let mut reference_values = Vec::new();
+            for i in [0, (ticks.len().max(1) - 1) / 2, ticks.len().max(1) - 1].iter() {
+                if let Some(tick) = ticks.get(*i) {
+                    if !reference_values.contains(tick) {
+                        reference_values.push(*tick);
+                    }
+                }
+            }
+
+            for value in reference_values.into_iter() {
+                let marker_size = size_scale.scale(&value).round().max(1_f32) as usize;
+                entries.push(LegendEntry::new(LegendMarkerType::from(self.marker_type), self.theme.get_axis_color(), String::from("none"), value.to_string()).set_marker_size(marker_size).set_marker_style(self.marker_style));
             }
         }
 
         entries
     }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        (self.x_scale.map(get_scale_id), self.y_scale.map(get_scale_id))
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleLinear, ScaleSqrt};
+
+    struct Reading(f32, f32, Option<(f32, f32)>);
+
+    impl PointDatum<f32, f32> for Reading {
+        fn get_x(&self) -> f32 {
+            self.0
+        }
+
+        fn get_y(&self) -> f32 {
+            self.1
+        }
+
+        fn get_key(&self) -> String {
+            String::new()
+        }
+
+        fn get_y_bounds(&self) -> Option<(f32, f32)> {
+            self.2
+        }
+    }
+
+    #[test]
+    fn points_with_y_bounds_have_vertical_error_bars() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 4_f32]).set_range(vec![100, 0]);
+        let data = vec![Reading(5_f32, 2_f32, Some((1_f32, 3_f32))), Reading(2_f32, 1_f32, None)];
+        let view = ScatterView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .load_data(&data)
+            .unwrap();
+        let svg = view.to_svg().unwrap().to_string();
+        assert_eq!(svg.matches("class=\"error-bar\"").count(), 1);
+        assert!(svg.contains("x1=\"50\" x2=\"50\" y1=\"75\" y2=\"25\""));
+        assert!(svg.contains("x1=\"45\" x2=\"55\" y1=\"75\" y2=\"75\""));
+        assert!(svg.contains("x1=\"45\" x2=\"55\" y1=\"25\" y2=\"25\""));
+    }
+
+    #[test]
+    fn size_scales_without_a_range_map_to_visible_markers() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        let size = ScaleSqrt::new().set_domain(vec![0_f32, 100_f32]);
+        let view = ScatterView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_size_scale(&size)
+            .load_data(&vec![(5_f32, 5_f32, 25_f32)])
+            .unwrap();
+
+        assert_eq!(size.scale(&25_f32), 10_f32);
+        assert!(view.to_svg().unwrap().to_string().contains("r=\"10\""));
+    }
+
+    #[test]
+    fn size_legend_markers_have_the_axis_color_of_the_theme() {
+        let x = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![0, 100]);
+        let y = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        let size = ScaleSqrt::new().set_domain(vec![0_f32, 100_f32]).set_range(vec![0, 10]);
+        let view = ScatterView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_size_scale(&size)
+            .load_data(&vec![(5_f32, 5_f32, 25_f32)])
+            .unwrap();
+        view.set_theme(&Theme::light().set_axis_color(Color::from_hex("#123456")));
+        let entries = view.get_legend_entries();
+
+        assert_eq!(entries.len(), 4);
+        assert!(entries[1..].iter().all(|entry| entry.get_color() == "#123456"));
+    }
 }
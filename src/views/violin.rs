@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use svg::node::Node;
+use svg::node::element::Group;
+use crate::components::violin::Violin;
+use crate::colors::Color;
+use crate::{Scale, BarDatum};
+use crate::scales::{ScaleType, get_scale_id};
+use crate::components::DatumRepresentation;
+use crate::views::View;
+use crate::chart::Orientation;
+use crate::components::legend::{LegendEntry, LegendMarkerType};
+use crate::theme::{Theme, ViewTheme};
+use crate::stats;
+
+/// The number of positions at which the density of a distribution is estimated.
+const DENSITY_RESOLUTION: usize = 50;
+
+/// The number of bandwidths by which the density estimate extends beyond the lowest
+/// and the highest samples, so that the tails of the violins taper off.
+const DENSITY_CUT: f32 = 3_f32;
+
+/// The density estimate of the samples of a category and key, which is positioned
+/// by the view's scales when the view is rendered.
+struct DensityEstimate {
+    category: String,
+    key_index: usize,
+    positions: Vec<f32>,
+    densities: Vec<f32>,
+    median: f32,
+    median_density: f32,
+}
+
+/// A View that represents the distribution of samples of each category as a violin,
+/// whose width mirrors the kernel density estimate of the samples.
+/// Every `BarDatum` entry of the dataset represents a single sample, where the category
+/// and the key are used to group samples and the value is the sample itself.
+pub struct ViolinView<'a> {
+    orientation: Orientation,
+    bandwidth: Option<f32>,
+    median_visible: bool,
+    entries: Vec<DensityEstimate>,
+    keys: Vec<String>,
+    theme: ViewTheme,
+    category_scale: Option<&'a dyn Scale<String>>,
+    value_scale: Option<&'a dyn Scale<f32>>,
+    custom_data_label: String,
+}
+
+impl<'a> ViolinView<'a> {
+    /// Create a new empty instance of the view.
+    pub fn new() -> Self {
+        Self {
+            orientation: Orientation::Vertical,
+            bandwidth: None,
+            median_visible: true,
+            entries: Vec::new(),
+            keys: Vec::new(),
+            theme: ViewTheme::new(),
+            category_scale: None,
+            value_scale: None,
+            custom_data_label: String::new(),
+        }
+    }
+
+    /// Set the orientation of the violins. A vertical orientation places
+    /// categories along the X axis and values along the Y axis.
+    pub fn set_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the scale for the category dimension.
+    pub fn set_category_scale(mut self, scale: &'a impl Scale<String>) -> Self {
+        self.category_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for the value dimension.
+    pub fn set_value_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+        self.value_scale = Some(scale);
+        self
+    }
+
+    /// Set the keys in case there are several violins per category.
+    pub fn set_keys(mut self, keys: Vec<String>) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Set the bandwidth (in domain units) of the kernel used to estimate the density.
+    /// The bandwidth should be a positive number. If not set, the bandwidth is estimated
+    /// for each distribution using Silverman's rule of thumb.
+    pub fn set_bandwidth(mut self, bandwidth: f32) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    /// Set the visibility of the median line.
+    pub fn set_median_visibility(mut self, median_visibility: bool) -> Self {
+        self.median_visible = median_visibility;
+        self
+    }
+
+    /// Set the color palette of the view. By default, the view takes the palette
+    /// of the theme of the chart that renders it.
+    pub fn set_colors(mut self, colors: Vec<Color>) -> Self {
+        self.theme.set_colors(colors);
+        self
+    }
+
+    /// Set custom label for the dataset.
+    /// This will work when the dataset represents only a single
+    /// type of data (i.e. there are no different "keys" by which to
+    /// differentiate data), otherwise, this will have no effect.
+    pub fn set_custom_data_label(mut self, label: String) -> Self {
+        self.custom_data_label = label;
+        self
+    }
+
+    /// Load and process a dataset of BarDatum samples.
+    pub fn load_data(mut self, data: &[impl BarDatum]) -> Result<Self, String> {
+        match self.category_scale {
+            Some(scale) if scale.get_type() == ScaleType::Band => {},
+            _ => return Err("The category scale should be a Band scale.".to_string()),
+        }
+        match self.value_scale {
+            Some(scale) if scale.get_type() == ScaleType::Linear => {},
+            _ => return Err("The value scale should be a Linear scale.".to_string()),
+        }
+        if let Some(bandwidth) = self.bandwidth {
+            if !(bandwidth.is_finite() && bandwidth > 0_f32) {
+                return Err("The bandwidth of the kernel should be a positive number.".to_string());
+            }
+        }
+
+        // If no keys were explicitly provided, extract the keys from the data.
+        if self.keys.is_empty() {
+            self.keys = Self::extract_keys(data);
+        }
+
+        // The density is not estimated beyond the domain of the value scale, where it can't be shown.
+        let value_scale = self.value_scale.unwrap();
+        let domain = [value_scale.range_start(), value_scale.range_end()].map(|range| value_scale.invert(range).unwrap_or(f32::NAN));
+        let (domain_min, domain_max) = match domain[0].is_finite() && domain[1].is_finite() {
+            true => (domain[0].min(domain[1]), domain[0].max(domain[1])),
+            false => (f32::NEG_INFINITY, f32::INFINITY),
+        };
+
+        // Group the samples by category and key, keeping the categories in the order
+        // in which they first appear in the data.
+        let mut categories: Vec<String> = Vec::new();
+        let mut samples: HashMap<(String, String), Vec<f32>> = HashMap::new();
+        // Samples that are not a number can't be placed on the value scale and are skipped.
+        for datum in data.iter().filter(|datum| !datum.get_value().is_nan()) {
+            if !categories.contains(&datum.get_category()) {
+                categories.push(datum.get_category());
+            }
+            samples.entry((datum.get_category(), datum.get_key())).or_default().push(datum.get_value());
+        }
+
+        for category in categories {
+            for (i, key) in self.keys.iter().enumerate() {
+                let mut values = match samples.remove(&(category.clone(), key.clone())) {
+                    Some(values) if !values.is_empty() => values,
+                    _ => continue,
+                };
+                values.sort_by(|a, b| a.total_cmp(b));
+
+                let bandwidth = self.bandwidth.unwrap_or_else(|| stats::silverman_bandwidth(&values));
+                let min = (values[0] - DENSITY_CUT * bandwidth).max(domain_min).min(values[0]);
+                let max = (values[values.len() - 1] + DENSITY_CUT * bandwidth).min(domain_max).max(values[values.len() - 1]);
+                let positions = (0..DENSITY_RESOLUTION)
+                    .map(|j| min + (max - min) * j as f32 / (DENSITY_RESOLUTION - 1) as f32)
+                    .collect::<Vec<f32>>();
+                let densities = stats::gaussian_kde(&values, bandwidth, &positions);
+                let median = stats::quantile(&values, 0.5);
+                let median_density = stats::gaussian_kde(&values, bandwidth, &[median])[0];
+
+                self.entries.push(DensityEstimate { category: category.clone(), key_index: i, positions, densities, median, median_density });
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Create the violins of the data, positioned by the view's scales.
+    fn layout(&self) -> Vec<Violin> {
+        let category_scale = self.category_scale.unwrap();
+        let value_scale = self.value_scale.unwrap();
+        // Violins of different keys are placed side by side within a category's band.
+        let slot_width = category_scale.bandwidth().unwrap() / self.keys.len() as f32;
+
+        // The violins' widths are normalized by the highest density across all of them.
+        let max_density = self.entries.iter()
+            .flat_map(|estimate| estimate.densities.iter())
+            .fold(0_f32, |acc, density| acc.max(*density));
+        let max_half_width = slot_width * 0.9 / 2_f32;
+        let density_to_width = |density: f32| {
+            if max_density > 0_f32 {
+                density / max_density * max_half_width
+            } else {
+                0_f32
+            }
+        };
+
+        self.entries.iter().map(|estimate| {
+            let center = category_scale.scale(&estimate.category) + slot_width * estimate.key_index as f32 + slot_width / 2_f32;
+            let contour = estimate.positions.iter().zip(estimate.densities.iter())
+                .map(|(position, density)| (value_scale.scale(position), density_to_width(*density)))
+                .collect::<Vec<(f32, f32)>>();
+            let median = match self.median_visible {
+                true => Some((value_scale.scale(&estimate.median), density_to_width(estimate.median_density))),
+                false => None,
+            };
+
+            Violin::new(self.orientation, center, contour, median, self.theme.get_color(estimate.key_index))
+                .set_outline_color(self.theme.get_foreground_color())
+                .set_key_style(self.theme.get_key_style(&self.keys[estimate.key_index]))
+        }).collect()
+    }
+
+    /// Extract the list of keys to use when grouping and coloring the violins.
+    fn extract_keys(data: &[impl BarDatum]) -> Vec<String> {
+        let mut keys = Vec::new();
+        let mut map = HashMap::new();
+
+        for datum in data.iter() {
+            match map.insert(datum.get_key(), 0) {
+                Some(_) => {},
+                None => keys.push(datum.get_key()),
+            }
+        }
+
+        keys
+    }
+}
+
+impl<'a> Default for ViolinView<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> View<'a> for ViolinView<'a> {
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, String> {
+        let mut group = Group::new();
+
+        for entry in self.layout().iter() {
+            let child_svg = entry.to_svg()?;
+            group.append(child_svg);
+        }
+
+        Ok(group)
+    }
+
+    /// Return the legend entries that this view represents.
+    fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        let mut entries = Vec::new();
+
+        // If there is a single key and it is an empty string (meaning
+        // the dataset consists only of categories and values), return
+        // the custom data label.
+        if self.keys.len() == 1 && self.keys[0].is_empty() {
+            entries.push(LegendEntry::new(LegendMarkerType::Square, self.theme.get_color(0), String::from("none"), self.custom_data_label.clone()));
+        } else {
+            for key in self.keys.iter() {
+                entries.push(LegendEntry::new(LegendMarkerType::Square, self.theme.get_key_color(&self.keys, key), String::from("none"), key.clone()).set_key(key));
+            }
+        }
+
+        entries
+    }
+
+    /// Return the identifiers of the scales of the view along the X and Y dimensions.
+    fn get_scale_ids(&self) -> (Option<usize>, Option<usize>) {
+        let category_scale = self.category_scale.map(get_scale_id);
+        let value_scale = self.value_scale.map(get_scale_id);
+        match self.orientation {
+            Orientation::Vertical => (category_scale, value_scale),
+            Orientation::Horizontal => (value_scale, category_scale),
+        }
+    }
+
+    /// Set the theme of the chart that renders the view.
+    fn set_theme(&self, theme: &Theme) {
+        self.theme.set_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ScaleBand, ScaleLinear};
+
+    fn scales() -> (ScaleBand, ScaleLinear) {
+        let category = ScaleBand::new().set_domain(vec![String::from("A")]).set_range(vec![0, 100]);
+        let value = ScaleLinear::new().set_domain(vec![0_f32, 10_f32]).set_range(vec![100, 0]);
+        (category, value)
+    }
+
+    #[test]
+    fn density_tapers_off_beyond_the_extreme_samples() {
+        let (category, value) = scales();
+        let view = ViolinView::new()
+            .set_category_scale(&category)
+            .set_value_scale(&value)
+            .set_bandwidth(0.5)
+            .load_data(&vec![("A", 4_f32), ("A", 5_f32), ("A", 6_f32)])
+            .unwrap();
+        let estimate = &view.entries[0];
+        let peak = estimate.densities.iter().fold(0_f32, |acc, density| acc.max(*density));
+        assert_eq!(estimate.positions[0], 2.5);
+        assert_eq!(estimate.positions[DENSITY_RESOLUTION - 1], 7.5);
+        assert!(estimate.densities[0] < peak * 0.05);
+        assert!(estimate.densities[DENSITY_RESOLUTION - 1] < peak * 0.05);
+        assert_eq!(estimate.median, 5_f32);
+        assert!(estimate.median_density >= peak);
+    }
+
+    #[test]
+    fn density_is_not_estimated_beyond_the_value_domain() {
+        let (category, value) = scales();
+        let view = ViolinView::new()
+            .set_category_scale(&category)
+            .set_value_scale(&value)
+            .set_bandwidth(1_f32)
+            .load_data(&vec![("A", 1_f32), ("A", 9_f32)])
+            .unwrap();
+        let estimate = &view.entries[0];
+        assert_eq!(estimate.positions[0], 0_f32);
+        assert_eq!(estimate.positions[DENSITY_RESOLUTION - 1], 10_f32);
+    }
+
+    #[test]
+    fn bandwidths_that_are_not_positive_numbers_are_rejected() {
+        let (category, value) = scales();
+        for bandwidth in [0_f32, -1_f32, f32::NAN, f32::INFINITY] {
+            let result = ViolinView::new()
+                .set_category_scale(&category)
+                .set_value_scale(&value)
+                .set_bandwidth(bandwidth)
+                .load_data(&vec![("A", 1_f32)]);
+            assert!(result.is_err(), "bandwidth {} was accepted", bandwidth);
+        }
+    }
+}